@@ -6,13 +6,14 @@ fn main() {
     tonic_build::configure()
         .out_dir("src/pb")
         .with_sql_type(&["reservation.ReservationStatus"])
+        .with_serde(&["reservation.BookingPolicy"])
         .with_builder(&[
             "reservation.ReservationQuery",
             "reservation.ReservationFilter",
         ])
         .with_builder_into(
             "reservation.ReservationQuery",
-            &["resource_id", "user_id", "status", "desc"],
+            &["resource_id", "user_id", "status", "desc", "attendee_id"],
         )
         .with_builder_into(
             "reservation.ReservationFilter",
@@ -23,6 +24,7 @@ fn main() {
                 "cursor",
                 "page_size",
                 "desc",
+                "attendee_id",
             ],
         )
         .with_builder_option("reservation.ReservationQuery", &["start", "end"])
@@ -37,6 +39,7 @@ fn main() {
 
 trait BuilderExt {
     fn with_sql_type(self, paths: &[&str]) -> Self;
+    fn with_serde(self, paths: &[&str]) -> Self;
     fn with_builder(self, paths: &[&str]) -> Self;
     fn with_builder_into(self, path: &str, fields: &[&str]) -> Self;
     fn with_builder_option(self, path: &str, fields: &[&str]) -> Self;
@@ -49,6 +52,15 @@ impl BuilderExt for Builder {
         })
     }
 
+    fn with_serde(self, paths: &[&str]) -> Self {
+        paths.iter().fold(self, |acc, path| {
+            acc.type_attribute(
+                path,
+                "#[derive(Eq, serde::Serialize, serde::Deserialize)] #[serde(default)]",
+            )
+        })
+    }
+
     fn with_builder(self, paths: &[&str]) -> Self {
         paths.iter().fold(self, |acc, path| {
             acc.type_attribute(path, "#[derive(derive_builder::Builder)]")
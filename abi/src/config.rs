@@ -3,12 +3,21 @@ use std::{fs, path::Path};
 
 use serde::{Deserialize, Serialize};
 
-use crate::Error;
+use crate::{BookingPolicy, Error};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Config {
     pub db: DbConfig,
     pub server: ServerConfig,
+    /// booking policies loaded at startup, they can be changed at runtime
+    #[serde(default)]
+    pub policies: Vec<BookingPolicy>,
+    #[serde(default)]
+    pub no_show: NoShowConfig,
+    #[serde(default)]
+    pub webhook: WebhookConfig,
+    #[serde(default)]
+    pub notification: NotificationConfig,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -32,6 +41,138 @@ pub struct ServerConfig {
     pub port: u16,
 }
 
+/// background job marking confirmed reservations nobody checked in as no_show
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NoShowConfig {
+    /// how long after start a guest may still check in
+    pub grace_period_secs: u64,
+    /// free the rest of the window of a no_show reservation so others can book it
+    pub release: bool,
+    /// how often the job runs, 0 disables it. Off by default: it marks every confirmed
+    /// reservation of every tenant nobody checked in, so enable it once guests do check in
+    pub interval_secs: u64,
+}
+
+impl Default for NoShowConfig {
+    fn default() -> Self {
+        Self {
+            grace_period_secs: 900,
+            release: false,
+            interval_secs: 0,
+        }
+    }
+}
+
+/// background job delivering reservation changes to webhook subscriptions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookConfig {
+    /// how often the job looks for due deliveries, 0 disables it
+    pub interval_secs: u64,
+    /// timeout of a single callback
+    pub timeout_secs: u64,
+    /// a delivery failing this many times is moved to the dead letters
+    pub max_attempts: u32,
+    /// delay before the first retry, doubled for every further one
+    pub backoff_secs: u64,
+    /// upper bound of the delay between retries
+    pub max_backoff_secs: u64,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 5,
+            timeout_secs: 10,
+            max_attempts: 8,
+            backoff_secs: 10,
+            max_backoff_secs: 3600,
+        }
+    }
+}
+
+impl WebhookConfig {
+    /// delay before retrying a delivery which failed `attempts` times
+    pub fn backoff(&self, attempts: u32) -> u64 {
+        let factor = 1u64
+            .checked_shl(attempts.saturating_sub(1))
+            .unwrap_or(u64::MAX);
+        self.backoff_secs
+            .saturating_mul(factor)
+            .min(self.max_backoff_secs)
+    }
+}
+
+/// background job reminding users of their reservations
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationConfig {
+    /// how often the job schedules and sends notifications, 0 disables it
+    pub interval_secs: u64,
+    /// remind users this long before their reservation starts, 0 disables reminders
+    pub reminder_secs: u64,
+    /// a pending reservation is a hold which must be confirmed before it starts, warn its user
+    /// this long before the start, 0 disables the warning
+    pub hold_notice_secs: u64,
+    /// a notification failing this many times is given up
+    pub max_attempts: u32,
+    /// delay before retrying a failed notification
+    pub retry_secs: u64,
+    /// at most this many notifications are claimed per run
+    pub batch_size: u32,
+    /// at most this many notifications are sent at the same time
+    pub concurrency: usize,
+    pub notifier: NotifierConfig,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 30,
+            reminder_secs: 3600,
+            hold_notice_secs: 7200,
+            max_attempts: 5,
+            retry_secs: 60,
+            batch_size: 100,
+            concurrency: 8,
+            notifier: NotifierConfig::Log,
+        }
+    }
+}
+
+/// how notifications reach users
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotifierConfig {
+    /// print them, for development
+    Log,
+    /// POST them as JSON, signed like webhook deliveries if a secret is set
+    Webhook {
+        url: String,
+        #[serde(default)]
+        secret: String,
+    },
+    /// mail them, user ids without "@" are local parts of addresses in `domain`
+    Smtp {
+        host: String,
+        port: u16,
+        from: String,
+        domain: String,
+        #[serde(default)]
+        username: String,
+        #[serde(default)]
+        password: String,
+        /// STARTTLS is required unless disabled, e.g. for a local relay
+        #[serde(default = "default_starttls")]
+        starttls: bool,
+    },
+}
+
+fn default_starttls() -> bool {
+    true
+}
+
 impl Config {
     pub fn load(filename: impl AsRef<Path>) -> Result<Self, Error> {
         let config = fs::read_to_string(filename.as_ref()).map_err(|_| Error::ConfigReadError)?;
@@ -77,8 +218,32 @@ mod tests {
                 server: ServerConfig {
                     host: "0.0.0.0".to_string(),
                     port: 50051
-                }
+                },
+                policies: vec![BookingPolicy {
+                    resource_type: "default".to_string(),
+                    max_active_reservations: Some(100),
+                    ..Default::default()
+                }],
+                no_show: NoShowConfig::default(),
+                webhook: WebhookConfig::default(),
+                notification: NotificationConfig::default(),
             }
         )
     }
+
+    #[test]
+    fn no_show_job_should_be_off_without_its_section() {
+        // an upgraded deployment must not mark the reservations made before check-in existed
+        let config = Config::load("../service/fixtures/config.yml").unwrap();
+        assert_eq!(config.no_show.interval_secs, 0);
+    }
+
+    #[test]
+    fn webhook_backoff_should_double_up_to_the_limit() {
+        let config = WebhookConfig::default();
+        let delays = (1..=3).map(|n| config.backoff(n)).collect::<Vec<_>>();
+        assert_eq!(delays, [10, 20, 40]);
+        assert_eq!(config.backoff(20), 3600);
+        assert_eq!(config.backoff(100), 3600);
+    }
 }
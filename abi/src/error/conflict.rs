@@ -15,6 +15,7 @@ pub struct ReservationConflict {
     pub old: ReservationWindow,
 }
 
+/// the window blocked by a reservation, turnaround buffers of the resource included
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReservationWindow {
     pub rid: String,
@@ -63,19 +64,34 @@ struct ParsedInfo {
 impl FromStr for ParsedInfo {
     type Err = ();
 
-    // "Key (resource_id, timespan)=(ocean-view-room-713, [\"2022-12-26 22:00:00+00\",\"2022-12-30 19:00:00+00\")) conflicts with existing key (resource_id, timespan)=(ocean-view-room-713, [\"2022-12-25 22:00:00+00\",\"2022-12-28 19:00:00+00\"))."
+    // "Key (tenant_id, resource_id, timespan)=(tenant-a, ocean-view-room-713, [\"2022-12-26 22:00:00+00\",\"2022-12-30 19:00:00+00\")) conflicts with existing key (tenant_id, resource_id, timespan)=(tenant-a, ocean-view-room-713, [\"2022-12-25 22:00:00+00\",\"2022-12-28 19:00:00+00\"))."
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let re = Regex::new(r#"\((?P<k1>[a-zA-Z0-9_-]+)\s*,\s*(?P<k2>[a-zA-Z0-9_-]+)\)=\((?P<v1>[a-zA-Z0-9_-]+)\s*,\s*\[(?P<v2>[^\)\]]+)"#).unwrap();
+        let re = Regex::new(
+            r#"\((?P<keys>[a-zA-Z0-9_,\s-]+)\)=\((?P<values>[a-zA-Z0-9_,\s-]*)\[(?P<range>[^\)\]]+)"#,
+        )
+        .unwrap();
         let mut maps = re
             .captures_iter(s)
             .take(2)
             .map(|caps| {
-                let mut a = HashMap::new();
-                a.insert(caps["k1"].to_string(), caps["v1"].to_string());
-                a.insert(caps["k2"].to_string(), caps["v2"].to_string());
-                a
+                // the range is always the last key, the leading values are separated by ", "
+                let keys = caps["keys"].split(',').map(str::trim).collect::<Vec<_>>();
+                let mut values = caps["values"]
+                    .split(',')
+                    .map(str::trim)
+                    .take(keys.len().saturating_sub(1))
+                    .collect::<Vec<_>>();
+                values.push(&caps["range"]);
+                if keys.len() != values.len() {
+                    return Err(());
+                }
+                Ok(keys
+                    .into_iter()
+                    .zip(values)
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<HashMap<String, String>>())
             })
-            .collect::<Vec<HashMap<String, String>>>();
+            .collect::<Result<Vec<HashMap<String, String>>, ()>>()?;
 
         if maps.len() != 2 {
             return Err(());
@@ -85,9 +101,6 @@ impl FromStr for ParsedInfo {
         let new = maps.remove(0);
 
         Ok(ParsedInfo { new, old })
-
-        // let [new, old] = <[_; 2]>::try_from(maps).map_err(|_| ())?;
-        // Ok(ParsedInfo { new, old })
     }
 }
 
@@ -96,9 +109,14 @@ impl TryFrom<HashMap<String, String>> for ReservationWindow {
 
     // "2022-12-26 22:00:00+00","2022-12-30 19:00:00+00"
     fn try_from(value: HashMap<String, String>) -> Result<Self, Self::Error> {
-        const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%#z";
+        // postgres prints fractional seconds only when there are any
+        const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f%#z";
         let rid = value.get("resource_id").ok_or(())?.to_string();
-        let timespan = value.get("timespan").ok_or(())?.replace('"', "");
+        let timespan = value
+            .get("blocked")
+            .or_else(|| value.get("timespan"))
+            .ok_or(())?
+            .replace('"', "");
         let split = timespan.splitn(2, ',').collect::<Vec<&str>>();
         let start = DateTime::parse_from_str(split[0], TIME_FORMAT)
             .map_err(|_| ())?
@@ -141,7 +159,69 @@ mod tests {
                 "2022-12-28 19:00:00+0000".parse::<DateTime<Utc>>().unwrap()
             );
         } else {
-            assert!(false);
+            panic!("conflict info should be parsed");
         }
     }
+
+    #[test]
+    pub fn reservation_conflict_info_parse_with_tenant_should_work() {
+        const TENANT_ERROR_MSG: &str = "Key (tenant_id, resource_id, timespan)=(hotel-a, ocean-view-room-713, [\"2022-12-26 22:00:00+00\",\"2022-12-30 19:00:00+00\")) conflicts with existing key (tenant_id, resource_id, timespan)=(hotel-a, ocean-view-room-713, [\"2022-12-25 22:00:00+00\",\"2022-12-28 19:00:00+00\")).";
+        let info: ReservationConflictInfo = TENANT_ERROR_MSG.parse().unwrap();
+        let ReservationConflictInfo::Parsed(info) = info else {
+            panic!("conflict info should be parsed");
+        };
+        assert_eq!(info.new.rid, "ocean-view-room-713");
+        assert_eq!(
+            info.new.start,
+            "2022-12-26 22:00:00+0000".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(info.old.rid, "ocean-view-room-713");
+        assert_eq!(
+            info.old.end,
+            "2022-12-28 19:00:00+0000".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[test]
+    pub fn reservation_conflict_info_parse_with_default_tenant_should_work() {
+        const DEFAULT_TENANT_ERROR_MSG: &str = "Key (tenant_id, resource_id, timespan)=(, ocean-view-room-713, [\"2022-12-26 22:00:00+00\",\"2022-12-30 19:00:00+00\")) conflicts with existing key (tenant_id, resource_id, timespan)=(, ocean-view-room-713, [\"2022-12-25 22:00:00+00\",\"2022-12-28 19:00:00+00\")).";
+        let info: ReservationConflictInfo = DEFAULT_TENANT_ERROR_MSG.parse().unwrap();
+        let ReservationConflictInfo::Parsed(info) = info else {
+            panic!("conflict info should be parsed");
+        };
+        assert_eq!(info.new.rid, "ocean-view-room-713");
+        assert_eq!(info.old.rid, "ocean-view-room-713");
+    }
+
+    #[test]
+    pub fn reservation_conflict_info_parse_with_blocked_window_should_work() {
+        const BLOCKED_ERROR_MSG: &str = "Key (tenant_id, resource_id, blocked)=(, ocean-view-room-713, [\"2022-12-26 22:00:00+00\",\"2022-12-30 19:30:00+00\")) conflicts with existing key (tenant_id, resource_id, blocked)=(, ocean-view-room-713, [\"2022-12-25 22:00:00+00\",\"2022-12-28 19:30:00+00\")).";
+        let info: ReservationConflictInfo = BLOCKED_ERROR_MSG.parse().unwrap();
+        let ReservationConflictInfo::Parsed(info) = info else {
+            panic!("conflict info should be parsed");
+        };
+        assert_eq!(
+            info.old.end,
+            "2022-12-28 19:30:00+0000".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[test]
+    pub fn reservation_conflict_info_parse_with_fractional_seconds_should_work() {
+        const FRACTIONAL_ERROR_MSG: &str = "Key (tenant_id, resource_id, blocked)=(, desk-1, [\"2026-10-18 16:43:09.709706+00\",\"2026-10-18 19:43:09.709706+00\")) conflicts with existing key (tenant_id, resource_id, blocked)=(, desk-1, [\"2026-10-18 16:43:09+00\",\"2026-10-18 17:43:09.801501+00\")).";
+        let info: ReservationConflictInfo = FRACTIONAL_ERROR_MSG.parse().unwrap();
+        let ReservationConflictInfo::Parsed(info) = info else {
+            panic!("conflict info should be parsed");
+        };
+        assert_eq!(
+            info.new.start,
+            "2026-10-18 16:43:09.709706+0000"
+                .parse::<DateTime<Utc>>()
+                .unwrap()
+        );
+        assert_eq!(
+            info.old.start,
+            "2026-10-18 16:43:09+0000".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
 }
@@ -1,9 +1,13 @@
 mod conflict;
+mod policy;
+mod slot;
 
 use sqlx::postgres::PgDatabaseError;
 use thiserror::Error;
 
 pub use conflict::{ReservationConflictInfo, ReservationWindow};
+pub use policy::PolicyViolation;
+pub use slot::SlotViolation;
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -16,6 +20,12 @@ pub enum Error {
     #[error("Invalid resource id: {0}")]
     InvalidResourceId(String),
 
+    #[error("Invalid attendee: {0}")]
+    InvalidAttendee(String),
+
+    #[error("Invalid tenant id: {0}")]
+    InvalidTenantId(String),
+
     #[error("Invalid start or end time for the reservation")]
     InvalidTime,
 
@@ -28,6 +38,54 @@ pub enum Error {
     #[error("Conflict reservation")]
     ConflictReservation(ReservationConflictInfo),
 
+    #[error("Invalid calendar: {0}")]
+    InvalidCalendar(String),
+
+    #[error("Outside opening hours: {0}")]
+    OutsideOpeningHours(String),
+
+    #[error("Invalid slot: {0}")]
+    InvalidSlot(SlotViolation),
+
+    #[error("Invalid cancellation policy: {0}")]
+    InvalidCancellationPolicy(String),
+
+    #[error("Policy violation: {0}")]
+    PolicyViolation(PolicyViolation),
+
+    #[error("Invalid reservation state: {0}")]
+    InvalidState(String),
+
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("Over capacity: {0}")]
+    OverCapacity(String),
+
+    #[error("No free resource in pool: {0}")]
+    PoolExhausted(String),
+
+    #[error("Invalid currency: {0}")]
+    InvalidCurrency(String),
+
+    #[error("Invalid rate: {0}")]
+    InvalidRate(String),
+
+    #[error("No rate applies: {0}")]
+    NoRate(String),
+
+    #[error("Invalid webhook: {0}")]
+    InvalidWebhook(String),
+
+    #[error("Invalid report: {0}")]
+    InvalidReport(String),
+
+    #[error("Invalid export: {0}")]
+    InvalidExport(String),
+
+    #[error("Invalid import: {0}")]
+    InvalidImport(String),
+
     #[error("Failed to read configuration file")]
     ConfigReadError,
 
@@ -72,10 +130,28 @@ impl PartialEq for Error {
             (Self::DbError(_), Self::DbError(_)) => true,
             (Self::InvalidTime, Self::InvalidTime) => true,
             (Self::ConflictReservation(v1), Self::ConflictReservation(v2)) => v1 == v2,
+            (Self::PolicyViolation(v1), Self::PolicyViolation(v2)) => v1 == v2,
+            (Self::InvalidCalendar(v1), Self::InvalidCalendar(v2)) => v1 == v2,
+            (Self::OutsideOpeningHours(v1), Self::OutsideOpeningHours(v2)) => v1 == v2,
+            (Self::InvalidSlot(v1), Self::InvalidSlot(v2)) => v1 == v2,
+            (Self::InvalidCancellationPolicy(v1), Self::InvalidCancellationPolicy(v2)) => v1 == v2,
+            (Self::InvalidState(v1), Self::InvalidState(v2)) => v1 == v2,
+            (Self::PermissionDenied(v1), Self::PermissionDenied(v2)) => v1 == v2,
+            (Self::OverCapacity(v1), Self::OverCapacity(v2)) => v1 == v2,
+            (Self::PoolExhausted(v1), Self::PoolExhausted(v2)) => v1 == v2,
+            (Self::InvalidCurrency(v1), Self::InvalidCurrency(v2)) => v1 == v2,
+            (Self::InvalidRate(v1), Self::InvalidRate(v2)) => v1 == v2,
+            (Self::NoRate(v1), Self::NoRate(v2)) => v1 == v2,
+            (Self::InvalidWebhook(v1), Self::InvalidWebhook(v2)) => v1 == v2,
+            (Self::InvalidReport(v1), Self::InvalidReport(v2)) => v1 == v2,
+            (Self::InvalidExport(v1), Self::InvalidExport(v2)) => v1 == v2,
+            (Self::InvalidImport(v1), Self::InvalidImport(v2)) => v1 == v2,
             (Self::NotFound, Self::NotFound) => true,
             (Self::InvalidReservationId(v1), Self::InvalidReservationId(v2)) => v1 == v2,
             (Self::InvalidUserId(v1), Self::InvalidUserId(v2)) => v1 == v2,
             (Self::InvalidResourceId(v1), Self::InvalidResourceId(v2)) => v1 == v2,
+            (Self::InvalidTenantId(v1), Self::InvalidTenantId(v2)) => v1 == v2,
+            (Self::InvalidAttendee(v1), Self::InvalidAttendee(v2)) => v1 == v2,
             (Self::Unknown, Self::Unknown) => true,
             _ => false,
         }
@@ -92,12 +168,30 @@ impl From<Error> for tonic::Status {
             | Error::InvalidReservationId(_)
             | Error::InvalidUserId(_)
             | Error::InvalidResourceId(_)
+            | Error::InvalidTenantId(_)
+            | Error::InvalidAttendee(_)
             | Error::InvalidPageSize(_)
             | Error::InvalidCursor(_)
-            | Error::InvalidStatus(_) => tonic::Status::invalid_argument(e.to_string()),
+            | Error::InvalidStatus(_)
+            | Error::InvalidCalendar(_)
+            | Error::OutsideOpeningHours(_)
+            | Error::InvalidSlot(_)
+            | Error::InvalidCancellationPolicy(_)
+            | Error::InvalidCurrency(_)
+            | Error::InvalidRate(_)
+            | Error::InvalidWebhook(_)
+            | Error::InvalidReport(_)
+            | Error::InvalidExport(_)
+            | Error::InvalidImport(_) => tonic::Status::invalid_argument(e.to_string()),
             Error::ConflictReservation(info) => {
                 tonic::Status::failed_precondition(format!("Conflict reservation: {:?}", info))
             }
+            Error::PolicyViolation(_)
+            | Error::InvalidState(_)
+            | Error::OverCapacity(_)
+            | Error::PoolExhausted(_)
+            | Error::NoRate(_) => tonic::Status::failed_precondition(e.to_string()),
+            Error::PermissionDenied(_) => tonic::Status::permission_denied(e.to_string()),
             Error::NotFound => {
                 tonic::Status::not_found("No reservation found by the given condition")
             }
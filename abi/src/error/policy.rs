@@ -0,0 +1,44 @@
+use std::fmt::{self, Display};
+
+/// the booking policy rule a reservation breaks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyViolation {
+    /// the user already holds the maximum number of active reservations
+    MaxActiveReservations(u32),
+    /// the reservation is longer than the maximum duration in seconds
+    MaxDuration(u64),
+    /// the reservation starts further in the future than allowed, in seconds
+    MaxAdvance(u64),
+    /// the reservation starts sooner than the minimum lead time in seconds
+    MinLeadTime(u64),
+    /// the reservation is cancelled with less notice than the shortest cancellation tier, in seconds
+    CancellationNotice(u64),
+}
+
+impl Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyViolation::MaxActiveReservations(limit) => {
+                write!(f, "at most {limit} active reservations per user")
+            }
+            PolicyViolation::MaxDuration(limit) => {
+                write!(f, "reservation must not be longer than {limit} seconds")
+            }
+            PolicyViolation::MaxAdvance(limit) => {
+                write!(f, "reservation must start within {limit} seconds from now")
+            }
+            PolicyViolation::MinLeadTime(limit) => {
+                write!(
+                    f,
+                    "reservation must start at least {limit} seconds from now"
+                )
+            }
+            PolicyViolation::CancellationNotice(limit) => {
+                write!(
+                    f,
+                    "reservation must be cancelled at least {limit} seconds before it starts"
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::{self, Display};
+
+use crate::UtcWindow;
+
+/// a reservation window breaking the slot rules of its resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotViolation {
+    /// the rule broken
+    pub reason: String,
+    /// the valid windows closest to the requested one
+    pub nearest: Vec<UtcWindow>,
+}
+
+impl Display for SlotViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)?;
+        if !self.nearest.is_empty() {
+            let nearest = self
+                .nearest
+                .iter()
+                .map(|(start, end)| format!("{} - {}", start.to_rfc3339(), end.to_rfc3339()))
+                .collect::<Vec<_>>();
+            write!(f, ", nearest valid windows: {}", nearest.join(", "))?;
+        }
+        Ok(())
+    }
+}
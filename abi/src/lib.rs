@@ -9,11 +9,15 @@ pub use config::*;
 pub use error::*;
 pub use pager::*;
 pub use pb::*;
+pub use types::*;
 pub use utils::*;
 
+pub use rust_decimal::Decimal;
+
 pub type ReservationId = i64;
 pub type UserId = String;
 pub type ResourceId = String;
+pub type TenantId = String;
 
 /// validate the data structure, raise error if invalid
 pub trait Validator {
@@ -27,6 +31,48 @@ pub enum RsvpStatus {
     Pending,
     Confirmed,
     Blocked,
+    Preempted,
+    #[sqlx(rename = "no_show")]
+    NoShow,
+    #[sqlx(rename = "awaiting_approval")]
+    AwaitingApproval,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "rsvp.attendee_role", rename_all = "snake_case")]
+pub enum RsvpAttendeeRole {
+    Unknown,
+    Required,
+    Optional,
+    Guest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "rsvp.attendee_response", rename_all = "snake_case")]
+pub enum RsvpAttendeeResponse {
+    Unknown,
+    NeedsAction,
+    Accepted,
+    Declined,
+    Tentative,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "rsvp.pool_strategy", rename_all = "snake_case")]
+pub enum RsvpPoolStrategy {
+    Unknown,
+    FirstFit,
+    LeastRecentlyUsed,
+    BestFit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "rsvp.rate_unit", rename_all = "snake_case")]
+pub enum RsvpRateUnit {
+    Unknown,
+    Hour,
+    Day,
 }
 
 impl Validator for ReservationId {
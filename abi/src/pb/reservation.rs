@@ -1,4 +1,14 @@
 // This file is @generated by prost-build.
+/// an amount of money
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Money {
+    /// ISO 4217 code, e.g. "USD"
+    #[prost(string, tag = "1")]
+    pub currency: ::prost::alloc::string::String,
+    /// decimal amount, e.g. "12.50"
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+}
 /// Core reservation object. Contains all the information for a reservation
 /// if ListenResponse op is DELETE, only id will be populated
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -24,18 +34,161 @@ pub struct Reservation {
     /// extra note
     #[prost(string, tag = "7")]
     pub note: ::prost::alloc::string::String,
+    /// tenant the reservation belongs to, always derived from the request metadata by the server
+    #[prost(string, tag = "8")]
+    pub tenant_id: ::prost::alloc::string::String,
+    /// a reservation may only preempt reservations of lower priority
+    #[prost(int32, tag = "9")]
+    pub priority: i32,
+    /// when the guest checked in, empty if not checked in yet
+    #[prost(message, optional, tag = "10")]
+    pub checked_in_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// when the guest checked out, empty if not checked out yet
+    #[prost(message, optional, tag = "11")]
+    pub checked_out_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// user the reservation is being transferred to, empty if no transfer is waiting for acceptance
+    #[prost(string, tag = "12")]
+    pub transfer_to: ::prost::alloc::string::String,
+    /// accepted beyond capacity by the overbooking ratio of the resource type, it does not hold
+    /// its window until confirmed
+    #[prost(bool, tag = "13")]
+    pub overbooked: bool,
+    /// computed from the rate plan of the resource when the reservation was made, empty if
+    /// the resource has no rate plan
+    #[prost(message, optional, tag = "14")]
+    pub price: ::core::option::Option<Money>,
+}
+/// A bookable resource. Reservations may refer to unregistered resources, which behave
+/// like a resource of type "default" without any extra settings
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Resource {
+    /// resource id, the same id used by Reservation.resource_id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// resource type, used to look up the booking policy. If empty, "default" is used
+    #[prost(string, tag = "2")]
+    pub resource_type: ::prost::alloc::string::String,
+    /// tenant the resource belongs to, always derived from the request metadata by the server
+    #[prost(string, tag = "3")]
+    pub tenant_id: ::prost::alloc::string::String,
+    /// turnaround time blocked before every reservation, in seconds. Changes apply to
+    /// reservations made or rescheduled afterwards
+    #[prost(uint64, tag = "4")]
+    pub pre_buffer_secs: u64,
+    /// turnaround time blocked after every reservation, in seconds
+    #[prost(uint64, tag = "5")]
+    pub post_buffer_secs: u64,
+    /// IANA time zone of the opening hours and closures, e.g. "Asia/Shanghai". If empty, UTC is used
+    #[prost(string, tag = "6")]
+    pub time_zone: ::prost::alloc::string::String,
+    /// weekly opening hours. If empty, the resource is open all the time except on closures
+    #[prost(message, repeated, tag = "7")]
+    pub opening_hours: ::prost::alloc::vec::Vec<OpeningHours>,
+    /// days on which the resource is closed
+    #[prost(message, repeated, tag = "8")]
+    pub closures: ::prost::alloc::vec::Vec<Closure>,
+    /// reservations are made in slots of this many seconds. If 0, any window is accepted
+    #[prost(uint64, tag = "9")]
+    pub slot_granularity_secs: u64,
+    /// slots start this many seconds after local midnight plus a multiple of the granularity
+    #[prost(uint64, tag = "10")]
+    pub slot_offset_secs: u64,
+    /// minimum number of slots of a reservation. If 0, no minimum
+    #[prost(uint32, tag = "11")]
+    pub min_slots: u32,
+    /// maximum number of slots of a reservation. If 0, no maximum
+    #[prost(uint32, tag = "12")]
+    pub max_slots: u32,
+    /// reservations wait in awaiting_approval, holding their window, until an approver decides
+    #[prost(bool, tag = "13")]
+    pub requires_approval: bool,
+    /// users who may approve or reject reservations of the resource
+    #[prost(string, repeated, tag = "14")]
+    pub approvers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// the enclosing resource, e.g. the floor of a room. A reservation conflicts with the
+    /// reservations of every ancestor and descendant. Empty for a top level resource
+    #[prost(string, tag = "15")]
+    pub parent_id: ::prost::alloc::string::String,
+    /// cancelling costs the fee of the tier with the longest notice the cancellation still
+    /// gives. Cancellations giving less notice than every tier, e.g. after the start, are
+    /// rejected. If empty, reservations can be cancelled for free at any time
+    #[prost(message, repeated, tag = "16")]
+    pub cancellation_tiers: ::prost::alloc::vec::Vec<CancellationTier>,
+}
+/// Cancelling at least min_notice_secs before the start costs fee_percent of the price
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CancellationTier {
+    #[prost(uint64, tag = "1")]
+    pub min_notice_secs: u64,
+    /// 0 to 100
+    #[prost(uint32, tag = "2")]
+    pub fee_percent: u32,
+}
+/// Opening hours on a day of the week, in the resource's time zone
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpeningHours {
+    /// ISO weekday, 1 is Monday and 7 is Sunday
+    #[prost(uint32, tag = "1")]
+    pub weekday: u32,
+    /// local opening time in HH:MM
+    #[prost(string, tag = "2")]
+    pub opens: ::prost::alloc::string::String,
+    /// local closing time in HH:MM, "24:00" closes at the end of the day
+    #[prost(string, tag = "3")]
+    pub closes: ::prost::alloc::string::String,
+}
+/// A day on which the resource is closed all day, e.g. a holiday
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Closure {
+    /// local date in YYYY-MM-DD
+    #[prost(string, tag = "1")]
+    pub day: ::prost::alloc::string::String,
+    /// extra note, e.g. the name of the holiday
+    #[prost(string, tag = "2")]
+    pub note: ::prost::alloc::string::String,
+}
+/// Booking limits for all resources of a resource type. Unset limits are not enforced
+#[derive(Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BookingPolicy {
+    /// resource type the policy applies to
+    #[prost(string, tag = "1")]
+    pub resource_type: ::prost::alloc::string::String,
+    /// maximum active (pending or confirmed, not yet ended) reservations per user
+    #[prost(uint32, optional, tag = "2")]
+    pub max_active_reservations: ::core::option::Option<u32>,
+    /// maximum duration of a single reservation in seconds
+    #[prost(uint64, optional, tag = "3")]
+    pub max_duration_secs: ::core::option::Option<u64>,
+    /// how far in the future a reservation may start, in seconds
+    #[prost(uint64, optional, tag = "4")]
+    pub max_advance_secs: ::core::option::Option<u64>,
+    /// minimum time between now and the start of a reservation, in seconds
+    #[prost(uint64, optional, tag = "5")]
+    pub min_lead_time_secs: ::core::option::Option<u64>,
+    /// pending reservations may exceed the capacity of the resource type, the number of its
+    /// registered resources, by this percentage. Confirmation still needs a free window
+    #[prost(uint32, optional, tag = "6")]
+    pub overbooking_percent: ::core::option::Option<u32>,
 }
 /// To make a reservation, send a ReservationRequest with Reservation object (id should be empty)
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReserveRequest {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
+    /// move conflicting reservations of lower priority to preempted, requires the preempt role
+    #[prost(bool, tag = "2")]
+    pub preempt: bool,
 }
 /// Created reservation will be returned in ReserveResponse
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReserveResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
+    /// reservations moved to preempted to make room
+    #[prost(message, repeated, tag = "2")]
+    pub preempted: ::prost::alloc::vec::Vec<Reservation>,
 }
 /// To update a reservation, send an UpdateRequest. Only note is updatable.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -51,6 +204,182 @@ pub struct UpdateResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
+/// To move a reservation to another window of the same resource, send a RescheduleRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RescheduleRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(message, optional, tag = "2")]
+    pub window: ::core::option::Option<TimeWindow>,
+}
+/// Rescheduled reservation will be returned in RescheduleResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RescheduleResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To release the rest of a reservation, send an EndEarlyRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EndEarlyRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// new end time, must be inside the current window. If empty, end now
+    #[prost(message, optional, tag = "2")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Shortened reservation will be returned in EndEarlyResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EndEarlyResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To make a reservation end later, send an ExtendRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ExtendRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// new end time, must be after the current end
+    #[prost(message, optional, tag = "2")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// if the new end collides with the next reservation, extend up to it instead of failing
+    #[prost(bool, tag = "3")]
+    pub best_effort: bool,
+}
+/// Extended reservation will be returned in ExtendResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtendResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To split a reservation in two at a point in time, send a SplitRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SplitRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// must be inside the reservation window
+    #[prost(message, optional, tag = "2")]
+    pub at: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// The shortened reservation and the new one for the rest of the window, in that order
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplitResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To combine contiguous reservations of the same user and resource, send a MergeRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeRequest {
+    #[prost(int64, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i64>,
+}
+/// The earliest reservation covers the merged window, the others are deleted
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To hand a reservation over to another user, send a TransferRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(string, tag = "2")]
+    pub to_user_id: ::prost::alloc::string::String,
+    /// if set, the reservation only changes hands once the recipient accepts
+    #[prost(bool, tag = "3")]
+    pub require_acceptance: bool,
+}
+/// Transferred reservation will be returned in TransferResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To accept a transfer as its recipient, send an AcceptTransferRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AcceptTransferRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+/// Reservation owned by the recipient will be returned in AcceptTransferResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcceptTransferResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To decline a transfer as its recipient, or to withdraw it as the owner, send a
+/// DeclineTransferRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeclineTransferRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+/// Reservation without the transfer will be returned in DeclineTransferResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeclineTransferResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To approve a reservation awaiting approval, send an ApproveRequest. The caller must be an
+/// approver of the resource
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ApproveRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+/// Confirmed reservation will be returned in ApproveResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApproveResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To reject a reservation awaiting approval, send a RejectRequest. The caller must be an
+/// approver of the resource
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RejectRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+/// Rejected reservation will be returned in RejectResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RejectResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To list the reservations the caller may approve, send an ApprovalInboxRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ApprovalInboxRequest {}
+/// Reservations awaiting approval of the caller, ordered by start time
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApprovalInboxResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To record the guest has arrived, send a CheckInRequest. Only confirmed reservations which
+/// have not ended can be checked in
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CheckInRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+/// Checked in reservation will be returned in CheckInResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckInResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To record the guest has left, send a CheckOutRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CheckOutRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+/// Checked out reservation will be returned in CheckOutResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckOutResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
 /// To change a reservation from pending to confirmed, send a ConfirmRequest
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ConfirmRequest {
@@ -68,12 +397,19 @@ pub struct ConfirmResponse {
 pub struct CancelRequest {
     #[prost(int64, tag = "1")]
     pub id: i64,
+    /// cancel regardless of the cancellation policy and without a fee, requires the admin role
+    #[prost(bool, tag = "2")]
+    pub override_policy: bool,
 }
 /// Canceled reservation will be returned in CancelResponse
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CancelResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
+    /// fee charged by the cancellation policy of the resource, empty if the reservation has
+    /// no price
+    #[prost(message, optional, tag = "2")]
+    pub fee: ::core::option::Option<Money>,
 }
 /// To get a reservation, send a GetRequest
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
@@ -114,6 +450,10 @@ pub struct ReservationQuery {
     #[prost(bool, tag = "6")]
     #[builder(setter(into), default)]
     pub desc: bool,
+    /// only reservations the user attends. If empty, no restriction
+    #[prost(string, tag = "7")]
+    #[builder(setter(into), default)]
+    pub attendee_id: ::prost::alloc::string::String,
 }
 /// To query reservations, send a QueryRequest
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -147,6 +487,10 @@ pub struct ReservationFilter {
     #[prost(bool, tag = "6")]
     #[builder(setter(into), default)]
     pub desc: bool,
+    /// only reservations the user attends. If empty, no restriction
+    #[prost(string, tag = "7")]
+    #[builder(setter(into), default)]
+    pub attendee_id: ::prost::alloc::string::String,
 }
 /// To query reservations, send a QueryRequest
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -184,451 +528,3941 @@ pub struct ListenResponse {
     #[prost(message, optional, tag = "2")]
     pub reservation: ::core::option::Option<Reservation>,
 }
-/// reservation status for a given time period
-#[derive(
-    sqlx::Type, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
-)]
-#[repr(i32)]
-pub enum ReservationStatus {
-    Unknown = 0,
-    Pending = 1,
-    Confirmed = 2,
-    Blocked = 3,
+/// To register or update a resource, send a SaveResourceRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveResourceRequest {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<Resource>,
 }
-impl ReservationStatus {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
-    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-    pub fn as_str_name(&self) -> &'static str {
-        match self {
-            Self::Unknown => "RESERVATION_STATUS_UNKNOWN",
-            Self::Pending => "RESERVATION_STATUS_PENDING",
-            Self::Confirmed => "RESERVATION_STATUS_CONFIRMED",
-            Self::Blocked => "RESERVATION_STATUS_BLOCKED",
-        }
-    }
-    /// Creates an enum from field names used in the ProtoBuf definition.
-    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-        match value {
-            "RESERVATION_STATUS_UNKNOWN" => Some(Self::Unknown),
-            "RESERVATION_STATUS_PENDING" => Some(Self::Pending),
-            "RESERVATION_STATUS_CONFIRMED" => Some(Self::Confirmed),
-            "RESERVATION_STATUS_BLOCKED" => Some(Self::Blocked),
-            _ => None,
-        }
-    }
+/// Saved resource will be returned in SaveResourceResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveResourceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<Resource>,
 }
-/// when reservation is updated, record the update type
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
-pub enum ReservationUpdateType {
-    Unknown = 0,
-    Create = 1,
-    Update = 2,
-    Delete = 3,
+/// To get a resource, send a GetResourceRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetResourceRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
 }
-impl ReservationUpdateType {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
-    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-    pub fn as_str_name(&self) -> &'static str {
-        match self {
-            Self::Unknown => "RESERVATION_UPDATE_TYPE_UNKNOWN",
-            Self::Create => "RESERVATION_UPDATE_TYPE_CREATE",
-            Self::Update => "RESERVATION_UPDATE_TYPE_UPDATE",
-            Self::Delete => "RESERVATION_UPDATE_TYPE_DELETE",
-        }
-    }
-    /// Creates an enum from field names used in the ProtoBuf definition.
-    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-        match value {
-            "RESERVATION_UPDATE_TYPE_UNKNOWN" => Some(Self::Unknown),
-            "RESERVATION_UPDATE_TYPE_CREATE" => Some(Self::Create),
-            "RESERVATION_UPDATE_TYPE_UPDATE" => Some(Self::Update),
-            "RESERVATION_UPDATE_TYPE_DELETE" => Some(Self::Delete),
-            _ => None,
-        }
-    }
+/// Resource will be returned in GetResourceResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetResourceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<Resource>,
 }
-/// Generated client implementations.
-pub mod reservation_service_client {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value
-    )]
-    use tonic::codegen::http::Uri;
-    use tonic::codegen::*;
-    /// Reservation service
-    #[derive(Debug, Clone)]
-    pub struct ReservationServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl ReservationServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
-    }
-    impl<T> ReservationServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::Body>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> ReservationServiceClient<InterceptedService<T, F>>
-        where
-            F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                    http::Request<tonic::body::Body>,
-                    Response = http::Response<
-                        <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
-                    >,
-                >,
-            <T as tonic::codegen::Service<http::Request<tonic::body::Body>>>::Error:
-                Into<StdError> + std::marker::Send + std::marker::Sync,
-        {
-            ReservationServiceClient::new(InterceptedService::new(inner, interceptor))
-        }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
-        }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
-        }
-        /// make a reservation
-        pub async fn reserve(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ReserveRequest>,
-        ) -> std::result::Result<tonic::Response<super::ReserveResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path =
-                http::uri::PathAndQuery::from_static("/reservation.ReservationService/reserve");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "reserve"));
-            self.inner.unary(req, path, codec).await
-        }
-        /// confirm a pending reservation, if reservation is not pending, do nothing
-        pub async fn confirm(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ConfirmRequest>,
-        ) -> std::result::Result<tonic::Response<super::ConfirmResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path =
-                http::uri::PathAndQuery::from_static("/reservation.ReservationService/confirm");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "confirm"));
-            self.inner.unary(req, path, codec).await
-        }
-        /// update the reservation note
-        pub async fn update(
-            &mut self,
-            request: impl tonic::IntoRequest<super::UpdateRequest>,
-        ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path =
-                http::uri::PathAndQuery::from_static("/reservation.ReservationService/update");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "update"));
-            self.inner.unary(req, path, codec).await
-        }
-        /// cancel a reservation
-        pub async fn cancel(
-            &mut self,
-            request: impl tonic::IntoRequest<super::CancelRequest>,
-        ) -> std::result::Result<tonic::Response<super::CancelResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path =
-                http::uri::PathAndQuery::from_static("/reservation.ReservationService/cancel");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "cancel"));
-            self.inner.unary(req, path, codec).await
-        }
-        /// get a reservation by id
-        pub async fn get(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/reservation.ReservationService/get");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "get"));
-            self.inner.unary(req, path, codec).await
-        }
-        /// query reservations by resource id, user id, status, start time, end time
-        pub async fn query(
-            &mut self,
-            request: impl tonic::IntoRequest<super::QueryRequest>,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::Reservation>>,
-            tonic::Status,
-        > {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path =
-                http::uri::PathAndQuery::from_static("/reservation.ReservationService/query");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "query"));
-            self.inner.server_streaming(req, path, codec).await
-        }
-        /// filter reservations, order by reservation id
-        pub async fn filter(
-            &mut self,
-            request: impl tonic::IntoRequest<super::FilterRequest>,
-        ) -> std::result::Result<tonic::Response<super::FilterResponse>, tonic::Status> {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path =
-                http::uri::PathAndQuery::from_static("/reservation.ReservationService/filter");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "filter"));
-            self.inner.unary(req, path, codec).await
-        }
-        /// another system could monitor newly added/confirmed/cancelled reservations
-        pub async fn listen(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ListenRequest>,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::Reservation>>,
-            tonic::Status,
-        > {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
-            })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path =
-                http::uri::PathAndQuery::from_static("/reservation.ReservationService/listen");
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "listen"));
-            self.inner.server_streaming(req, path, codec).await
-        }
-    }
+/// A named set of interchangeable resources, e.g. the spots of a car park
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pool {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// tenant the pool belongs to, always derived from the request metadata by the server
+    #[prost(string, tag = "2")]
+    pub tenant_id: ::prost::alloc::string::String,
+    /// member resource ids, in the order first fit tries them
+    #[prost(string, repeated, tag = "3")]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(enumeration = "PoolStrategy", tag = "4")]
+    pub strategy: i32,
 }
-/// Generated server implementations.
-pub mod reservation_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ReservationServiceServer.
-    #[async_trait]
-    pub trait ReservationService: std::marker::Send + std::marker::Sync + 'static {
-        /// make a reservation
-        async fn reserve(
-            &self,
-            request: tonic::Request<super::ReserveRequest>,
-        ) -> std::result::Result<tonic::Response<super::ReserveResponse>, tonic::Status>;
-        /// confirm a pending reservation, if reservation is not pending, do nothing
-        async fn confirm(
-            &self,
-            request: tonic::Request<super::ConfirmRequest>,
-        ) -> std::result::Result<tonic::Response<super::ConfirmResponse>, tonic::Status>;
-        /// update the reservation note
-        async fn update(
-            &self,
-            request: tonic::Request<super::UpdateRequest>,
-        ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status>;
-        /// cancel a reservation
-        async fn cancel(
-            &self,
-            request: tonic::Request<super::CancelRequest>,
-        ) -> std::result::Result<tonic::Response<super::CancelResponse>, tonic::Status>;
-        /// get a reservation by id
-        async fn get(
-            &self,
-            request: tonic::Request<super::GetRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
-        /// Server streaming response type for the query method.
-        type queryStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::Reservation, tonic::Status>,
-            > + std::marker::Send
-            + 'static;
-        /// query reservations by resource id, user id, status, start time, end time
-        async fn query(
-            &self,
-            request: tonic::Request<super::QueryRequest>,
-        ) -> std::result::Result<tonic::Response<Self::queryStream>, tonic::Status>;
-        /// filter reservations, order by reservation id
-        async fn filter(
-            &self,
-            request: tonic::Request<super::FilterRequest>,
-        ) -> std::result::Result<tonic::Response<super::FilterResponse>, tonic::Status>;
-        /// Server streaming response type for the listen method.
-        type listenStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::Reservation, tonic::Status>,
-            > + std::marker::Send
-            + 'static;
-        /// another system could monitor newly added/confirmed/cancelled reservations
-        async fn listen(
-            &self,
-            request: tonic::Request<super::ListenRequest>,
-        ) -> std::result::Result<tonic::Response<Self::listenStream>, tonic::Status>;
-    }
-    /// Reservation service
-    #[derive(Debug)]
-    pub struct ReservationServiceServer<T> {
-        inner: Arc<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    impl<T> ReservationServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
-        }
-        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
-        }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
-        }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
-        }
+/// To register or replace a pool, send a SavePoolRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SavePoolRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pool: ::core::option::Option<Pool>,
+}
+/// Saved pool will be returned in SavePoolResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SavePoolResponse {
+    #[prost(message, optional, tag = "1")]
+    pub pool: ::core::option::Option<Pool>,
+}
+/// To get a pool by id, send a GetPoolRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPoolRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Pool found by id will be returned in GetPoolResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPoolResponse {
+    #[prost(message, optional, tag = "1")]
+    pub pool: ::core::option::Option<Pool>,
+}
+/// To reserve any free member of a pool, send a ReserveFromPoolRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveFromPoolRequest {
+    #[prost(string, tag = "1")]
+    pub pool_id: ::prost::alloc::string::String,
+    /// resource_id is ignored, the pool's strategy picks it
+    #[prost(message, optional, tag = "2")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// Created reservation, with the picked resource, will be returned in ReserveFromPoolResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveFromPoolResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// A price per unit, for the periods in the resource's time zone it matches
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Rate {
+    /// shown on quote lines, e.g. "weekend"
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "RateUnit", tag = "2")]
+    pub unit: i32,
+    /// decimal price per unit, in the currency of the tenant
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    /// ISO weekdays the rate applies to, 1 is Monday and 7 is Sunday. Empty for every day
+    #[prost(uint32, repeated, tag = "4")]
+    pub weekdays: ::prost::alloc::vec::Vec<u32>,
+    /// first local day the rate applies to in YYYY-MM-DD, empty for no limit
+    #[prost(string, tag = "5")]
+    pub valid_from: ::prost::alloc::string::String,
+    /// last local day the rate applies to in YYYY-MM-DD, empty for no limit
+    #[prost(string, tag = "6")]
+    pub valid_until: ::prost::alloc::string::String,
+}
+/// Rates of a resource. Of the rates matching a period, the last one in the plan wins, so list
+/// the base rate first and weekend or seasonal rates after it
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RatePlan {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// tenant the plan belongs to, always derived from the request metadata by the server
+    #[prost(string, tag = "2")]
+    pub tenant_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub rates: ::prost::alloc::vec::Vec<Rate>,
+}
+/// consecutive units charged at the same rate
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuoteLine {
+    /// name of the rate
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "RateUnit", tag = "2")]
+    pub unit: i32,
+    #[prost(uint32, tag = "3")]
+    pub quantity: u32,
+    /// decimal price per unit
+    #[prost(string, tag = "4")]
+    pub unit_amount: ::prost::alloc::string::String,
+    /// decimal price of all units, rounded to the currency
+    #[prost(string, tag = "5")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// price of a reservation, broken down by rate
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Quote {
+    #[prost(message, optional, tag = "1")]
+    pub total: ::core::option::Option<Money>,
+    #[prost(message, repeated, tag = "2")]
+    pub lines: ::prost::alloc::vec::Vec<QuoteLine>,
+}
+/// settings of the tenant of the request
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TenantSettings {
+    /// tenant the settings belong to, always derived from the request metadata by the server
+    #[prost(string, tag = "1")]
+    pub tenant_id: ::prost::alloc::string::String,
+    /// ISO 4217 code all prices of the tenant are in, "USD" if never set
+    #[prost(string, tag = "2")]
+    pub currency: ::prost::alloc::string::String,
+}
+/// To register or replace the rate plan of a resource, send a SaveRatePlanRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveRatePlanRequest {
+    #[prost(message, optional, tag = "1")]
+    pub rate_plan: ::core::option::Option<RatePlan>,
+}
+/// Saved rate plan will be returned in SaveRatePlanResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveRatePlanResponse {
+    #[prost(message, optional, tag = "1")]
+    pub rate_plan: ::core::option::Option<RatePlan>,
+}
+/// To get the rate plan of a resource, send a GetRatePlanRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRatePlanRequest {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+}
+/// Rate plan of the resource, without rates if it has none
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRatePlanResponse {
+    #[prost(message, optional, tag = "1")]
+    pub rate_plan: ::core::option::Option<RatePlan>,
+}
+/// To price a prospective reservation without making it, send a QuoteRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuoteRequest {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// The price the reservation would get if it was made now
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuoteResponse {
+    #[prost(message, optional, tag = "1")]
+    pub quote: ::core::option::Option<Quote>,
+}
+/// To change the settings of the tenant, send a SaveTenantSettingsRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveTenantSettingsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub settings: ::core::option::Option<TenantSettings>,
+}
+/// Saved settings will be returned in SaveTenantSettingsResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveTenantSettingsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub settings: ::core::option::Option<TenantSettings>,
+}
+/// To get the settings of the tenant, send a GetTenantSettingsRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetTenantSettingsRequest {}
+/// Settings of the tenant will be returned in GetTenantSettingsResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTenantSettingsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub settings: ::core::option::Option<TenantSettings>,
+}
+/// A downstream system receiving the reservation changes of its tenant as HTTP callbacks.
+/// Every change is POSTed as JSON, one at a time and in order per subscription
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WebhookSubscription {
+    /// assigned by the server, 0 to register a new subscription
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// tenant the subscription belongs to, always derived from the request metadata by the server
+    #[prost(string, tag = "2")]
+    pub tenant_id: ::prost::alloc::string::String,
+    /// http or https url receiving the callbacks
+    #[prost(string, tag = "3")]
+    pub url: ::prost::alloc::string::String,
+    /// key of the HMAC-SHA256 signature of the body, sent as "sha256=<hex>" in the
+    /// X-Rsvp-Signature header. Write only, never returned. If empty on update, it is kept
+    #[prost(string, tag = "4")]
+    pub secret: ::prost::alloc::string::String,
+    /// change types to deliver, every change if empty
+    #[prost(enumeration = "ReservationUpdateType", repeated, tag = "5")]
+    pub ops: ::prost::alloc::vec::Vec<i32>,
+    /// paused subscriptions keep queueing changes but nothing is delivered
+    #[prost(bool, tag = "6")]
+    pub active: bool,
+}
+/// A change which could not be delivered within the retry limit
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WebhookDeadLetter {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(int64, tag = "2")]
+    pub subscription_id: i64,
+    #[prost(int64, tag = "3")]
+    pub change_id: i64,
+    /// the JSON body of the callback
+    #[prost(string, tag = "4")]
+    pub payload: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "5")]
+    pub attempts: u32,
+    #[prost(string, tag = "6")]
+    pub last_error: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "7")]
+    pub failed_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// To register or update a webhook subscription, send a SaveWebhookRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveWebhookRequest {
+    #[prost(message, optional, tag = "1")]
+    pub subscription: ::core::option::Option<WebhookSubscription>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveWebhookResponse {
+    #[prost(message, optional, tag = "1")]
+    pub subscription: ::core::option::Option<WebhookSubscription>,
+}
+/// Undelivered changes of a deleted subscription are dropped
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeleteWebhookRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteWebhookResponse {
+    #[prost(message, optional, tag = "1")]
+    pub subscription: ::core::option::Option<WebhookSubscription>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListWebhooksRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListWebhooksResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub subscriptions: ::prost::alloc::vec::Vec<WebhookSubscription>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListDeadLettersRequest {
+    /// only dead letters of this subscription, all of them if 0
+    #[prost(int64, tag = "1")]
+    pub subscription_id: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDeadLettersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub dead_letters: ::prost::alloc::vec::Vec<WebhookDeadLetter>,
+}
+/// To add or replace the policy of a resource type, send a SetPolicyRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetPolicyRequest {
+    #[prost(message, optional, tag = "1")]
+    pub policy: ::core::option::Option<BookingPolicy>,
+}
+/// Policy in effect will be returned in SetPolicyResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetPolicyResponse {
+    #[prost(message, optional, tag = "1")]
+    pub policy: ::core::option::Option<BookingPolicy>,
+}
+/// To list all policies in effect, send a ListPoliciesRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListPoliciesRequest {}
+/// Policies in effect, ordered by resource type
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPoliciesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub policies: ::prost::alloc::vec::Vec<BookingPolicy>,
+}
+/// a window of time
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TimeWindow {
+    #[prost(message, optional, tag = "1")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// To find when a resource can be reserved, send an AvailabilityRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AvailabilityRequest {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// the window to search in
+    #[prost(message, optional, tag = "2")]
+    pub window: ::core::option::Option<TimeWindow>,
+}
+/// Free windows in the requested window, ordered by start time. Any reservation whose start
+/// and end lie within one of the windows can be made, turnaround buffers and opening hours
+/// are already honored
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AvailabilityResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub windows: ::prost::alloc::vec::Vec<TimeWindow>,
+}
+/// overbooked reservations of a resource type whose windows overlap each other
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OverbookedWindow {
+    #[prost(string, tag = "1")]
+    pub resource_type: ::prost::alloc::string::String,
+    /// from the earliest start to the latest end of the reservations
+    #[prost(message, optional, tag = "2")]
+    pub window: ::core::option::Option<TimeWindow>,
+    /// number of registered resources of the type
+    #[prost(int64, tag = "3")]
+    pub capacity: i64,
+    #[prost(message, repeated, tag = "4")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To find overbooked windows which have not ended yet, send an OverbookedWindowsRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OverbookedWindowsRequest {
+    /// empty for every resource type
+    #[prost(string, tag = "1")]
+    pub resource_type: ::prost::alloc::string::String,
+}
+/// Overbooked windows ordered by resource type and start time
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OverbookedWindowsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub windows: ::prost::alloc::vec::Vec<OverbookedWindow>,
+}
+/// To report how much resources were used in a period, send a UtilizationRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UtilizationRequest {
+    /// empty for every registered resource
+    #[prost(string, repeated, tag = "1")]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "2")]
+    pub period: ::core::option::Option<TimeWindow>,
+    #[prost(enumeration = "ReportBucket", tag = "3")]
+    pub bucket: i32,
+    /// IANA name of the time zone buckets and peak hours are computed in, empty for UTC
+    #[prost(string, tag = "4")]
+    pub time_zone: ::prost::alloc::string::String,
+}
+/// booked time within one bucket of the period
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct UtilizationBucket {
+    /// the first bucket starts with the period, the last one ends with it
+    #[prost(message, optional, tag = "1")]
+    pub window: ::core::option::Option<TimeWindow>,
+    #[prost(double, tag = "2")]
+    pub booked_hours: f64,
+    /// booked hours in percent of the hours of the bucket
+    #[prost(double, tag = "3")]
+    pub utilization_percent: f64,
+}
+/// number of reservations in a status
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StatusCount {
+    #[prost(enumeration = "ReservationStatus", tag = "1")]
+    pub status: i32,
+    #[prost(int64, tag = "2")]
+    pub count: i64,
+}
+/// how much a resource was used in the period. Booked time counts every reservation holding
+/// its window, including those of its ancestors and descendants, overlapping reservations count
+/// once
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResourceUtilization {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(double, tag = "2")]
+    pub booked_hours: f64,
+    /// booked hours in percent of the hours of the period
+    #[prost(double, tag = "3")]
+    pub utilization_percent: f64,
+    /// reservations overlapping the period, ordered by status
+    #[prost(message, repeated, tag = "4")]
+    pub status_counts: ::prost::alloc::vec::Vec<StatusCount>,
+    /// hours of the day (0-23) with the most booked time over the period, empty if nothing
+    /// was booked
+    #[prost(uint32, repeated, tag = "5")]
+    pub peak_hours: ::prost::alloc::vec::Vec<u32>,
+    /// share of the confirmed reservations which started already that nobody checked in to,
+    /// from 0 to 1
+    #[prost(double, tag = "6")]
+    pub no_show_rate: f64,
+    #[prost(message, repeated, tag = "7")]
+    pub buckets: ::prost::alloc::vec::Vec<UtilizationBucket>,
+}
+/// Utilization ordered by resource id
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UtilizationResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub resources: ::prost::alloc::vec::Vec<ResourceUtilization>,
+}
+/// To render a timeline of many resources, send an OccupancyRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OccupancyRequest {
+    /// empty for every registered resource
+    #[prost(string, repeated, tag = "1")]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "2")]
+    pub window: ::core::option::Option<TimeWindow>,
+    /// bucket i starts i * bucket_secs after the window, the last one ends with the window
+    #[prost(uint64, tag = "3")]
+    pub bucket_secs: u64,
+}
+/// states of the buckets of one resource, in the order of the buckets
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OccupancyTimeline {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "OccupancyState", repeated, tag = "2")]
+    pub states: ::prost::alloc::vec::Vec<i32>,
+}
+/// Timelines ordered by resource id
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OccupancyResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub timelines: ::prost::alloc::vec::Vec<OccupancyTimeline>,
+}
+/// To dump reservations, send an ExportRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportRequest {
+    #[prost(enumeration = "ExportFormat", tag = "3")]
+    pub format: i32,
+    /// columns or JSON fields in order, empty for all of them: id, tenant_id, user_id,
+    /// resource_id, status, start, end, note, priority, checked_in_at, checked_out_at,
+    /// transfer_to, overbooked, price, currency
+    #[prost(string, repeated, tag = "4")]
+    pub columns: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// IANA name of the time zone times are rendered in, empty for UTC
+    #[prost(string, tag = "5")]
+    pub time_zone: ::prost::alloc::string::String,
+    #[prost(oneof = "export_request::Selection", tags = "1, 2")]
+    pub selection: ::core::option::Option<export_request::Selection>,
+}
+/// Nested message and enum types in `ExportRequest`.
+pub mod export_request {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Selection {
+        /// reservations within its window, ordered by start time
+        #[prost(message, tag = "1")]
+        Query(super::ReservationQuery),
+        /// reservations after its cursor, ordered by id, the page size is ignored
+        #[prost(message, tag = "2")]
+        Filter(super::ReservationFilter),
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for ReservationServiceServer<T>
-    where
-        T: ReservationService,
-        B: Body + std::marker::Send + 'static,
-        B::Error: Into<StdError> + std::marker::Send + 'static,
-    {
-        type Response = http::Response<tonic::body::Body>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
-            &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
-        }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            match req.uri().path() {
-                "/reservation.ReservationService/reserve" => {
+}
+/// a piece of an export, the chunks in order make up the whole file
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportChunk {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// a piece of an import, the chunks in order make up the whole file. The columns or JSON
+/// fields are those of an export: user_id, resource_id, start and end are required, note is
+/// optional, other export columns are ignored. Rows are reserved like new reservations, pending
+/// or awaiting approval at the default priority, and priced. Times need a UTC offset
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportChunk {
+    /// format and mode are taken from the first chunk
+    #[prost(enumeration = "ExportFormat", tag = "1")]
+    pub format: i32,
+    #[prost(enumeration = "ImportMode", tag = "2")]
+    pub mode: i32,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// a row of an import which could not be parsed or was invalid
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportRowError {
+    /// number of the row, counting from 1, without the CSV header and empty lines
+    #[prost(uint64, tag = "1")]
+    pub row: u64,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+/// the window blocked by a reservation, turnaround buffers included
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConflictWindow {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub window: ::core::option::Option<TimeWindow>,
+}
+/// a row of an import conflicting with an existing reservation or an earlier row
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportConflict {
+    #[prost(uint64, tag = "1")]
+    pub row: u64,
+    #[prost(message, optional, tag = "2")]
+    pub new: ::core::option::Option<ConflictWindow>,
+    #[prost(message, optional, tag = "3")]
+    pub existing: ::core::option::Option<ConflictWindow>,
+    /// the conflict as reported by the database if its windows could not be parsed
+    #[prost(string, tag = "4")]
+    pub detail: ::prost::alloc::string::String,
+}
+/// report of an import
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportResponse {
+    /// rows which were imported, 0 if the import was not committed
+    #[prost(uint64, tag = "1")]
+    pub accepted: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub conflicts: ::prost::alloc::vec::Vec<ImportConflict>,
+    #[prost(message, repeated, tag = "3")]
+    pub invalid: ::prost::alloc::vec::Vec<ImportRowError>,
+    /// whether the valid rows were saved
+    #[prost(bool, tag = "4")]
+    pub committed: bool,
+}
+/// a request to be given the window once it becomes free
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WaitlistEntry {
+    /// unique id for the entry, if put into JoinWaitlistRequest, id should be empty
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(string, tag = "2")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "5")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// note of the reservation made for the entry
+    #[prost(string, tag = "6")]
+    pub note: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub tenant_id: ::prost::alloc::string::String,
+    /// id of the pending reservation the entry was converted into, 0 while still waiting
+    #[prost(int64, tag = "8")]
+    pub reservation_id: i64,
+}
+/// To wait for a window which is already reserved, send a JoinWaitlistRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JoinWaitlistRequest {
+    #[prost(message, optional, tag = "1")]
+    pub entry: ::core::option::Option<WaitlistEntry>,
+}
+/// Queued entry will be returned in JoinWaitlistResponse. If the window is already free,
+/// the entry is converted right away
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JoinWaitlistResponse {
+    #[prost(message, optional, tag = "1")]
+    pub entry: ::core::option::Option<WaitlistEntry>,
+}
+/// To stop waiting, send a LeaveWaitlistRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LeaveWaitlistRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+/// Removed entry will be returned in LeaveWaitlistResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LeaveWaitlistResponse {
+    #[prost(message, optional, tag = "1")]
+    pub entry: ::core::option::Option<WaitlistEntry>,
+}
+/// To list the waitlist, send a ListWaitlistRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListWaitlistRequest {
+    /// if empty, list all resources
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// if empty, list all users
+    #[prost(string, tag = "2")]
+    pub user_id: ::prost::alloc::string::String,
+}
+/// Waitlist entries, in the order they are served
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListWaitlistResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub entries: ::prost::alloc::vec::Vec<WaitlistEntry>,
+}
+/// a participant of a reservation besides its owner
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Attendee {
+    /// unique id for the attendee, if put into AddAttendeeRequest, id should be empty
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(int64, tag = "2")]
+    pub reservation_id: i64,
+    /// either user id or email is set
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    /// email of an external attendee
+    #[prost(string, tag = "4")]
+    pub email: ::prost::alloc::string::String,
+    #[prost(enumeration = "AttendeeRole", tag = "5")]
+    pub role: i32,
+    #[prost(enumeration = "AttendeeResponse", tag = "6")]
+    pub response: i32,
+    #[prost(string, tag = "7")]
+    pub tenant_id: ::prost::alloc::string::String,
+}
+/// To invite an attendee, send an AddAttendeeRequest
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddAttendeeRequest {
+    #[prost(message, optional, tag = "1")]
+    pub attendee: ::core::option::Option<Attendee>,
+}
+/// Added attendee will be returned in AddAttendeeResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddAttendeeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub attendee: ::core::option::Option<Attendee>,
+}
+/// To remove an attendee, send a RemoveAttendeeRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RemoveAttendeeRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+/// Removed attendee will be returned in RemoveAttendeeResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveAttendeeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub attendee: ::core::option::Option<Attendee>,
+}
+/// To answer an invitation, send a RespondRequest. The caller must be the attendee, or the
+/// reservation owner answering for an external attendee
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RespondRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(enumeration = "AttendeeResponse", tag = "2")]
+    pub response: i32,
+}
+/// Updated attendee will be returned in RespondResponse
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RespondResponse {
+    #[prost(message, optional, tag = "1")]
+    pub attendee: ::core::option::Option<Attendee>,
+}
+/// To list the attendees of a reservation, send a ListAttendeesRequest
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListAttendeesRequest {
+    #[prost(int64, tag = "1")]
+    pub reservation_id: i64,
+}
+/// Attendees of the reservation, ordered by id
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAttendeesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub attendees: ::prost::alloc::vec::Vec<Attendee>,
+}
+/// reservation status for a given time period
+#[derive(
+    sqlx::Type, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum ReservationStatus {
+    Unknown = 0,
+    Pending = 1,
+    Confirmed = 2,
+    Blocked = 3,
+    /// bumped by a reservation of higher priority, no longer holds the window
+    Preempted = 4,
+    /// confirmed, but nobody checked in within the grace period
+    NoShow = 5,
+    /// waiting for an approver, holds the window
+    AwaitingApproval = 6,
+    /// turned down by an approver, no longer holds the window
+    Rejected = 7,
+}
+impl ReservationStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "RESERVATION_STATUS_UNKNOWN",
+            Self::Pending => "RESERVATION_STATUS_PENDING",
+            Self::Confirmed => "RESERVATION_STATUS_CONFIRMED",
+            Self::Blocked => "RESERVATION_STATUS_BLOCKED",
+            Self::Preempted => "RESERVATION_STATUS_PREEMPTED",
+            Self::NoShow => "RESERVATION_STATUS_NO_SHOW",
+            Self::AwaitingApproval => "RESERVATION_STATUS_AWAITING_APPROVAL",
+            Self::Rejected => "RESERVATION_STATUS_REJECTED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RESERVATION_STATUS_UNKNOWN" => Some(Self::Unknown),
+            "RESERVATION_STATUS_PENDING" => Some(Self::Pending),
+            "RESERVATION_STATUS_CONFIRMED" => Some(Self::Confirmed),
+            "RESERVATION_STATUS_BLOCKED" => Some(Self::Blocked),
+            "RESERVATION_STATUS_PREEMPTED" => Some(Self::Preempted),
+            "RESERVATION_STATUS_NO_SHOW" => Some(Self::NoShow),
+            "RESERVATION_STATUS_AWAITING_APPROVAL" => Some(Self::AwaitingApproval),
+            "RESERVATION_STATUS_REJECTED" => Some(Self::Rejected),
+            _ => None,
+        }
+    }
+}
+/// when reservation is updated, record the update type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReservationUpdateType {
+    Unknown = 0,
+    Create = 1,
+    Update = 2,
+    Delete = 3,
+}
+impl ReservationUpdateType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "RESERVATION_UPDATE_TYPE_UNKNOWN",
+            Self::Create => "RESERVATION_UPDATE_TYPE_CREATE",
+            Self::Update => "RESERVATION_UPDATE_TYPE_UPDATE",
+            Self::Delete => "RESERVATION_UPDATE_TYPE_DELETE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RESERVATION_UPDATE_TYPE_UNKNOWN" => Some(Self::Unknown),
+            "RESERVATION_UPDATE_TYPE_CREATE" => Some(Self::Create),
+            "RESERVATION_UPDATE_TYPE_UPDATE" => Some(Self::Update),
+            "RESERVATION_UPDATE_TYPE_DELETE" => Some(Self::Delete),
+            _ => None,
+        }
+    }
+}
+/// role of an attendee in a reservation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AttendeeRole {
+    Unknown = 0,
+    Required = 1,
+    Optional = 2,
+    /// additional guest, e.g. of a hotel booking
+    Guest = 3,
+}
+impl AttendeeRole {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "ATTENDEE_ROLE_UNKNOWN",
+            Self::Required => "ATTENDEE_ROLE_REQUIRED",
+            Self::Optional => "ATTENDEE_ROLE_OPTIONAL",
+            Self::Guest => "ATTENDEE_ROLE_GUEST",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ATTENDEE_ROLE_UNKNOWN" => Some(Self::Unknown),
+            "ATTENDEE_ROLE_REQUIRED" => Some(Self::Required),
+            "ATTENDEE_ROLE_OPTIONAL" => Some(Self::Optional),
+            "ATTENDEE_ROLE_GUEST" => Some(Self::Guest),
+            _ => None,
+        }
+    }
+}
+/// whether an attendee will take part
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AttendeeResponse {
+    Unknown = 0,
+    NeedsAction = 1,
+    Accepted = 2,
+    Declined = 3,
+    Tentative = 4,
+}
+impl AttendeeResponse {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "ATTENDEE_RESPONSE_UNKNOWN",
+            Self::NeedsAction => "ATTENDEE_RESPONSE_NEEDS_ACTION",
+            Self::Accepted => "ATTENDEE_RESPONSE_ACCEPTED",
+            Self::Declined => "ATTENDEE_RESPONSE_DECLINED",
+            Self::Tentative => "ATTENDEE_RESPONSE_TENTATIVE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ATTENDEE_RESPONSE_UNKNOWN" => Some(Self::Unknown),
+            "ATTENDEE_RESPONSE_NEEDS_ACTION" => Some(Self::NeedsAction),
+            "ATTENDEE_RESPONSE_ACCEPTED" => Some(Self::Accepted),
+            "ATTENDEE_RESPONSE_DECLINED" => Some(Self::Declined),
+            "ATTENDEE_RESPONSE_TENTATIVE" => Some(Self::Tentative),
+            _ => None,
+        }
+    }
+}
+/// how a pool picks the member for a reservation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PoolStrategy {
+    /// same as first fit
+    Unknown = 0,
+    /// the first free member in the order of the pool definition
+    FirstFit = 1,
+    /// the free member whose last reservation ended the longest time ago
+    LeastRecentlyUsed = 2,
+    /// the free member with the least free time around the window, to minimise fragmentation
+    BestFit = 3,
+}
+impl PoolStrategy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "POOL_STRATEGY_UNKNOWN",
+            Self::FirstFit => "POOL_STRATEGY_FIRST_FIT",
+            Self::LeastRecentlyUsed => "POOL_STRATEGY_LEAST_RECENTLY_USED",
+            Self::BestFit => "POOL_STRATEGY_BEST_FIT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "POOL_STRATEGY_UNKNOWN" => Some(Self::Unknown),
+            "POOL_STRATEGY_FIRST_FIT" => Some(Self::FirstFit),
+            "POOL_STRATEGY_LEAST_RECENTLY_USED" => Some(Self::LeastRecentlyUsed),
+            "POOL_STRATEGY_BEST_FIT" => Some(Self::BestFit),
+            _ => None,
+        }
+    }
+}
+/// unit a rate is charged per
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RateUnit {
+    Unknown = 0,
+    /// every started hour
+    Hour = 1,
+    /// every started 24 hours, used for reservations of a day or longer
+    Day = 2,
+}
+impl RateUnit {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "RATE_UNIT_UNKNOWN",
+            Self::Hour => "RATE_UNIT_HOUR",
+            Self::Day => "RATE_UNIT_DAY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RATE_UNIT_UNKNOWN" => Some(Self::Unknown),
+            "RATE_UNIT_HOUR" => Some(Self::Hour),
+            "RATE_UNIT_DAY" => Some(Self::Day),
+            _ => None,
+        }
+    }
+}
+/// length of the buckets of a utilization report, in the time zone of the report
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReportBucket {
+    /// same as day
+    Unknown = 0,
+    Hour = 1,
+    Day = 2,
+    /// weeks start on Monday
+    Week = 3,
+}
+impl ReportBucket {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "REPORT_BUCKET_UNKNOWN",
+            Self::Hour => "REPORT_BUCKET_HOUR",
+            Self::Day => "REPORT_BUCKET_DAY",
+            Self::Week => "REPORT_BUCKET_WEEK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REPORT_BUCKET_UNKNOWN" => Some(Self::Unknown),
+            "REPORT_BUCKET_HOUR" => Some(Self::Hour),
+            "REPORT_BUCKET_DAY" => Some(Self::Day),
+            "REPORT_BUCKET_WEEK" => Some(Self::Week),
+            _ => None,
+        }
+    }
+}
+/// what holds a bucket of an occupancy timeline, later states take precedence
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OccupancyState {
+    Unknown = 0,
+    Free = 1,
+    /// pending or awaiting approval
+    Pending = 2,
+    /// confirmed, including no-shows which kept their window
+    Confirmed = 3,
+    /// blocked reservations, turnaround buffers around reservations and reservations of the
+    /// resource's ancestors and descendants
+    Blocked = 4,
+}
+impl OccupancyState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "OCCUPANCY_STATE_UNKNOWN",
+            Self::Free => "OCCUPANCY_STATE_FREE",
+            Self::Pending => "OCCUPANCY_STATE_PENDING",
+            Self::Confirmed => "OCCUPANCY_STATE_CONFIRMED",
+            Self::Blocked => "OCCUPANCY_STATE_BLOCKED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OCCUPANCY_STATE_UNKNOWN" => Some(Self::Unknown),
+            "OCCUPANCY_STATE_FREE" => Some(Self::Free),
+            "OCCUPANCY_STATE_PENDING" => Some(Self::Pending),
+            "OCCUPANCY_STATE_CONFIRMED" => Some(Self::Confirmed),
+            "OCCUPANCY_STATE_BLOCKED" => Some(Self::Blocked),
+            _ => None,
+        }
+    }
+}
+/// file format of an export or import
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExportFormat {
+    /// same as CSV
+    Unknown = 0,
+    /// a header line with the column names, then a line per reservation
+    Csv = 1,
+    /// a JSON object per reservation and line
+    JsonLines = 2,
+}
+impl ExportFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "EXPORT_FORMAT_UNKNOWN",
+            Self::Csv => "EXPORT_FORMAT_CSV",
+            Self::JsonLines => "EXPORT_FORMAT_JSON_LINES",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EXPORT_FORMAT_UNKNOWN" => Some(Self::Unknown),
+            "EXPORT_FORMAT_CSV" => Some(Self::Csv),
+            "EXPORT_FORMAT_JSON_LINES" => Some(Self::JsonLines),
+            _ => None,
+        }
+    }
+}
+/// what happens to the valid rows of an import when other rows fail
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ImportMode {
+    /// same as all or nothing
+    Unknown = 0,
+    /// nothing is imported unless every row is
+    AllOrNothing = 1,
+    /// the valid rows are imported anyway
+    BestEffort = 2,
+}
+impl ImportMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "IMPORT_MODE_UNKNOWN",
+            Self::AllOrNothing => "IMPORT_MODE_ALL_OR_NOTHING",
+            Self::BestEffort => "IMPORT_MODE_BEST_EFFORT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "IMPORT_MODE_UNKNOWN" => Some(Self::Unknown),
+            "IMPORT_MODE_ALL_OR_NOTHING" => Some(Self::AllOrNothing),
+            "IMPORT_MODE_BEST_EFFORT" => Some(Self::BestEffort),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod reservation_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value
+    )]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    /// Reservation service
+    #[derive(Debug, Clone)]
+    pub struct ReservationServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ReservationServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ReservationServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ReservationServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                    http::Request<tonic::body::Body>,
+                    Response = http::Response<
+                        <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                    >,
+                >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::Body>>>::Error:
+                Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            ReservationServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// make a reservation
+        pub async fn reserve(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReserveRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/reserve");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "reserve"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// confirm a pending reservation, if reservation is not pending, do nothing
+        pub async fn confirm(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ConfirmRequest>,
+        ) -> std::result::Result<tonic::Response<super::ConfirmResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/confirm");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "confirm"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// update the reservation note
+        pub async fn update(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/update");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "update"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// move the reservation to another window
+        pub async fn reschedule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RescheduleRequest>,
+        ) -> std::result::Result<tonic::Response<super::RescheduleResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/reschedule");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reschedule",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// end the reservation now or at the given time, freeing the rest of its window
+        pub async fn end_early(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EndEarlyRequest>,
+        ) -> std::result::Result<tonic::Response<super::EndEarlyResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/end_early");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "end_early",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// make the reservation end later if the following time is free
+        pub async fn extend(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExtendRequest>,
+        ) -> std::result::Result<tonic::Response<super::ExtendResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/extend");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "extend"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// split a reservation in two at a point in time
+        pub async fn split(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SplitRequest>,
+        ) -> std::result::Result<tonic::Response<super::SplitResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/split");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "split"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// combine contiguous reservations of the same user and resource
+        pub async fn merge(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MergeRequest>,
+        ) -> std::result::Result<tonic::Response<super::MergeResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/merge");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "merge"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// hand the reservation over to another user
+        pub async fn transfer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransferRequest>,
+        ) -> std::result::Result<tonic::Response<super::TransferResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/transfer");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "transfer",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// accept a transfer, the caller must be its recipient
+        pub async fn accept_transfer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AcceptTransferRequest>,
+        ) -> std::result::Result<tonic::Response<super::AcceptTransferResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/accept_transfer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "accept_transfer",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// decline or withdraw a transfer, the caller must be its recipient or the owner
+        pub async fn decline_transfer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeclineTransferRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeclineTransferResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/decline_transfer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "decline_transfer",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// confirm a reservation awaiting approval
+        pub async fn approve(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ApproveRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApproveResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/approve");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "approve"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// reject a reservation awaiting approval, releasing its window
+        pub async fn reject(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RejectRequest>,
+        ) -> std::result::Result<tonic::Response<super::RejectResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/reject");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "reject"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// reservations awaiting approval of the caller
+        pub async fn approval_inbox(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ApprovalInboxRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApprovalInboxResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/approval_inbox",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "approval_inbox",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// record the guest has arrived
+        pub async fn check_in(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckInRequest>,
+        ) -> std::result::Result<tonic::Response<super::CheckInResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/check_in");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "check_in",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// record the guest has left
+        pub async fn check_out(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckOutRequest>,
+        ) -> std::result::Result<tonic::Response<super::CheckOutResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/check_out");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "check_out",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// cancel a reservation by the cancellation policy of its resource
+        pub async fn cancel(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelRequest>,
+        ) -> std::result::Result<tonic::Response<super::CancelResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/cancel");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "cancel"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// get a reservation by id
+        pub async fn get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/reservation.ReservationService/get");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "get"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// query reservations by resource id, user id, status, start time, end time
+        pub async fn query(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::Reservation>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/query");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "query"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// filter reservations, order by reservation id
+        pub async fn filter(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FilterRequest>,
+        ) -> std::result::Result<tonic::Response<super::FilterResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/filter");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "filter"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// another system could monitor newly added/confirmed/cancelled reservations
+        pub async fn listen(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::Reservation>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/listen");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "listen"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// register or update a resource
+        pub async fn save_resource(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SaveResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::SaveResourceResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/save_resource",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "save_resource",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// get a resource by id
+        pub async fn get_resource(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResourceResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/get_resource",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "get_resource",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// register or replace the rate plan of a resource
+        pub async fn save_rate_plan(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SaveRatePlanRequest>,
+        ) -> std::result::Result<tonic::Response<super::SaveRatePlanResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/save_rate_plan",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "save_rate_plan",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// get the rate plan of a resource
+        pub async fn get_rate_plan(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRatePlanRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetRatePlanResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/get_rate_plan",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "get_rate_plan",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// price a prospective reservation
+        pub async fn quote(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QuoteRequest>,
+        ) -> std::result::Result<tonic::Response<super::QuoteResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/quote");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "quote"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// change the settings of the tenant, e.g. its currency
+        pub async fn save_tenant_settings(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SaveTenantSettingsRequest>,
+        ) -> std::result::Result<tonic::Response<super::SaveTenantSettingsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/save_tenant_settings",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "save_tenant_settings",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// get the settings of the tenant
+        pub async fn get_tenant_settings(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTenantSettingsRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetTenantSettingsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/get_tenant_settings",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "get_tenant_settings",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// register or update a webhook subscription, requires the admin role
+        pub async fn save_webhook(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SaveWebhookRequest>,
+        ) -> std::result::Result<tonic::Response<super::SaveWebhookResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/save_webhook",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "save_webhook",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// delete a webhook subscription, requires the admin role
+        pub async fn delete_webhook(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteWebhookRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeleteWebhookResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/delete_webhook",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "delete_webhook",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// list the webhook subscriptions of the tenant, requires the admin role
+        pub async fn list_webhooks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListWebhooksRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListWebhooksResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/list_webhooks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "list_webhooks",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// list changes which could not be delivered, requires the admin role
+        pub async fn list_dead_letters(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDeadLettersRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListDeadLettersResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/list_dead_letters",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "list_dead_letters",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// register or replace a pool of interchangeable resources
+        pub async fn save_pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SavePoolRequest>,
+        ) -> std::result::Result<tonic::Response<super::SavePoolResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/save_pool");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "save_pool",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// get a pool by id
+        pub async fn get_pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetPoolRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetPoolResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/get_pool");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "get_pool",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// reserve whichever member of the pool its strategy picks
+        pub async fn reserve_from_pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReserveFromPoolRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveFromPoolResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/reserve_from_pool",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reserve_from_pool",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// add or replace the booking policy of a resource type at runtime
+        pub async fn set_policy(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetPolicyRequest>,
+        ) -> std::result::Result<tonic::Response<super::SetPolicyResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/set_policy");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "set_policy",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// list the booking policies in effect
+        pub async fn list_policies(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPoliciesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListPoliciesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/list_policies",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "list_policies",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// report the overbooked windows which have not ended yet
+        pub async fn overbooked_windows(
+            &mut self,
+            request: impl tonic::IntoRequest<super::OverbookedWindowsRequest>,
+        ) -> std::result::Result<tonic::Response<super::OverbookedWindowsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/overbooked_windows",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "overbooked_windows",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// find the free windows of a resource
+        pub async fn availability(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AvailabilityRequest>,
+        ) -> std::result::Result<tonic::Response<super::AvailabilityResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/availability",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "availability",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// report booked hours, status counts, peak hours and no-show rate of resources in a period
+        pub async fn utilization(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UtilizationRequest>,
+        ) -> std::result::Result<tonic::Response<super::UtilizationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/utilization");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "utilization",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// occupancy state of resources per fixed-size bucket of a window, for a resource x time grid
+        pub async fn occupancy(
+            &mut self,
+            request: impl tonic::IntoRequest<super::OccupancyRequest>,
+        ) -> std::result::Result<tonic::Response<super::OccupancyResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/occupancy");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "occupancy",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// stream matching reservations as CSV or JSON Lines, in chunks
+        pub async fn export(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ExportChunk>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/export");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "export"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// load reservations from CSV or JSON Lines in batches, admins only
+        pub async fn import(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::ImportChunk>,
+        ) -> std::result::Result<tonic::Response<super::ImportResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/import");
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "import"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        /// wait for a reserved window, the entry becomes a pending reservation once the window is free
+        pub async fn join_waitlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::JoinWaitlistRequest>,
+        ) -> std::result::Result<tonic::Response<super::JoinWaitlistResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/join_waitlist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "join_waitlist",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// leave the waitlist
+        pub async fn leave_waitlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LeaveWaitlistRequest>,
+        ) -> std::result::Result<tonic::Response<super::LeaveWaitlistResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/leave_waitlist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "leave_waitlist",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// list waitlist entries by resource id and user id
+        pub async fn list_waitlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListWaitlistRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListWaitlistResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/list_waitlist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "list_waitlist",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// invite a user or external email to a reservation
+        pub async fn add_attendee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddAttendeeRequest>,
+        ) -> std::result::Result<tonic::Response<super::AddAttendeeResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/add_attendee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "add_attendee",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// remove an attendee from a reservation
+        pub async fn remove_attendee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveAttendeeRequest>,
+        ) -> std::result::Result<tonic::Response<super::RemoveAttendeeResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/remove_attendee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "remove_attendee",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// answer an invitation
+        pub async fn respond(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RespondRequest>,
+        ) -> std::result::Result<tonic::Response<super::RespondResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/respond");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "respond"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// list the attendees of a reservation
+        pub async fn list_attendees(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAttendeesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListAttendeesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/list_attendees",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "list_attendees",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod reservation_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ReservationServiceServer.
+    #[async_trait]
+    pub trait ReservationService: std::marker::Send + std::marker::Sync + 'static {
+        /// make a reservation
+        async fn reserve(
+            &self,
+            request: tonic::Request<super::ReserveRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveResponse>, tonic::Status>;
+        /// confirm a pending reservation, if reservation is not pending, do nothing
+        async fn confirm(
+            &self,
+            request: tonic::Request<super::ConfirmRequest>,
+        ) -> std::result::Result<tonic::Response<super::ConfirmResponse>, tonic::Status>;
+        /// update the reservation note
+        async fn update(
+            &self,
+            request: tonic::Request<super::UpdateRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status>;
+        /// move the reservation to another window
+        async fn reschedule(
+            &self,
+            request: tonic::Request<super::RescheduleRequest>,
+        ) -> std::result::Result<tonic::Response<super::RescheduleResponse>, tonic::Status>;
+        /// end the reservation now or at the given time, freeing the rest of its window
+        async fn end_early(
+            &self,
+            request: tonic::Request<super::EndEarlyRequest>,
+        ) -> std::result::Result<tonic::Response<super::EndEarlyResponse>, tonic::Status>;
+        /// make the reservation end later if the following time is free
+        async fn extend(
+            &self,
+            request: tonic::Request<super::ExtendRequest>,
+        ) -> std::result::Result<tonic::Response<super::ExtendResponse>, tonic::Status>;
+        /// split a reservation in two at a point in time
+        async fn split(
+            &self,
+            request: tonic::Request<super::SplitRequest>,
+        ) -> std::result::Result<tonic::Response<super::SplitResponse>, tonic::Status>;
+        /// combine contiguous reservations of the same user and resource
+        async fn merge(
+            &self,
+            request: tonic::Request<super::MergeRequest>,
+        ) -> std::result::Result<tonic::Response<super::MergeResponse>, tonic::Status>;
+        /// hand the reservation over to another user
+        async fn transfer(
+            &self,
+            request: tonic::Request<super::TransferRequest>,
+        ) -> std::result::Result<tonic::Response<super::TransferResponse>, tonic::Status>;
+        /// accept a transfer, the caller must be its recipient
+        async fn accept_transfer(
+            &self,
+            request: tonic::Request<super::AcceptTransferRequest>,
+        ) -> std::result::Result<tonic::Response<super::AcceptTransferResponse>, tonic::Status>;
+        /// decline or withdraw a transfer, the caller must be its recipient or the owner
+        async fn decline_transfer(
+            &self,
+            request: tonic::Request<super::DeclineTransferRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeclineTransferResponse>, tonic::Status>;
+        /// confirm a reservation awaiting approval
+        async fn approve(
+            &self,
+            request: tonic::Request<super::ApproveRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApproveResponse>, tonic::Status>;
+        /// reject a reservation awaiting approval, releasing its window
+        async fn reject(
+            &self,
+            request: tonic::Request<super::RejectRequest>,
+        ) -> std::result::Result<tonic::Response<super::RejectResponse>, tonic::Status>;
+        /// reservations awaiting approval of the caller
+        async fn approval_inbox(
+            &self,
+            request: tonic::Request<super::ApprovalInboxRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApprovalInboxResponse>, tonic::Status>;
+        /// record the guest has arrived
+        async fn check_in(
+            &self,
+            request: tonic::Request<super::CheckInRequest>,
+        ) -> std::result::Result<tonic::Response<super::CheckInResponse>, tonic::Status>;
+        /// record the guest has left
+        async fn check_out(
+            &self,
+            request: tonic::Request<super::CheckOutRequest>,
+        ) -> std::result::Result<tonic::Response<super::CheckOutResponse>, tonic::Status>;
+        /// cancel a reservation by the cancellation policy of its resource
+        async fn cancel(
+            &self,
+            request: tonic::Request<super::CancelRequest>,
+        ) -> std::result::Result<tonic::Response<super::CancelResponse>, tonic::Status>;
+        /// get a reservation by id
+        async fn get(
+            &self,
+            request: tonic::Request<super::GetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
+        /// Server streaming response type for the query method.
+        type queryStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::Reservation, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// query reservations by resource id, user id, status, start time, end time
+        async fn query(
+            &self,
+            request: tonic::Request<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<Self::queryStream>, tonic::Status>;
+        /// filter reservations, order by reservation id
+        async fn filter(
+            &self,
+            request: tonic::Request<super::FilterRequest>,
+        ) -> std::result::Result<tonic::Response<super::FilterResponse>, tonic::Status>;
+        /// Server streaming response type for the listen method.
+        type listenStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::Reservation, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// another system could monitor newly added/confirmed/cancelled reservations
+        async fn listen(
+            &self,
+            request: tonic::Request<super::ListenRequest>,
+        ) -> std::result::Result<tonic::Response<Self::listenStream>, tonic::Status>;
+        /// register or update a resource
+        async fn save_resource(
+            &self,
+            request: tonic::Request<super::SaveResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::SaveResourceResponse>, tonic::Status>;
+        /// get a resource by id
+        async fn get_resource(
+            &self,
+            request: tonic::Request<super::GetResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResourceResponse>, tonic::Status>;
+        /// register or replace the rate plan of a resource
+        async fn save_rate_plan(
+            &self,
+            request: tonic::Request<super::SaveRatePlanRequest>,
+        ) -> std::result::Result<tonic::Response<super::SaveRatePlanResponse>, tonic::Status>;
+        /// get the rate plan of a resource
+        async fn get_rate_plan(
+            &self,
+            request: tonic::Request<super::GetRatePlanRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetRatePlanResponse>, tonic::Status>;
+        /// price a prospective reservation
+        async fn quote(
+            &self,
+            request: tonic::Request<super::QuoteRequest>,
+        ) -> std::result::Result<tonic::Response<super::QuoteResponse>, tonic::Status>;
+        /// change the settings of the tenant, e.g. its currency
+        async fn save_tenant_settings(
+            &self,
+            request: tonic::Request<super::SaveTenantSettingsRequest>,
+        ) -> std::result::Result<tonic::Response<super::SaveTenantSettingsResponse>, tonic::Status>;
+        /// get the settings of the tenant
+        async fn get_tenant_settings(
+            &self,
+            request: tonic::Request<super::GetTenantSettingsRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetTenantSettingsResponse>, tonic::Status>;
+        /// register or update a webhook subscription, requires the admin role
+        async fn save_webhook(
+            &self,
+            request: tonic::Request<super::SaveWebhookRequest>,
+        ) -> std::result::Result<tonic::Response<super::SaveWebhookResponse>, tonic::Status>;
+        /// delete a webhook subscription, requires the admin role
+        async fn delete_webhook(
+            &self,
+            request: tonic::Request<super::DeleteWebhookRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeleteWebhookResponse>, tonic::Status>;
+        /// list the webhook subscriptions of the tenant, requires the admin role
+        async fn list_webhooks(
+            &self,
+            request: tonic::Request<super::ListWebhooksRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListWebhooksResponse>, tonic::Status>;
+        /// list changes which could not be delivered, requires the admin role
+        async fn list_dead_letters(
+            &self,
+            request: tonic::Request<super::ListDeadLettersRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListDeadLettersResponse>, tonic::Status>;
+        /// register or replace a pool of interchangeable resources
+        async fn save_pool(
+            &self,
+            request: tonic::Request<super::SavePoolRequest>,
+        ) -> std::result::Result<tonic::Response<super::SavePoolResponse>, tonic::Status>;
+        /// get a pool by id
+        async fn get_pool(
+            &self,
+            request: tonic::Request<super::GetPoolRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetPoolResponse>, tonic::Status>;
+        /// reserve whichever member of the pool its strategy picks
+        async fn reserve_from_pool(
+            &self,
+            request: tonic::Request<super::ReserveFromPoolRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveFromPoolResponse>, tonic::Status>;
+        /// add or replace the booking policy of a resource type at runtime
+        async fn set_policy(
+            &self,
+            request: tonic::Request<super::SetPolicyRequest>,
+        ) -> std::result::Result<tonic::Response<super::SetPolicyResponse>, tonic::Status>;
+        /// list the booking policies in effect
+        async fn list_policies(
+            &self,
+            request: tonic::Request<super::ListPoliciesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListPoliciesResponse>, tonic::Status>;
+        /// report the overbooked windows which have not ended yet
+        async fn overbooked_windows(
+            &self,
+            request: tonic::Request<super::OverbookedWindowsRequest>,
+        ) -> std::result::Result<tonic::Response<super::OverbookedWindowsResponse>, tonic::Status>;
+        /// find the free windows of a resource
+        async fn availability(
+            &self,
+            request: tonic::Request<super::AvailabilityRequest>,
+        ) -> std::result::Result<tonic::Response<super::AvailabilityResponse>, tonic::Status>;
+        /// report booked hours, status counts, peak hours and no-show rate of resources in a period
+        async fn utilization(
+            &self,
+            request: tonic::Request<super::UtilizationRequest>,
+        ) -> std::result::Result<tonic::Response<super::UtilizationResponse>, tonic::Status>;
+        /// occupancy state of resources per fixed-size bucket of a window, for a resource x time grid
+        async fn occupancy(
+            &self,
+            request: tonic::Request<super::OccupancyRequest>,
+        ) -> std::result::Result<tonic::Response<super::OccupancyResponse>, tonic::Status>;
+        /// Server streaming response type for the export method.
+        type exportStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ExportChunk, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// stream matching reservations as CSV or JSON Lines, in chunks
+        async fn export(
+            &self,
+            request: tonic::Request<super::ExportRequest>,
+        ) -> std::result::Result<tonic::Response<Self::exportStream>, tonic::Status>;
+        /// load reservations from CSV or JSON Lines in batches, admins only
+        async fn import(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::ImportChunk>>,
+        ) -> std::result::Result<tonic::Response<super::ImportResponse>, tonic::Status>;
+        /// wait for a reserved window, the entry becomes a pending reservation once the window is free
+        async fn join_waitlist(
+            &self,
+            request: tonic::Request<super::JoinWaitlistRequest>,
+        ) -> std::result::Result<tonic::Response<super::JoinWaitlistResponse>, tonic::Status>;
+        /// leave the waitlist
+        async fn leave_waitlist(
+            &self,
+            request: tonic::Request<super::LeaveWaitlistRequest>,
+        ) -> std::result::Result<tonic::Response<super::LeaveWaitlistResponse>, tonic::Status>;
+        /// list waitlist entries by resource id and user id
+        async fn list_waitlist(
+            &self,
+            request: tonic::Request<super::ListWaitlistRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListWaitlistResponse>, tonic::Status>;
+        /// invite a user or external email to a reservation
+        async fn add_attendee(
+            &self,
+            request: tonic::Request<super::AddAttendeeRequest>,
+        ) -> std::result::Result<tonic::Response<super::AddAttendeeResponse>, tonic::Status>;
+        /// remove an attendee from a reservation
+        async fn remove_attendee(
+            &self,
+            request: tonic::Request<super::RemoveAttendeeRequest>,
+        ) -> std::result::Result<tonic::Response<super::RemoveAttendeeResponse>, tonic::Status>;
+        /// answer an invitation
+        async fn respond(
+            &self,
+            request: tonic::Request<super::RespondRequest>,
+        ) -> std::result::Result<tonic::Response<super::RespondResponse>, tonic::Status>;
+        /// list the attendees of a reservation
+        async fn list_attendees(
+            &self,
+            request: tonic::Request<super::ListAttendeesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListAttendeesResponse>, tonic::Status>;
+    }
+    /// Reservation service
+    #[derive(Debug)]
+    pub struct ReservationServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ReservationServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ReservationServiceServer<T>
+    where
+        T: ReservationService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/reservation.ReservationService/reserve" => {
+                    #[allow(non_camel_case_types)]
+                    struct reserveSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::ReserveRequest> for reserveSvc<T> {
+                        type Response = super::ReserveResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReserveRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::reserve(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = reserveSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/confirm" => {
+                    #[allow(non_camel_case_types)]
+                    struct confirmSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::ConfirmRequest> for confirmSvc<T> {
+                        type Response = super::ConfirmResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ConfirmRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::confirm(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = confirmSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/update" => {
+                    #[allow(non_camel_case_types)]
+                    struct updateSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::UpdateRequest> for updateSvc<T> {
+                        type Response = super::UpdateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::update(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = updateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/reschedule" => {
+                    #[allow(non_camel_case_types)]
+                    struct rescheduleSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::RescheduleRequest> for rescheduleSvc<T>
+                    {
+                        type Response = super::RescheduleResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RescheduleRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::reschedule(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = rescheduleSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/end_early" => {
+                    #[allow(non_camel_case_types)]
+                    struct end_earlySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::EndEarlyRequest>
+                        for end_earlySvc<T>
+                    {
+                        type Response = super::EndEarlyResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EndEarlyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::end_early(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = end_earlySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/extend" => {
+                    #[allow(non_camel_case_types)]
+                    struct extendSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::ExtendRequest> for extendSvc<T> {
+                        type Response = super::ExtendResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExtendRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::extend(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = extendSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/split" => {
+                    #[allow(non_camel_case_types)]
+                    struct splitSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::SplitRequest> for splitSvc<T> {
+                        type Response = super::SplitResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SplitRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::split(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = splitSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/merge" => {
+                    #[allow(non_camel_case_types)]
+                    struct mergeSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::MergeRequest> for mergeSvc<T> {
+                        type Response = super::MergeResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MergeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::merge(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = mergeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/transfer" => {
+                    #[allow(non_camel_case_types)]
+                    struct transferSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::TransferRequest> for transferSvc<T> {
+                        type Response = super::TransferResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::transfer(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = transferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/accept_transfer" => {
+                    #[allow(non_camel_case_types)]
+                    struct accept_transferSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::AcceptTransferRequest>
+                        for accept_transferSvc<T>
+                    {
+                        type Response = super::AcceptTransferResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AcceptTransferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::accept_transfer(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = accept_transferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/decline_transfer" => {
+                    #[allow(non_camel_case_types)]
+                    struct decline_transferSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::DeclineTransferRequest>
+                        for decline_transferSvc<T>
+                    {
+                        type Response = super::DeclineTransferResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeclineTransferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::decline_transfer(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = decline_transferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/approve" => {
+                    #[allow(non_camel_case_types)]
+                    struct approveSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::ApproveRequest> for approveSvc<T> {
+                        type Response = super::ApproveResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ApproveRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::approve(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = approveSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/reject" => {
+                    #[allow(non_camel_case_types)]
+                    struct rejectSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::RejectRequest> for rejectSvc<T> {
+                        type Response = super::RejectResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RejectRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::reject(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = rejectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/approval_inbox" => {
+                    #[allow(non_camel_case_types)]
+                    struct approval_inboxSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ApprovalInboxRequest>
+                        for approval_inboxSvc<T>
+                    {
+                        type Response = super::ApprovalInboxResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ApprovalInboxRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::approval_inbox(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = approval_inboxSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/check_in" => {
+                    #[allow(non_camel_case_types)]
+                    struct check_inSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::CheckInRequest> for check_inSvc<T> {
+                        type Response = super::CheckInResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CheckInRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::check_in(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = check_inSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/check_out" => {
+                    #[allow(non_camel_case_types)]
+                    struct check_outSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::CheckOutRequest>
+                        for check_outSvc<T>
+                    {
+                        type Response = super::CheckOutResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CheckOutRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::check_out(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = check_outSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/cancel" => {
+                    #[allow(non_camel_case_types)]
+                    struct cancelSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::CancelRequest> for cancelSvc<T> {
+                        type Response = super::CancelResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::cancel(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = cancelSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/get" => {
+                    #[allow(non_camel_case_types)]
+                    struct getSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::GetRequest> for getSvc<T> {
+                        type Response = super::GetResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = getSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/query" => {
+                    #[allow(non_camel_case_types)]
+                    struct querySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
+                    {
+                        type Response = super::Reservation;
+                        type ResponseStream = T::queryStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::query(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = querySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/filter" => {
+                    #[allow(non_camel_case_types)]
+                    struct filterSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::FilterRequest> for filterSvc<T> {
+                        type Response = super::FilterResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FilterRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::filter(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = filterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/listen" => {
+                    #[allow(non_camel_case_types)]
+                    struct listenSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::ServerStreamingService<super::ListenRequest>
+                        for listenSvc<T>
+                    {
+                        type Response = super::Reservation;
+                        type ResponseStream = T::listenStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListenRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::listen(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = listenSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/save_resource" => {
+                    #[allow(non_camel_case_types)]
+                    struct save_resourceSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::SaveResourceRequest>
+                        for save_resourceSvc<T>
+                    {
+                        type Response = super::SaveResourceResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SaveResourceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::save_resource(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = save_resourceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/get_resource" => {
+                    #[allow(non_camel_case_types)]
+                    struct get_resourceSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::GetResourceRequest>
+                        for get_resourceSvc<T>
+                    {
+                        type Response = super::GetResourceResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetResourceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::get_resource(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = get_resourceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/save_rate_plan" => {
+                    #[allow(non_camel_case_types)]
+                    struct save_rate_planSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::SaveRatePlanRequest>
+                        for save_rate_planSvc<T>
+                    {
+                        type Response = super::SaveRatePlanResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SaveRatePlanRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::save_rate_plan(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = save_rate_planSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/get_rate_plan" => {
+                    #[allow(non_camel_case_types)]
+                    struct get_rate_planSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::GetRatePlanRequest>
+                        for get_rate_planSvc<T>
+                    {
+                        type Response = super::GetRatePlanResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRatePlanRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::get_rate_plan(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = get_rate_planSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/quote" => {
+                    #[allow(non_camel_case_types)]
+                    struct quoteSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::QuoteRequest> for quoteSvc<T> {
+                        type Response = super::QuoteResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QuoteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::quote(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = quoteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/save_tenant_settings" => {
+                    #[allow(non_camel_case_types)]
+                    struct save_tenant_settingsSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::SaveTenantSettingsRequest>
+                        for save_tenant_settingsSvc<T>
+                    {
+                        type Response = super::SaveTenantSettingsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SaveTenantSettingsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::save_tenant_settings(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = save_tenant_settingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/get_tenant_settings" => {
+                    #[allow(non_camel_case_types)]
+                    struct get_tenant_settingsSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::GetTenantSettingsRequest>
+                        for get_tenant_settingsSvc<T>
+                    {
+                        type Response = super::GetTenantSettingsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTenantSettingsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::get_tenant_settings(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = get_tenant_settingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/save_webhook" => {
+                    #[allow(non_camel_case_types)]
+                    struct save_webhookSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::SaveWebhookRequest>
+                        for save_webhookSvc<T>
+                    {
+                        type Response = super::SaveWebhookResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SaveWebhookRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::save_webhook(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = save_webhookSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/delete_webhook" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_webhookSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::DeleteWebhookRequest>
+                        for delete_webhookSvc<T>
+                    {
+                        type Response = super::DeleteWebhookResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteWebhookRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::delete_webhook(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = delete_webhookSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/list_webhooks" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_webhooksSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ListWebhooksRequest>
+                        for list_webhooksSvc<T>
+                    {
+                        type Response = super::ListWebhooksResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListWebhooksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::list_webhooks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = list_webhooksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/list_dead_letters" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_dead_lettersSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ListDeadLettersRequest>
+                        for list_dead_lettersSvc<T>
+                    {
+                        type Response = super::ListDeadLettersResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListDeadLettersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::list_dead_letters(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = list_dead_lettersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/save_pool" => {
+                    #[allow(non_camel_case_types)]
+                    struct save_poolSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::SavePoolRequest>
+                        for save_poolSvc<T>
+                    {
+                        type Response = super::SavePoolResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SavePoolRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::save_pool(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = save_poolSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/get_pool" => {
+                    #[allow(non_camel_case_types)]
+                    struct get_poolSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::GetPoolRequest> for get_poolSvc<T> {
+                        type Response = super::GetPoolResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetPoolRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::get_pool(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = get_poolSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/reserve_from_pool" => {
+                    #[allow(non_camel_case_types)]
+                    struct reserve_from_poolSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ReserveFromPoolRequest>
+                        for reserve_from_poolSvc<T>
+                    {
+                        type Response = super::ReserveFromPoolResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReserveFromPoolRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::reserve_from_pool(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = reserve_from_poolSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/set_policy" => {
+                    #[allow(non_camel_case_types)]
+                    struct set_policySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::SetPolicyRequest>
+                        for set_policySvc<T>
+                    {
+                        type Response = super::SetPolicyResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetPolicyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::set_policy(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = set_policySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/list_policies" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_policiesSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ListPoliciesRequest>
+                        for list_policiesSvc<T>
+                    {
+                        type Response = super::ListPoliciesResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPoliciesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::list_policies(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = list_policiesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/overbooked_windows" => {
                     #[allow(non_camel_case_types)]
-                    struct reserveSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::ReserveRequest> for reserveSvc<T> {
-                        type Response = super::ReserveResponse;
+                    struct overbooked_windowsSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::OverbookedWindowsRequest>
+                        for overbooked_windowsSvc<T>
+                    {
+                        type Response = super::OverbookedWindowsResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ReserveRequest>,
+                            request: tonic::Request<super::OverbookedWindowsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::reserve(&inner, request).await
+                                <T as ReservationService>::overbooked_windows(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -639,7 +4473,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = reserveSvc(inner);
+                        let method = overbooked_windowsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -655,19 +4489,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/confirm" => {
+                "/reservation.ReservationService/availability" => {
                     #[allow(non_camel_case_types)]
-                    struct confirmSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::ConfirmRequest> for confirmSvc<T> {
-                        type Response = super::ConfirmResponse;
+                    struct availabilitySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::AvailabilityRequest>
+                        for availabilitySvc<T>
+                    {
+                        type Response = super::AvailabilityResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ConfirmRequest>,
+                            request: tonic::Request<super::AvailabilityRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::confirm(&inner, request).await
+                                <T as ReservationService>::availability(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -678,7 +4515,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = confirmSvc(inner);
+                        let method = availabilitySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -694,19 +4531,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/update" => {
+                "/reservation.ReservationService/utilization" => {
                     #[allow(non_camel_case_types)]
-                    struct updateSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::UpdateRequest> for updateSvc<T> {
-                        type Response = super::UpdateResponse;
+                    struct utilizationSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::UtilizationRequest>
+                        for utilizationSvc<T>
+                    {
+                        type Response = super::UtilizationResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateRequest>,
+                            request: tonic::Request<super::UtilizationRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::update(&inner, request).await
+                                <T as ReservationService>::utilization(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -717,7 +4557,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = updateSvc(inner);
+                        let method = utilizationSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -733,19 +4573,21 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/cancel" => {
+                "/reservation.ReservationService/occupancy" => {
                     #[allow(non_camel_case_types)]
-                    struct cancelSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::CancelRequest> for cancelSvc<T> {
-                        type Response = super::CancelResponse;
+                    struct occupancySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::OccupancyRequest>
+                        for occupancySvc<T>
+                    {
+                        type Response = super::OccupancyResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CancelRequest>,
+                            request: tonic::Request<super::OccupancyRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::cancel(&inner, request).await
+                                <T as ReservationService>::occupancy(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -756,7 +4598,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = cancelSvc(inner);
+                        let method = occupancySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -772,19 +4614,65 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/get" => {
+                "/reservation.ReservationService/export" => {
                     #[allow(non_camel_case_types)]
-                    struct getSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::GetRequest> for getSvc<T> {
-                        type Response = super::GetResponse;
+                    struct exportSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::ServerStreamingService<super::ExportRequest>
+                        for exportSvc<T>
+                    {
+                        type Response = super::ExportChunk;
+                        type ResponseStream = T::exportStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::export(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = exportSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/import" => {
+                    #[allow(non_camel_case_types)]
+                    struct importSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::ClientStreamingService<super::ImportChunk> for importSvc<T>
+                    {
+                        type Response = super::ImportResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetRequest>,
+                            request: tonic::Request<tonic::Streaming<super::ImportChunk>>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::get(&inner, request).await
+                                <T as ReservationService>::import(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -795,7 +4683,49 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = getSvc(inner);
+                        let method = importSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/join_waitlist" => {
+                    #[allow(non_camel_case_types)]
+                    struct join_waitlistSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::JoinWaitlistRequest>
+                        for join_waitlistSvc<T>
+                    {
+                        type Response = super::JoinWaitlistResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::JoinWaitlistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::join_waitlist(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = join_waitlistSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -811,23 +4741,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/query" => {
+                "/reservation.ReservationService/leave_waitlist" => {
                     #[allow(non_camel_case_types)]
-                    struct querySvc<T: ReservationService>(pub Arc<T>);
+                    struct leave_waitlistSvc<T: ReservationService>(pub Arc<T>);
                     impl<T: ReservationService>
-                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
+                        tonic::server::UnaryService<super::LeaveWaitlistRequest>
+                        for leave_waitlistSvc<T>
                     {
-                        type Response = super::Reservation;
-                        type ResponseStream = T::queryStream;
-                        type Future =
-                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        type Response = super::LeaveWaitlistResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::QueryRequest>,
+                            request: tonic::Request<super::LeaveWaitlistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::query(&inner, request).await
+                                <T as ReservationService>::leave_waitlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -838,7 +4767,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = querySvc(inner);
+                        let method = leave_waitlistSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -849,24 +4778,27 @@ pub mod reservation_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/filter" => {
+                "/reservation.ReservationService/list_waitlist" => {
                     #[allow(non_camel_case_types)]
-                    struct filterSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::FilterRequest> for filterSvc<T> {
-                        type Response = super::FilterResponse;
+                    struct list_waitlistSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ListWaitlistRequest>
+                        for list_waitlistSvc<T>
+                    {
+                        type Response = super::ListWaitlistResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::FilterRequest>,
+                            request: tonic::Request<super::ListWaitlistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::filter(&inner, request).await
+                                <T as ReservationService>::list_waitlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -877,7 +4809,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = filterSvc(inner);
+                        let method = list_waitlistSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -893,24 +4825,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/listen" => {
+                "/reservation.ReservationService/add_attendee" => {
                     #[allow(non_camel_case_types)]
-                    struct listenSvc<T: ReservationService>(pub Arc<T>);
+                    struct add_attendeeSvc<T: ReservationService>(pub Arc<T>);
                     impl<T: ReservationService>
-                        tonic::server::ServerStreamingService<super::ListenRequest>
-                        for listenSvc<T>
+                        tonic::server::UnaryService<super::AddAttendeeRequest>
+                        for add_attendeeSvc<T>
                     {
-                        type Response = super::Reservation;
-                        type ResponseStream = T::listenStream;
-                        type Future =
-                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        type Response = super::AddAttendeeResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ListenRequest>,
+                            request: tonic::Request<super::AddAttendeeRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::listen(&inner, request).await
+                                <T as ReservationService>::add_attendee(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -921,7 +4851,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = listenSvc(inner);
+                        let method = add_attendeeSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -932,7 +4862,130 @@ pub mod reservation_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/remove_attendee" => {
+                    #[allow(non_camel_case_types)]
+                    struct remove_attendeeSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::RemoveAttendeeRequest>
+                        for remove_attendeeSvc<T>
+                    {
+                        type Response = super::RemoveAttendeeResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemoveAttendeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::remove_attendee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = remove_attendeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/respond" => {
+                    #[allow(non_camel_case_types)]
+                    struct respondSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::RespondRequest> for respondSvc<T> {
+                        type Response = super::RespondResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RespondRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::respond(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = respondSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/list_attendees" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_attendeesSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ListAttendeesRequest>
+                        for list_attendeesSvc<T>
+                    {
+                        type Response = super::ListAttendeesResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAttendeesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::list_attendees(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = list_attendeesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
@@ -0,0 +1,146 @@
+use sqlx::{FromRow, Row, postgres::PgRow};
+
+use crate::{
+    Attendee, AttendeeResponse, AttendeeRole, Error, RsvpAttendeeResponse, RsvpAttendeeRole,
+};
+
+impl Attendee {
+    pub fn new_user(rid: i64, uid: impl Into<String>, role: AttendeeRole) -> Self {
+        Attendee {
+            reservation_id: rid,
+            user_id: uid.into(),
+            role: role as i32,
+            response: AttendeeResponse::NeedsAction as i32,
+            ..Default::default()
+        }
+    }
+
+    pub fn new_email(rid: i64, email: impl Into<String>, role: AttendeeRole) -> Self {
+        Attendee {
+            reservation_id: rid,
+            email: email.into(),
+            role: role as i32,
+            response: AttendeeResponse::NeedsAction as i32,
+            ..Default::default()
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.reservation_id <= 0 {
+            return Err(Error::InvalidReservationId(self.reservation_id));
+        }
+        match (self.user_id.is_empty(), self.email.is_empty()) {
+            (false, true) if self.user_id.len() <= 64 => Ok(()),
+            (true, false) if self.email.len() <= 320 && self.email.contains('@') => Ok(()),
+            (false, false) => Err(Error::InvalidAttendee(
+                "either user id or email, not both".to_string(),
+            )),
+            (true, true) => Err(Error::InvalidAttendee(
+                "user id or email required".to_string(),
+            )),
+            _ => Err(Error::InvalidAttendee(format!(
+                "{}{}",
+                self.user_id, self.email
+            ))),
+        }
+    }
+
+    /// the user id, or the email of an external attendee
+    pub fn who(&self) -> &str {
+        if self.user_id.is_empty() {
+            &self.email
+        } else {
+            &self.user_id
+        }
+    }
+}
+
+impl FromRow<'_, PgRow> for Attendee {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let role: RsvpAttendeeRole = row.get("role");
+        let response: RsvpAttendeeResponse = row.get("response");
+        Ok(Attendee {
+            id: row.get("id"),
+            reservation_id: row.get("reservation_id"),
+            user_id: row.get::<Option<String>, _>("user_id").unwrap_or_default(),
+            email: row.get::<Option<String>, _>("email").unwrap_or_default(),
+            role: AttendeeRole::from(role) as i32,
+            response: AttendeeResponse::from(response) as i32,
+            tenant_id: row.get("tenant_id"),
+        })
+    }
+}
+
+impl From<RsvpAttendeeRole> for AttendeeRole {
+    fn from(role: RsvpAttendeeRole) -> Self {
+        match role {
+            RsvpAttendeeRole::Unknown => AttendeeRole::Unknown,
+            RsvpAttendeeRole::Required => AttendeeRole::Required,
+            RsvpAttendeeRole::Optional => AttendeeRole::Optional,
+            RsvpAttendeeRole::Guest => AttendeeRole::Guest,
+        }
+    }
+}
+
+impl From<AttendeeRole> for RsvpAttendeeRole {
+    fn from(role: AttendeeRole) -> Self {
+        match role {
+            AttendeeRole::Unknown => RsvpAttendeeRole::Unknown,
+            AttendeeRole::Required => RsvpAttendeeRole::Required,
+            AttendeeRole::Optional => RsvpAttendeeRole::Optional,
+            AttendeeRole::Guest => RsvpAttendeeRole::Guest,
+        }
+    }
+}
+
+impl From<RsvpAttendeeResponse> for AttendeeResponse {
+    fn from(response: RsvpAttendeeResponse) -> Self {
+        match response {
+            RsvpAttendeeResponse::Unknown => AttendeeResponse::Unknown,
+            RsvpAttendeeResponse::NeedsAction => AttendeeResponse::NeedsAction,
+            RsvpAttendeeResponse::Accepted => AttendeeResponse::Accepted,
+            RsvpAttendeeResponse::Declined => AttendeeResponse::Declined,
+            RsvpAttendeeResponse::Tentative => AttendeeResponse::Tentative,
+        }
+    }
+}
+
+impl From<AttendeeResponse> for RsvpAttendeeResponse {
+    fn from(response: AttendeeResponse) -> Self {
+        match response {
+            AttendeeResponse::Unknown => RsvpAttendeeResponse::Unknown,
+            AttendeeResponse::NeedsAction => RsvpAttendeeResponse::NeedsAction,
+            AttendeeResponse::Accepted => RsvpAttendeeResponse::Accepted,
+            AttendeeResponse::Declined => RsvpAttendeeResponse::Declined,
+            AttendeeResponse::Tentative => RsvpAttendeeResponse::Tentative,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attendee_validate_should_require_user_or_email() {
+        assert!(
+            Attendee::new_user(1, "aliceId", AttendeeRole::Required)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            Attendee::new_email(1, "bob@example.com", AttendeeRole::Guest)
+                .validate()
+                .is_ok()
+        );
+        assert!(matches!(
+            Attendee::new_email(1, "bob", AttendeeRole::Guest).validate(),
+            Err(Error::InvalidAttendee(_))
+        ));
+        let both = Attendee {
+            email: "bob@example.com".to_string(),
+            ..Attendee::new_user(1, "aliceId", AttendeeRole::Required)
+        };
+        assert!(matches!(both.validate(), Err(Error::InvalidAttendee(_))));
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Row, postgres::PgRow};
+
+use crate::{BookingPolicy, PolicyViolation};
+
+impl BookingPolicy {
+    /// check duration, advance booking horizon and lead time of a reservation window made at `now`
+    pub fn check_window(
+        &self,
+        now: DateTime<Utc>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<(), PolicyViolation> {
+        self.check_duration(start, end)?;
+
+        if let Some(limit) = self.max_advance_secs
+            && (start - now).num_seconds() > limit as i64
+        {
+            return Err(PolicyViolation::MaxAdvance(limit));
+        }
+
+        if let Some(limit) = self.min_lead_time_secs
+            && (start - now).num_seconds() < limit as i64
+        {
+            return Err(PolicyViolation::MinLeadTime(limit));
+        }
+
+        Ok(())
+    }
+
+    /// check the duration of a reservation window
+    pub fn check_duration(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<(), PolicyViolation> {
+        match self.max_duration_secs {
+            Some(limit) if (end - start).num_seconds() > limit as i64 => {
+                Err(PolicyViolation::MaxDuration(limit))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// check the number of active reservations the user already holds
+    pub fn check_active_reservations(&self, active: i64) -> Result<(), PolicyViolation> {
+        match self.max_active_reservations {
+            Some(limit) if active >= limit as i64 => {
+                Err(PolicyViolation::MaxActiveReservations(limit))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// how many overbooked reservations a resource type of the given capacity may hold
+    pub fn overbooking_allowance(&self, capacity: i64) -> i64 {
+        capacity * self.overbooking_percent.unwrap_or_default() as i64 / 100
+    }
+}
+
+impl FromRow<'_, PgRow> for BookingPolicy {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let max_active_reservations: Option<i32> = row.get("max_active_reservations");
+        let max_duration_secs: Option<i64> = row.get("max_duration_secs");
+        let max_advance_secs: Option<i64> = row.get("max_advance_secs");
+        let min_lead_time_secs: Option<i64> = row.get("min_lead_time_secs");
+        let overbooking_percent: Option<i32> = row.get("overbooking_percent");
+        Ok(BookingPolicy {
+            resource_type: row.get("resource_type"),
+            max_active_reservations: max_active_reservations.map(|limit| limit as u32),
+            max_duration_secs: max_duration_secs.map(|limit| limit as u64),
+            max_advance_secs: max_advance_secs.map(|limit| limit as u64),
+            min_lead_time_secs: min_lead_time_secs.map(|limit| limit as u64),
+            overbooking_percent: overbooking_percent.map(|percent| percent as u32),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_window_should_reject_broken_rules() {
+        let now: DateTime<Utc> = "2025-06-01T08:00:00Z".parse().unwrap();
+        let policy = BookingPolicy {
+            resource_type: "meeting-room".into(),
+            max_duration_secs: Some(2 * 3600),
+            max_advance_secs: Some(7 * 24 * 3600),
+            min_lead_time_secs: Some(1800),
+            ..Default::default()
+        };
+
+        let start = "2025-06-01T10:00:00Z".parse().unwrap();
+        let end = "2025-06-01T11:00:00Z".parse().unwrap();
+        assert_eq!(policy.check_window(now, start, end), Ok(()));
+
+        let end = "2025-06-01T13:00:00Z".parse().unwrap();
+        assert_eq!(
+            policy.check_window(now, start, end),
+            Err(PolicyViolation::MaxDuration(2 * 3600))
+        );
+
+        let start = "2025-06-10T10:00:00Z".parse().unwrap();
+        let end = "2025-06-10T11:00:00Z".parse().unwrap();
+        assert_eq!(
+            policy.check_window(now, start, end),
+            Err(PolicyViolation::MaxAdvance(7 * 24 * 3600))
+        );
+
+        let start = "2025-06-01T08:10:00Z".parse().unwrap();
+        let end = "2025-06-01T09:00:00Z".parse().unwrap();
+        assert_eq!(
+            policy.check_window(now, start, end),
+            Err(PolicyViolation::MinLeadTime(1800))
+        );
+    }
+
+    #[test]
+    fn check_active_reservations_should_respect_limit() {
+        let policy = BookingPolicy {
+            max_active_reservations: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(policy.check_active_reservations(1), Ok(()));
+        assert_eq!(
+            policy.check_active_reservations(2),
+            Err(PolicyViolation::MaxActiveReservations(2))
+        );
+        assert_eq!(
+            BookingPolicy::default().check_active_reservations(1000),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn overbooking_allowance_should_round_down() {
+        let policy = BookingPolicy {
+            overbooking_percent: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(policy.overbooking_allowance(100), 5);
+        assert_eq!(policy.overbooking_allowance(39), 1);
+        assert_eq!(policy.overbooking_allowance(19), 0);
+        assert_eq!(BookingPolicy::default().overbooking_allowance(100), 0);
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::{Closure, Error, OpeningHours, Resource, UtcWindow};
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+impl OpeningHours {
+    pub fn new(weekday: u32, opens: impl Into<String>, closes: impl Into<String>) -> Self {
+        OpeningHours {
+            weekday,
+            opens: opens.into(),
+            closes: closes.into(),
+        }
+    }
+
+    /// opening and closing time in minutes since local midnight
+    pub fn minutes(&self) -> Result<(u32, u32), Error> {
+        let opens = parse_minutes(&self.opens)?;
+        let closes = parse_minutes(&self.closes)?;
+        if !(1..=7).contains(&self.weekday) || opens >= closes {
+            return Err(Error::InvalidCalendar(format!(
+                "invalid opening hours {} {}-{}",
+                self.weekday, self.opens, self.closes
+            )));
+        }
+        Ok((opens, closes))
+    }
+}
+
+impl Closure {
+    pub fn new(day: impl Into<String>, note: impl Into<String>) -> Self {
+        Closure {
+            day: day.into(),
+            note: note.into(),
+        }
+    }
+
+    pub fn date(&self) -> Result<NaiveDate, Error> {
+        NaiveDate::parse_from_str(&self.day, "%Y-%m-%d")
+            .map_err(|_| Error::InvalidCalendar(format!("invalid closure day {}", self.day)))
+    }
+}
+
+impl Resource {
+    /// time zone of the opening hours, UTC if not set
+    pub fn tz(&self) -> Result<Tz, Error> {
+        if self.time_zone.is_empty() {
+            return Ok(Tz::UTC);
+        }
+        self.time_zone
+            .parse()
+            .map_err(|_| Error::InvalidCalendar(format!("invalid time zone {}", self.time_zone)))
+    }
+
+    pub fn validate_calendar(&self) -> Result<(), Error> {
+        self.tz()?;
+        for hours in &self.opening_hours {
+            hours.minutes()?;
+        }
+        for closure in &self.closures {
+            closure.date()?;
+        }
+        Ok(())
+    }
+
+    /// windows within [start, end) in which the resource is open, ordered and merged
+    pub fn open_windows(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<UtcWindow>, Error> {
+        let tz = self.tz()?;
+        let hours = self
+            .opening_hours
+            .iter()
+            .map(|hours| Ok((hours.weekday, hours.minutes()?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let closures = self
+            .closures
+            .iter()
+            .map(Closure::date)
+            .collect::<Result<HashSet<_>, Error>>()?;
+
+        let mut windows = Vec::new();
+        let last = end.with_timezone(&tz).date_naive();
+        let mut day = start.with_timezone(&tz).date_naive();
+        while day <= last {
+            if !closures.contains(&day) {
+                let weekday = day.weekday().number_from_monday();
+                let day_hours = if hours.is_empty() {
+                    vec![(0, MINUTES_PER_DAY)]
+                } else {
+                    hours
+                        .iter()
+                        .filter(|(w, _)| *w == weekday)
+                        .map(|(_, minutes)| *minutes)
+                        .collect()
+                };
+                for (opens, closes) in day_hours {
+                    let opens = local_to_utc(&tz, day, opens);
+                    let closes = local_to_utc(&tz, day, closes);
+                    if let (Some(opens), Some(closes)) = (opens, closes) {
+                        windows.push((opens.max(start), closes.min(end)));
+                    }
+                }
+            }
+            day = day.succ_opt().ok_or(Error::InvalidTime)?;
+        }
+
+        windows.retain(|(opens, closes)| opens < closes);
+        windows.sort();
+        let mut merged: Vec<UtcWindow> = Vec::with_capacity(windows.len());
+        for (opens, closes) in windows {
+            match merged.last_mut() {
+                Some(last) if opens <= last.1 => last.1 = last.1.max(closes),
+                _ => merged.push((opens, closes)),
+            }
+        }
+        Ok(merged)
+    }
+
+    /// the resource must be open for the whole window [start, end)
+    pub fn check_opening_hours(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        let windows = self.open_windows(start, end)?;
+        let closed_at = match windows.first() {
+            Some((opens, closes)) if *opens == start && *closes == end => return Ok(()),
+            Some((opens, closes)) if *opens == start => *closes,
+            _ => start,
+        };
+        let tz = self.tz()?;
+        Err(Error::OutsideOpeningHours(format!(
+            "{} is closed at {} ({})",
+            self.id,
+            closed_at.with_timezone(&tz).format("%Y-%m-%d %H:%M"),
+            tz
+        )))
+    }
+}
+
+fn parse_minutes(s: &str) -> Result<u32, Error> {
+    let invalid = || Error::InvalidCalendar(format!("invalid time {s}, expect HH:MM"));
+    let (h, m) = s.split_once(':').ok_or_else(invalid)?;
+    let (h, m): (u32, u32) = (
+        h.parse().map_err(|_| invalid())?,
+        m.parse().map_err(|_| invalid())?,
+    );
+    let minutes = h * 60 + m;
+    if m >= 60 || minutes > MINUTES_PER_DAY {
+        return Err(invalid());
+    }
+    Ok(minutes)
+}
+
+fn local_to_utc(tz: &Tz, day: NaiveDate, minutes: u32) -> Option<DateTime<Utc>> {
+    let local: NaiveDateTime = day.and_hms_opt(0, 0, 0)? + Duration::minutes(minutes as i64);
+    // a local time skipped by a daylight saving change is moved past the gap
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn boardroom() -> Resource {
+        Resource {
+            time_zone: "Asia/Shanghai".into(),
+            // Monday to Friday, 09:00-12:00 and 13:00-18:00
+            opening_hours: (1..=5)
+                .flat_map(|d| {
+                    [
+                        OpeningHours::new(d, "09:00", "12:00"),
+                        OpeningHours::new(d, "13:00", "18:00"),
+                    ]
+                })
+                .collect(),
+            closures: vec![Closure::new("2025-10-01", "National Day")],
+            ..Resource::new("boardroom", "meeting-room")
+        }
+    }
+
+    #[test]
+    fn open_windows_should_follow_local_opening_hours() {
+        // Tuesday 2025-09-30 in Asia/Shanghai, 01:00 UTC is 09:00 local
+        let windows = boardroom()
+            .open_windows(t("2025-09-30T00:00:00Z"), t("2025-10-02T00:00:00Z"))
+            .unwrap();
+        assert_eq!(
+            windows,
+            vec![
+                (t("2025-09-30T01:00:00Z"), t("2025-09-30T04:00:00Z")),
+                (t("2025-09-30T05:00:00Z"), t("2025-09-30T10:00:00Z")),
+                // 2025-10-01 is a closure, 2025-10-02 opens at 01:00 UTC
+            ]
+        );
+    }
+
+    #[test]
+    fn open_windows_should_merge_adjacent_days() {
+        let hotel = Resource {
+            opening_hours: (1..=7)
+                .map(|d| OpeningHours::new(d, "00:00", "24:00"))
+                .collect(),
+            ..Resource::new("ocean-view-room-713", "room")
+        };
+        let windows = hotel
+            .open_windows(t("2025-09-30T12:00:00Z"), t("2025-10-03T12:00:00Z"))
+            .unwrap();
+        assert_eq!(
+            windows,
+            vec![(t("2025-09-30T12:00:00Z"), t("2025-10-03T12:00:00Z"))]
+        );
+    }
+
+    #[test]
+    fn check_opening_hours_should_describe_closed_time() {
+        let room = boardroom();
+        assert!(
+            room.check_opening_hours(t("2025-09-30T01:00:00Z"), t("2025-09-30T02:00:00Z"))
+                .is_ok()
+        );
+        assert_eq!(
+            room.check_opening_hours(t("2025-09-30T03:00:00Z"), t("2025-09-30T06:00:00Z")),
+            Err(Error::OutsideOpeningHours(
+                "boardroom is closed at 2025-09-30 12:00 (Asia/Shanghai)".into()
+            ))
+        );
+        assert_eq!(
+            room.check_opening_hours(t("2025-09-29T19:00:00Z"), t("2025-09-30T02:00:00Z")),
+            Err(Error::OutsideOpeningHours(
+                "boardroom is closed at 2025-09-30 03:00 (Asia/Shanghai)".into()
+            ))
+        );
+        assert!(
+            room.check_opening_hours(t("2025-10-01T01:00:00Z"), t("2025-10-01T02:00:00Z"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn invalid_calendar_should_be_rejected() {
+        let room = Resource {
+            time_zone: "Mars/Olympus".into(),
+            ..Resource::new("boardroom", "meeting-room")
+        };
+        assert!(room.validate_calendar().is_err());
+        assert!(OpeningHours::new(1, "18:00", "09:00").minutes().is_err());
+        assert!(OpeningHours::new(8, "09:00", "18:00").minutes().is_err());
+        assert!(OpeningHours::new(1, "09:00", "24:01").minutes().is_err());
+        assert_eq!(
+            OpeningHours::new(7, "00:00", "24:00").minutes().unwrap(),
+            (0, 1440)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+
+use crate::{CancellationTier, Error, PolicyViolation, Resource};
+
+impl CancellationTier {
+    pub fn new(min_notice_secs: u64, fee_percent: u32) -> Self {
+        CancellationTier {
+            min_notice_secs,
+            fee_percent,
+        }
+    }
+}
+
+impl Resource {
+    pub(crate) fn validate_cancellation_tiers(&self) -> Result<(), Error> {
+        for (i, tier) in self.cancellation_tiers.iter().enumerate() {
+            if tier.fee_percent > 100 {
+                return Err(Error::InvalidCancellationPolicy(format!(
+                    "fee of {}% exceeds the price",
+                    tier.fee_percent
+                )));
+            }
+            if self.cancellation_tiers[..i]
+                .iter()
+                .any(|other| other.min_notice_secs == tier.min_notice_secs)
+            {
+                return Err(Error::InvalidCancellationPolicy(format!(
+                    "more than one tier for a notice of {} seconds",
+                    tier.min_notice_secs
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// fee in percent of the price for cancelling a reservation starting at `start` now
+    pub fn cancellation_fee(&self, start: DateTime<Utc>, now: DateTime<Utc>) -> Result<u32, Error> {
+        let Some(shortest) = self
+            .cancellation_tiers
+            .iter()
+            .map(|tier| tier.min_notice_secs)
+            .min()
+        else {
+            return Ok(0);
+        };
+        let notice = (start - now).num_seconds();
+        self.cancellation_tiers
+            .iter()
+            .filter(|tier| notice >= 0 && tier.min_notice_secs <= notice as u64)
+            .max_by_key(|tier| tier.min_notice_secs)
+            .map(|tier| tier.fee_percent)
+            .ok_or(Error::PolicyViolation(PolicyViolation::CancellationNotice(
+                shortest,
+            )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn cancellation_fee_should_follow_the_tier_of_the_notice() {
+        let resource = Resource {
+            cancellation_tiers: vec![
+                CancellationTier::new(7200, 50),
+                CancellationTier::new(86400, 0),
+            ],
+            ..Resource::new("ocean-view-room-713", "room")
+        };
+        let start: DateTime<Utc> = "2030-12-25T12:00:00Z".parse().unwrap();
+        let fee = |before: Duration| resource.cancellation_fee(start, start - before);
+        assert_eq!(fee(Duration::days(2)), Ok(0));
+        assert_eq!(fee(Duration::hours(24)), Ok(0));
+        assert_eq!(fee(Duration::hours(3)), Ok(50));
+        assert_eq!(
+            fee(Duration::hours(1)),
+            Err(Error::PolicyViolation(PolicyViolation::CancellationNotice(
+                7200
+            )))
+        );
+        assert!(fee(Duration::hours(-1)).is_err());
+
+        let free = Resource::new("ocean-view-room-713", "room");
+        assert_eq!(
+            free.cancellation_fee(start, start + Duration::hours(1)),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn invalid_cancellation_tiers_should_be_rejected() {
+        let resource = Resource {
+            cancellation_tiers: vec![CancellationTier::new(0, 120)],
+            ..Resource::new("ocean-view-room-713", "room")
+        };
+        assert!(matches!(
+            resource.validate(),
+            Err(Error::InvalidCancellationPolicy(_))
+        ));
+        let resource = Resource {
+            cancellation_tiers: vec![CancellationTier::new(0, 100), CancellationTier::new(0, 50)],
+            ..Resource::new("ocean-view-room-713", "room")
+        };
+        assert!(resource.validate().is_err());
+    }
+}
@@ -0,0 +1,270 @@
+use chrono_tz::Tz;
+use prost_types::Timestamp;
+use serde_json::{Map, Value};
+
+use crate::{
+    Error, ExportFormat, ExportRequest, Reservation, ReservationStatus, convert_to_utc_time,
+};
+
+/// columns of an export in their default order
+pub const EXPORT_COLUMNS: [&str; 15] = [
+    "id",
+    "tenant_id",
+    "user_id",
+    "resource_id",
+    "status",
+    "start",
+    "end",
+    "note",
+    "priority",
+    "checked_in_at",
+    "checked_out_at",
+    "transfer_to",
+    "overbooked",
+    "price",
+    "currency",
+];
+
+impl ExportRequest {
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.selection.is_none() {
+            return Err(Error::InvalidExport("missing query or filter".into()));
+        }
+        ExportFormat::try_from(self.format)
+            .map_err(|_| Error::InvalidExport(format!("invalid format {}", self.format)))?;
+        if let Some(column) = self
+            .columns
+            .iter()
+            .find(|column| !EXPORT_COLUMNS.contains(&column.as_str()))
+        {
+            return Err(Error::InvalidExport(format!("unknown column {column}")));
+        }
+        self.tz()?;
+        Ok(())
+    }
+
+    /// time zone times are rendered in, UTC if not set
+    pub fn tz(&self) -> Result<Tz, Error> {
+        if self.time_zone.is_empty() {
+            return Ok(Tz::UTC);
+        }
+        self.time_zone
+            .parse()
+            .map_err(|_| Error::InvalidExport(format!("invalid time zone {}", self.time_zone)))
+    }
+}
+
+/// renders reservations as lines of an export
+#[derive(Debug, Clone)]
+pub struct ExportWriter {
+    format: ExportFormat,
+    columns: Vec<String>,
+    tz: Tz,
+}
+
+impl ExportWriter {
+    pub fn new(request: &ExportRequest) -> Result<Self, Error> {
+        request.validate()?;
+        let columns = if request.columns.is_empty() {
+            EXPORT_COLUMNS.iter().map(|c| c.to_string()).collect()
+        } else {
+            request.columns.clone()
+        };
+        Ok(Self {
+            format: request.format(),
+            columns,
+            tz: request.tz()?,
+        })
+    }
+
+    /// what the export starts with, the header line of CSV
+    pub fn header(&self) -> String {
+        match self.format {
+            ExportFormat::JsonLines => String::new(),
+            _ => {
+                let mut line = self.columns.join(",");
+                line.push_str("\r\n");
+                line
+            }
+        }
+    }
+
+    /// append the line of the reservation
+    pub fn write(&self, rsvp: &Reservation, out: &mut String) {
+        let values = self.columns.iter().map(|column| self.value(rsvp, column));
+        match self.format {
+            ExportFormat::JsonLines => {
+                let object: Map<String, Value> = self.columns.iter().cloned().zip(values).collect();
+                out.push_str(&Value::Object(object).to_string());
+                out.push('\n');
+            }
+            _ => {
+                for (i, value) in values.enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    let field = match value {
+                        Value::Null => String::new(),
+                        Value::String(s) => defuse_formula(s),
+                        value => value.to_string(),
+                    };
+                    out.push_str(&csv_field(&field));
+                }
+                out.push_str("\r\n");
+            }
+        }
+    }
+
+    fn value(&self, rsvp: &Reservation, column: &str) -> Value {
+        let price = rsvp.price.as_ref();
+        match column {
+            "id" => rsvp.id.into(),
+            "tenant_id" => rsvp.tenant_id.clone().into(),
+            "user_id" => rsvp.user_id.clone().into(),
+            "resource_id" => rsvp.resource_id.clone().into(),
+            "status" => ReservationStatus::try_from(rsvp.status)
+                .unwrap_or_default()
+                .to_string()
+                .into(),
+            "start" => self.time(rsvp.start.as_ref()),
+            "end" => self.time(rsvp.end.as_ref()),
+            "note" => rsvp.note.clone().into(),
+            "priority" => rsvp.priority.into(),
+            "checked_in_at" => self.time(rsvp.checked_in_at.as_ref()),
+            "checked_out_at" => self.time(rsvp.checked_out_at.as_ref()),
+            "transfer_to" => rsvp.transfer_to.clone().into(),
+            "overbooked" => rsvp.overbooked.into(),
+            "price" => price.map_or(Value::Null, |p| p.amount.clone().into()),
+            "currency" => price.map_or(Value::Null, |p| p.currency.clone().into()),
+            _ => Value::Null,
+        }
+    }
+
+    fn time(&self, ts: Option<&Timestamp>) -> Value {
+        ts.map_or(Value::Null, |ts| {
+            convert_to_utc_time(ts)
+                .with_timezone(&self.tz)
+                .to_rfc3339()
+                .into()
+        })
+    }
+}
+
+/// prefix text a spreadsheet would evaluate as a formula with a quote, so it shows as text.
+/// Numbers are left alone, a negative priority is no formula
+fn defuse_formula(text: String) -> String {
+    if text.starts_with(['=', '+', '-', '@']) {
+        format!("'{text}")
+    } else {
+        text
+    }
+}
+
+/// quote a CSV field if it holds a separator, quote or line break (RFC 4180)
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ReservationQuery, export_request::Selection};
+
+    fn request(format: ExportFormat, columns: &[&str]) -> ExportRequest {
+        ExportRequest {
+            selection: Some(Selection::Query(ReservationQuery::default())),
+            format: format as i32,
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            time_zone: "Asia/Shanghai".into(),
+        }
+    }
+
+    fn reservation() -> Reservation {
+        Reservation::new_pending(
+            "silwingsId",
+            "ocean-view-room-713",
+            "2030-12-25T12:00:00-0700".parse().unwrap(),
+            "2030-12-28T12:00:00-0700".parse().unwrap(),
+            "late arrival, \"quiet\" room",
+        )
+    }
+
+    #[test]
+    fn csv_export_should_quote_fields_and_render_times_in_the_time_zone() {
+        let writer = ExportWriter::new(&request(
+            ExportFormat::Csv,
+            &["id", "start", "note", "price"],
+        ))
+        .unwrap();
+        let mut out = writer.header();
+        writer.write(&reservation(), &mut out);
+        assert_eq!(
+            out,
+            "id,start,note,price\r\n0,2030-12-26T03:00:00+08:00,\"late arrival, \"\"quiet\"\" room\",\r\n"
+        );
+    }
+
+    #[test]
+    fn csv_export_should_not_leave_formulas_to_spreadsheets() {
+        let writer = ExportWriter::new(&request(ExportFormat::Csv, &["note", "priority"])).unwrap();
+        let mut out = String::new();
+        for note in ["=HYPERLINK(\"http://evil\")", "+1", "-1", "@SUM(A1)", "a=b"] {
+            writer.write(
+                &Reservation {
+                    note: note.into(),
+                    priority: -1,
+                    ..reservation()
+                },
+                &mut out,
+            );
+        }
+        assert_eq!(
+            out,
+            "\"'=HYPERLINK(\"\"http://evil\"\")\",-1\r\n'+1,-1\r\n'-1,-1\r\n'@SUM(A1),-1\r\na=b,-1\r\n"
+        );
+    }
+
+    #[test]
+    fn json_lines_export_should_write_an_object_per_line() {
+        let writer = ExportWriter::new(&request(ExportFormat::JsonLines, &[])).unwrap();
+        assert_eq!(writer.header(), "");
+        let mut out = String::new();
+        writer.write(&reservation(), &mut out);
+        writer.write(&reservation(), &mut out);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        let object: Value = serde_json::from_str(lines[0]).unwrap();
+        // fields keep the order of the columns
+        let fields = object.as_object().unwrap().keys().collect::<Vec<_>>();
+        assert_eq!(fields, EXPORT_COLUMNS);
+        assert_eq!(object["status"], "pending");
+        assert_eq!(object["end"], "2030-12-29T03:00:00+08:00");
+        assert_eq!(object["checked_in_at"], Value::Null);
+    }
+
+    #[test]
+    fn invalid_export_request_should_be_rejected() {
+        let invalid = [
+            ExportRequest {
+                selection: None,
+                ..request(ExportFormat::Csv, &[])
+            },
+            request(ExportFormat::Csv, &["id", "secret"]),
+            ExportRequest {
+                format: 7,
+                ..request(ExportFormat::Csv, &[])
+            },
+            ExportRequest {
+                time_zone: "Mars/Olympus".into(),
+                ..request(ExportFormat::Csv, &[])
+            },
+        ];
+        for request in invalid {
+            assert!(matches!(request.validate(), Err(Error::InvalidExport(_))));
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use chrono::{DateTime, Utc};
+use prost_types::Timestamp;
+use serde_json::{Map, Value};
+
+use crate::{
+    ConflictWindow, EXPORT_COLUMNS, Error, ExportFormat, ImportConflict, Reservation,
+    ReservationConflictInfo, ReservationStatus, ReservationWindow, TimeWindow,
+    convert_to_timestamp,
+};
+
+/// columns an import needs, note is the only optional one read
+const REQUIRED_COLUMNS: [&str; 4] = ["user_id", "resource_id", "start", "end"];
+
+/// a data record of an import, with its reservation or why it is invalid
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRow {
+    /// counting from 1, without the CSV header and empty lines
+    pub row: u64,
+    pub reservation: Result<Reservation, String>,
+}
+
+/// splits the chunks of an import into rows as they arrive. Records may span chunks, and CSV
+/// records may span lines within quotes
+#[derive(Debug)]
+pub struct ImportReader {
+    format: ExportFormat,
+    buf: Vec<u8>,
+    /// how much of the buffer was scanned for the end of the record
+    scanned: usize,
+    quoted: bool,
+    header: Option<Vec<String>>,
+    rows: u64,
+}
+
+impl ImportReader {
+    pub fn new(format: ExportFormat) -> Self {
+        Self {
+            format,
+            buf: Vec::new(),
+            scanned: 0,
+            quoted: false,
+            header: None,
+            rows: 0,
+        }
+    }
+
+    /// the rows completed by the chunk
+    pub fn push(&mut self, data: &[u8]) -> Result<Vec<ImportRow>, Error> {
+        self.buf.extend_from_slice(data);
+        let csv = self.format != ExportFormat::JsonLines;
+        let mut rows = vec![];
+        let mut start = 0;
+        for i in self.scanned..self.buf.len() {
+            match self.buf[i] {
+                b'"' if csv => self.quoted = !self.quoted,
+                b'\n' if !self.quoted => {
+                    let record = self.buf[start..i].to_vec();
+                    rows.extend(self.record(&record)?);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        self.buf.drain(..start);
+        self.scanned = self.buf.len();
+        Ok(rows)
+    }
+
+    /// the last row if the file does not end with a line break
+    pub fn finish(mut self) -> Result<Option<ImportRow>, Error> {
+        let record = std::mem::take(&mut self.buf);
+        let row = self.record(&record)?;
+        if self.format != ExportFormat::JsonLines && self.header.is_none() {
+            return Err(Error::InvalidImport("missing CSV header".into()));
+        }
+        Ok(row)
+    }
+
+    fn record(&mut self, record: &[u8]) -> Result<Option<ImportRow>, Error> {
+        let record = String::from_utf8_lossy(record);
+        let record = record.strip_suffix('\r').unwrap_or(&record);
+        if record.trim().is_empty() {
+            return Ok(None);
+        }
+        if self.format != ExportFormat::JsonLines && self.header.is_none() {
+            self.header = Some(csv_header(record)?);
+            return Ok(None);
+        }
+
+        self.rows += 1;
+        let reservation = match self.header.as_ref() {
+            Some(header) => {
+                let fields = csv_fields(record);
+                if fields.len() != header.len() {
+                    Err(format!(
+                        "{} fields, the header has {}",
+                        fields.len(),
+                        header.len()
+                    ))
+                } else {
+                    reservation(|name| {
+                        let i = header.iter().position(|column| column == name)?;
+                        Some(fields[i].clone())
+                    })
+                }
+            }
+            None => json_reservation(record),
+        };
+        Ok(Some(ImportRow {
+            row: self.rows,
+            reservation,
+        }))
+    }
+}
+
+impl ImportConflict {
+    pub fn new(row: u64, info: ReservationConflictInfo) -> Self {
+        match info {
+            ReservationConflictInfo::Parsed(conflict) => ImportConflict {
+                row,
+                new: Some(conflict.new.into()),
+                existing: Some(conflict.old.into()),
+                detail: String::new(),
+            },
+            ReservationConflictInfo::Unparsed(detail) => ImportConflict {
+                row,
+                new: None,
+                existing: None,
+                detail,
+            },
+        }
+    }
+}
+
+impl From<ReservationWindow> for ConflictWindow {
+    fn from(window: ReservationWindow) -> Self {
+        ConflictWindow {
+            resource_id: window.rid,
+            window: Some(TimeWindow::new(window.start, window.end)),
+        }
+    }
+}
+
+fn csv_header(record: &str) -> Result<Vec<String>, Error> {
+    let header = csv_fields(record);
+    if let Some(column) = header
+        .iter()
+        .find(|column| !EXPORT_COLUMNS.contains(&column.as_str()))
+    {
+        return Err(Error::InvalidImport(format!("unknown column {column}")));
+    }
+    if let Some(column) = REQUIRED_COLUMNS
+        .iter()
+        .find(|column| !header.iter().any(|c| c == *column))
+    {
+        return Err(Error::InvalidImport(format!("missing column {column}")));
+    }
+    Ok(header)
+}
+
+/// fields of a CSV record, quotes removed (RFC 4180)
+fn csv_fields(record: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = record.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+fn json_reservation(record: &str) -> Result<Reservation, String> {
+    let object: Map<String, Value> =
+        serde_json::from_str(record).map_err(|e| format!("invalid JSON object: {e}"))?;
+    if let Some(field) = object
+        .keys()
+        .find(|field| !EXPORT_COLUMNS.contains(&field.as_str()))
+    {
+        return Err(format!("unknown field {field}"));
+    }
+    reservation(|name| match object.get(name)? {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        value => Some(value.to_string()),
+    })
+}
+
+/// the reservation of a record, validated
+fn reservation(field: impl Fn(&str) -> Option<String>) -> Result<Reservation, String> {
+    let time = |name: &str| -> Result<Option<Timestamp>, String> {
+        match field(name).filter(|s| !s.is_empty()) {
+            None => Ok(None),
+            Some(s) => DateTime::parse_from_rfc3339(&s)
+                .map(|t| Some(convert_to_timestamp(t.with_timezone(&Utc))))
+                .map_err(|_| format!("invalid {name} {s}")),
+        }
+    };
+    // imported reservations are new ones, their status and priority are not taken from the file
+    let rsvp = Reservation {
+        user_id: field("user_id").unwrap_or_default(),
+        resource_id: field("resource_id").unwrap_or_default(),
+        status: ReservationStatus::Pending as i32,
+        start: time("start")?,
+        end: time("end")?,
+        note: field("note").unwrap_or_default(),
+        ..Default::default()
+    };
+    rsvp.validate().map_err(|e| e.to_string())?;
+    Ok(rsvp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_import_should_read_records_across_chunks() {
+        let mut reader = ImportReader::new(ExportFormat::Csv);
+        let data = "id,user_id,resource_id,start,end,note,status\r\n\
+            1,silwingsId,room-1,2030-12-25T12:00:00+08:00,2030-12-26T12:00:00+08:00,\"two\r\nlines, \"\"quoted\"\"\",confirmed\r\n\
+            \r\n\
+            2,silwingsId,room-1,2030-12-25T12:00:00+08:00,2030-12-24T12:00:00+08:00,,\r\n\
+            3,silwingsId,room-1,yesterday,2030-12-24T12:00:00+08:00,,\r\n\
+            4,silwingsId,room-2";
+        // a byte at a time, so records and quotes are split everywhere
+        let mut rows = vec![];
+        for byte in data.as_bytes() {
+            rows.extend(reader.push(&[*byte]).unwrap());
+        }
+        rows.extend(reader.finish().unwrap());
+
+        assert_eq!(rows.len(), 4);
+        let rsvp = rows[0].reservation.as_ref().unwrap();
+        assert_eq!(rsvp.note, "two\r\nlines, \"quoted\"");
+        // the status of the file is not taken over
+        assert_eq!(rsvp.status, ReservationStatus::Pending as i32);
+        assert_eq!(rsvp.bounds().0.to_rfc3339(), "2030-12-25T04:00:00+00:00");
+        assert_eq!(rows[1].row, 2);
+        assert_eq!(
+            rows[1].reservation,
+            Err("Invalid start or end time for the reservation".into())
+        );
+        assert_eq!(rows[2].reservation, Err("invalid start yesterday".into()));
+        assert_eq!(
+            rows[3].reservation,
+            Err("3 fields, the header has 7".into())
+        );
+    }
+
+    #[test]
+    fn json_lines_import_should_read_a_row_per_line() {
+        let mut reader = ImportReader::new(ExportFormat::JsonLines);
+        let data = r#"{"user_id": "silwingsId", "resource_id": "room-1", "start": "2030-12-25T12:00:00Z", "end": "2030-12-26T12:00:00Z", "priority": 3}
+{"user_id": "silwingsId", "resource_id": "room-1", "status": "gone"}
+not json
+"#;
+        let rows = reader.push(data.as_bytes()).unwrap();
+        assert_eq!(reader.finish().unwrap(), None);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].reservation.as_ref().unwrap().priority, 0);
+        assert_eq!(
+            rows[1].reservation,
+            Err("Invalid start or end time for the reservation".into())
+        );
+        assert!(rows[2].reservation.is_err());
+    }
+
+    #[test]
+    fn csv_import_should_reject_an_invalid_header() {
+        for header in [
+            "user_id,resource_id,start\n",
+            "user_id,resource_id,start,end,secret\n",
+        ] {
+            let mut reader = ImportReader::new(ExportFormat::Csv);
+            assert!(matches!(
+                reader.push(header.as_bytes()),
+                Err(Error::InvalidImport(_))
+            ));
+        }
+        assert!(ImportReader::new(ExportFormat::Csv).finish().is_err());
+    }
+}
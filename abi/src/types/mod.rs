@@ -1,4 +1,25 @@
+mod attendee;
+mod booking_policy;
+mod calendar;
+mod cancellation;
+mod export;
+mod import;
+mod money;
+mod pool;
+mod rate_plan;
+mod report;
 mod reservation;
 mod reservation_query;
 mod reservation_status;
 mod reservations_filter;
+mod resource;
+mod slot;
+mod time_window;
+mod waitlist;
+mod webhook;
+
+pub use export::{EXPORT_COLUMNS, ExportWriter};
+pub use import::{ImportReader, ImportRow};
+pub use money::{DEFAULT_CURRENCY, currency_scale, validate_currency};
+pub use resource::DEFAULT_RESOURCE_TYPE;
+pub use time_window::UtcWindow;
@@ -0,0 +1,80 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::{Error, Money};
+
+/// currency of tenants which never set one
+pub const DEFAULT_CURRENCY: &str = "USD";
+
+impl Money {
+    /// the amount rounded to the minor unit of the currency
+    pub fn new(currency: impl Into<String>, amount: Decimal) -> Self {
+        let currency = currency.into();
+        let mut amount = amount.round_dp_with_strategy(
+            currency_scale(&currency),
+            RoundingStrategy::MidpointAwayFromZero,
+        );
+        amount.rescale(currency_scale(&currency));
+        Money {
+            currency,
+            amount: amount.to_string(),
+        }
+    }
+
+    pub fn amount(&self) -> Result<Decimal, Error> {
+        parse_amount(&self.amount)
+    }
+
+    /// the given percentage of the amount, e.g. a cancellation fee
+    pub fn percent(&self, percent: u32) -> Result<Money, Error> {
+        Ok(Money::new(
+            &self.currency,
+            self.amount()? * Decimal::from(percent) / Decimal::ONE_HUNDRED,
+        ))
+    }
+}
+
+/// digits after the decimal point of the currency's minor unit
+pub fn currency_scale(currency: &str) -> u32 {
+    match currency {
+        "CLP" | "ISK" | "JPY" | "KRW" | "VND" => 0,
+        "BHD" | "JOD" | "KWD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+/// an ISO 4217 code, three upper case letters
+pub fn validate_currency(currency: &str) -> Result<(), Error> {
+    if currency.len() == 3 && currency.bytes().all(|b| b.is_ascii_uppercase()) {
+        Ok(())
+    } else {
+        Err(Error::InvalidCurrency(currency.to_string()))
+    }
+}
+
+pub(crate) fn parse_amount(amount: &str) -> Result<Decimal, Error> {
+    amount
+        .parse::<Decimal>()
+        .map_err(|_| Error::InvalidRate(format!("invalid amount {amount}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn money_should_round_to_the_minor_unit_of_its_currency() {
+        let amount: Decimal = "1234.5650".parse().unwrap();
+        assert_eq!(Money::new("USD", amount).amount, "1234.57");
+        assert_eq!(Money::new("JPY", amount).amount, "1235");
+        assert_eq!(Money::new("KWD", amount).amount, "1234.565");
+        assert_eq!(Money::new("EUR", Decimal::from(25)).amount, "25.00");
+        assert_eq!(
+            Money::new("JPY", Decimal::from(1235)).percent(50).unwrap(),
+            Money::new("JPY", Decimal::from(618))
+        );
+        assert_eq!(
+            validate_currency("usd"),
+            Err(Error::InvalidCurrency("usd".into()))
+        );
+    }
+}
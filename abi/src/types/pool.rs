@@ -0,0 +1,85 @@
+use sqlx::{FromRow, Row, postgres::PgRow};
+
+use crate::{Error, Pool, PoolStrategy, RsvpPoolStrategy};
+
+impl Pool {
+    pub fn new(
+        id: impl Into<String>,
+        resource_ids: impl IntoIterator<Item = impl Into<String>>,
+        strategy: PoolStrategy,
+    ) -> Self {
+        Pool {
+            id: id.into(),
+            tenant_id: String::new(),
+            resource_ids: resource_ids.into_iter().map(Into::into).collect(),
+            strategy: strategy as i32,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.id.is_empty() || self.id.len() > 64 {
+            return Err(Error::InvalidResourceId(self.id.clone()));
+        }
+        if self.resource_ids.is_empty() {
+            return Err(Error::InvalidResourceId(format!(
+                "pool {} has no members",
+                self.id
+            )));
+        }
+        for (i, rid) in self.resource_ids.iter().enumerate() {
+            if rid.is_empty() || rid.len() > 64 || self.resource_ids[..i].contains(rid) {
+                return Err(Error::InvalidResourceId(rid.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromRow<'_, PgRow> for Pool {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let strategy: RsvpPoolStrategy = row.get("strategy");
+        Ok(Pool {
+            id: row.get("id"),
+            tenant_id: row.get("tenant_id"),
+            resource_ids: vec![],
+            strategy: PoolStrategy::from(strategy) as i32,
+        })
+    }
+}
+
+impl From<RsvpPoolStrategy> for PoolStrategy {
+    fn from(strategy: RsvpPoolStrategy) -> Self {
+        match strategy {
+            RsvpPoolStrategy::Unknown => PoolStrategy::Unknown,
+            RsvpPoolStrategy::FirstFit => PoolStrategy::FirstFit,
+            RsvpPoolStrategy::LeastRecentlyUsed => PoolStrategy::LeastRecentlyUsed,
+            RsvpPoolStrategy::BestFit => PoolStrategy::BestFit,
+        }
+    }
+}
+
+impl From<PoolStrategy> for RsvpPoolStrategy {
+    fn from(strategy: PoolStrategy) -> Self {
+        match strategy {
+            PoolStrategy::Unknown => RsvpPoolStrategy::Unknown,
+            PoolStrategy::FirstFit => RsvpPoolStrategy::FirstFit,
+            PoolStrategy::LeastRecentlyUsed => RsvpPoolStrategy::LeastRecentlyUsed,
+            PoolStrategy::BestFit => RsvpPoolStrategy::BestFit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_with_duplicate_members_should_be_invalid() {
+        let pool = Pool::new("car-park", ["p1", "p2"], PoolStrategy::FirstFit);
+        assert_eq!(pool.validate(), Ok(()));
+        let pool = Pool::new("car-park", ["p1", "p2", "p1"], PoolStrategy::FirstFit);
+        assert_eq!(pool.validate(), Err(Error::InvalidResourceId("p1".into())));
+        let pool = Pool::new("car-park", Vec::<String>::new(), PoolStrategy::FirstFit);
+        assert!(pool.validate().is_err());
+    }
+}
@@ -0,0 +1,301 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+use rust_decimal::Decimal;
+use sqlx::{FromRow, Row, postgres::PgRow};
+
+use super::money::parse_amount;
+use crate::{Error, Money, Quote, QuoteLine, Rate, RatePlan, RateUnit, RsvpRateUnit};
+
+impl Rate {
+    pub fn new(name: impl Into<String>, unit: RateUnit, amount: impl Into<String>) -> Self {
+        Rate {
+            name: name.into(),
+            unit: unit as i32,
+            amount: amount.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.unit() == RateUnit::Unknown {
+            return Err(Error::InvalidRate(format!(
+                "rate {} has no unit",
+                self.name
+            )));
+        }
+        if self.name.len() > 64 {
+            return Err(Error::InvalidRate(format!("name too long {}", self.name)));
+        }
+        if parse_amount(&self.amount)?.is_sign_negative() {
+            return Err(Error::InvalidRate(format!(
+                "negative amount {}",
+                self.amount
+            )));
+        }
+        if let Some(day) = self.weekdays.iter().find(|day| !(1..=7).contains(*day)) {
+            return Err(Error::InvalidRate(format!("invalid weekday {day}")));
+        }
+        if let (Some(from), Some(until)) = (self.valid_from()?, self.valid_until()?)
+            && from > until
+        {
+            return Err(Error::InvalidRate(format!(
+                "rate {} ends before it starts",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn valid_from(&self) -> Result<Option<NaiveDate>, Error> {
+        parse_day(&self.valid_from)
+    }
+
+    pub fn valid_until(&self) -> Result<Option<NaiveDate>, Error> {
+        parse_day(&self.valid_until)
+    }
+
+    /// whether the rate applies to a period starting on the given local day
+    fn matches(&self, unit: RateUnit, day: NaiveDate) -> Result<bool, Error> {
+        Ok(self.unit() == unit
+            && (self.weekdays.is_empty()
+                || self.weekdays.contains(&day.weekday().number_from_monday()))
+            && self.valid_from()?.is_none_or(|from| from <= day)
+            && self.valid_until()?.is_none_or(|until| day <= until))
+    }
+}
+
+impl RatePlan {
+    pub fn new(resource_id: impl Into<String>, rates: Vec<Rate>) -> Self {
+        RatePlan {
+            resource_id: resource_id.into(),
+            tenant_id: String::new(),
+            rates,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.resource_id.is_empty() || self.resource_id.len() > 64 {
+            return Err(Error::InvalidResourceId(self.resource_id.clone()));
+        }
+        self.rates.iter().try_for_each(Rate::validate)
+    }
+
+    /// price the window, periods start at `start` and are matched by their local day in `tz`.
+    /// Reservations of a day or longer are charged per day if the plan has daily rates
+    pub fn quote(
+        &self,
+        tz: Tz,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        currency: &str,
+    ) -> Result<Quote, Error> {
+        let has = |unit: RateUnit| self.rates.iter().any(|rate| rate.unit() == unit);
+        let unit = match (has(RateUnit::Hour), has(RateUnit::Day)) {
+            (_, true) if end - start >= Duration::days(1) => RateUnit::Day,
+            (true, _) => RateUnit::Hour,
+            (false, true) => RateUnit::Day,
+            (false, false) => {
+                return Err(Error::NoRate(format!(
+                    "{} has no rate plan",
+                    self.resource_id
+                )));
+            }
+        };
+        let step = match unit {
+            RateUnit::Day => Duration::days(1),
+            _ => Duration::hours(1),
+        };
+
+        // (index of the rate, number of periods) of consecutive periods charged at one rate
+        let mut charges: Vec<(usize, u32)> = Vec::new();
+        let mut period = start;
+        while period < end {
+            let day = period.with_timezone(&tz).date_naive();
+            let mut matched = None;
+            for (i, rate) in self.rates.iter().enumerate() {
+                if rate.matches(unit, day)? {
+                    matched = Some(i);
+                }
+            }
+            let Some(i) = matched else {
+                return Err(Error::NoRate(format!(
+                    "{} has no {} rate for {day}",
+                    self.resource_id,
+                    unit.as_str_name()
+                )));
+            };
+            match charges.last_mut() {
+                Some((last, quantity)) if *last == i => *quantity += 1,
+                _ => charges.push((i, 1)),
+            }
+            period += step;
+        }
+
+        let mut total = Decimal::ZERO;
+        let mut lines = Vec::with_capacity(charges.len());
+        for (i, quantity) in charges {
+            let rate = &self.rates[i];
+            let unit_amount = parse_amount(&rate.amount)?;
+            let amount = Money::new(currency, unit_amount * Decimal::from(quantity));
+            total += amount.amount()?;
+            lines.push(QuoteLine {
+                name: rate.name.clone(),
+                unit: unit as i32,
+                quantity,
+                unit_amount: unit_amount.normalize().to_string(),
+                amount: amount.amount,
+            });
+        }
+        Ok(Quote {
+            total: Some(Money::new(currency, total)),
+            lines,
+        })
+    }
+}
+
+impl FromRow<'_, PgRow> for Rate {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let unit: RsvpRateUnit = row.get("unit");
+        let amount: Decimal = row.get("amount");
+        let weekdays: Vec<i16> = row.get("weekdays");
+        let format_day = |day: Option<NaiveDate>| {
+            day.map(|day| day.format("%Y-%m-%d").to_string())
+                .unwrap_or_default()
+        };
+        Ok(Rate {
+            name: row.get("name"),
+            unit: RateUnit::from(unit) as i32,
+            amount: amount.normalize().to_string(),
+            weekdays: weekdays.into_iter().map(|day| day as u32).collect(),
+            valid_from: format_day(row.get("valid_from")),
+            valid_until: format_day(row.get("valid_until")),
+        })
+    }
+}
+
+impl From<RsvpRateUnit> for RateUnit {
+    fn from(unit: RsvpRateUnit) -> Self {
+        match unit {
+            RsvpRateUnit::Unknown => RateUnit::Unknown,
+            RsvpRateUnit::Hour => RateUnit::Hour,
+            RsvpRateUnit::Day => RateUnit::Day,
+        }
+    }
+}
+
+impl From<RateUnit> for RsvpRateUnit {
+    fn from(unit: RateUnit) -> Self {
+        match unit {
+            RateUnit::Unknown => RsvpRateUnit::Unknown,
+            RateUnit::Hour => RsvpRateUnit::Hour,
+            RateUnit::Day => RsvpRateUnit::Day,
+        }
+    }
+}
+
+fn parse_day(day: &str) -> Result<Option<NaiveDate>, Error> {
+    if day.is_empty() {
+        return Ok(None);
+    }
+    NaiveDate::parse_from_str(day, "%Y-%m-%d")
+        .map(Some)
+        .map_err(|_| Error::InvalidRate(format!("invalid day {day}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan() -> RatePlan {
+        RatePlan::new(
+            "ocean-view-room-713",
+            vec![
+                Rate::new("hourly", RateUnit::Hour, "12.50"),
+                Rate::new("nightly", RateUnit::Day, "180"),
+                Rate {
+                    weekdays: vec![6, 7],
+                    ..Rate::new("weekend", RateUnit::Day, "220")
+                },
+                Rate {
+                    valid_from: "2030-12-24".into(),
+                    valid_until: "2030-12-26".into(),
+                    ..Rate::new("christmas", RateUnit::Day, "300")
+                },
+            ],
+        )
+    }
+
+    fn t(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn quote_should_charge_every_started_hour() {
+        let quote = plan()
+            .quote(
+                Tz::UTC,
+                t("2030-12-02T10:00:00Z"),
+                t("2030-12-02T12:30:00Z"),
+                "USD",
+            )
+            .unwrap();
+        assert_eq!(quote.total, Some(Money::new("USD", Decimal::new(3750, 2))));
+        assert_eq!(quote.lines.len(), 1);
+        assert_eq!(quote.lines[0].quantity, 3);
+        assert_eq!(quote.lines[0].unit_amount, "12.5");
+    }
+
+    #[test]
+    fn quote_should_pick_the_last_matching_daily_rate() {
+        // Friday 2030-12-20 to Friday 2030-12-27 in Shanghai, the first day starts on Friday
+        let quote = plan()
+            .quote(
+                "Asia/Shanghai".parse().unwrap(),
+                t("2030-12-20T06:00:00Z"),
+                t("2030-12-27T06:00:00Z"),
+                "CNY",
+            )
+            .unwrap();
+        let lines = quote
+            .lines
+            .iter()
+            .map(|line| (line.name.as_str(), line.quantity))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                ("nightly", 1),
+                ("weekend", 2),
+                ("nightly", 1),
+                ("christmas", 3)
+            ]
+        );
+        // 180 + 440 + 180 + 900
+        assert_eq!(quote.total.unwrap().amount, "1700.00");
+    }
+
+    #[test]
+    fn quote_without_matching_rate_should_fail() {
+        let plan = RatePlan::new(
+            "ocean-view-room-713",
+            vec![Rate {
+                weekdays: vec![1],
+                ..Rate::new("monday", RateUnit::Hour, "10")
+            }],
+        );
+        let err = plan
+            .quote(
+                Tz::UTC,
+                t("2030-12-03T10:00:00Z"),
+                t("2030-12-03T11:00:00Z"),
+                "USD",
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::NoRate(_)));
+        assert!(
+            RatePlan::new("room", vec![Rate::new("bad", RateUnit::Hour, "-1")])
+                .validate()
+                .is_err()
+        );
+    }
+}
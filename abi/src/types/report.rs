@@ -0,0 +1,141 @@
+use chrono::Duration;
+use chrono_tz::Tz;
+
+use crate::{Error, OccupancyRequest, ReportBucket, UtilizationRequest};
+
+/// longest period a utilization report covers
+const MAX_REPORT_DAYS: i64 = 366;
+/// most buckets of an occupancy timeline
+const MAX_OCCUPANCY_BUCKETS: u64 = 2000;
+
+impl ReportBucket {
+    /// field of date_trunc() and step of the buckets in PostgreSQL
+    pub fn unit(&self) -> &'static str {
+        match self {
+            ReportBucket::Hour => "hour",
+            ReportBucket::Unknown | ReportBucket::Day => "day",
+            ReportBucket::Week => "week",
+        }
+    }
+}
+
+impl UtilizationRequest {
+    /// time zone of the report, UTC if not set
+    pub fn tz(&self) -> Result<Tz, Error> {
+        if self.time_zone.is_empty() {
+            return Ok(Tz::UTC);
+        }
+        self.time_zone
+            .parse()
+            .map_err(|_| Error::InvalidReport(format!("invalid time zone {}", self.time_zone)))
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        let Some(period) = self.period.as_ref() else {
+            return Err(Error::InvalidReport("missing period".into()));
+        };
+        period.validate()?;
+        let (start, end) = period.bounds();
+        if end - start > Duration::days(MAX_REPORT_DAYS) {
+            return Err(Error::InvalidReport(format!(
+                "period longer than {MAX_REPORT_DAYS} days"
+            )));
+        }
+        ReportBucket::try_from(self.bucket)
+            .map_err(|_| Error::InvalidReport(format!("invalid bucket {}", self.bucket)))?;
+        self.tz()?;
+        Ok(())
+    }
+}
+
+impl OccupancyRequest {
+    pub fn validate(&self) -> Result<(), Error> {
+        let Some(window) = self.window.as_ref() else {
+            return Err(Error::InvalidReport("missing window".into()));
+        };
+        window.validate()?;
+        if self.bucket_secs == 0 {
+            return Err(Error::InvalidReport("bucket size required".into()));
+        }
+        if self.bucket_count() > MAX_OCCUPANCY_BUCKETS {
+            return Err(Error::InvalidReport(format!(
+                "more than {MAX_OCCUPANCY_BUCKETS} buckets"
+            )));
+        }
+        Ok(())
+    }
+
+    /// number of buckets of a validated request, the last one may be shorter
+    pub fn bucket_count(&self) -> u64 {
+        let (start, end) = self.window.as_ref().unwrap().bounds();
+        ((end - start).num_seconds() as u64).div_ceil(self.bucket_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use super::*;
+    use crate::TimeWindow;
+
+    #[test]
+    fn utilization_request_should_validate() {
+        let start: DateTime<Utc> = "2030-12-01T00:00:00Z".parse().unwrap();
+        let request = UtilizationRequest {
+            resource_ids: vec!["ocean-view-room-713".into()],
+            period: Some(TimeWindow::new(start, start + Duration::days(7))),
+            bucket: ReportBucket::Week as i32,
+            time_zone: "Asia/Shanghai".into(),
+        };
+        assert!(request.validate().is_ok());
+        assert_eq!(request.tz().unwrap(), Tz::Asia__Shanghai);
+
+        let invalid = [
+            UtilizationRequest {
+                period: None,
+                ..request.clone()
+            },
+            UtilizationRequest {
+                period: Some(TimeWindow::new(start, start + Duration::days(400))),
+                ..request.clone()
+            },
+            UtilizationRequest {
+                bucket: 9,
+                ..request.clone()
+            },
+            UtilizationRequest {
+                time_zone: "Mars/Olympus".into(),
+                ..request.clone()
+            },
+        ];
+        for request in invalid {
+            assert!(matches!(request.validate(), Err(Error::InvalidReport(_))));
+        }
+        let reversed = UtilizationRequest {
+            period: Some(TimeWindow::new(start, start)),
+            ..request
+        };
+        assert_eq!(reversed.validate(), Err(Error::InvalidTime));
+    }
+
+    #[test]
+    fn occupancy_request_should_validate() {
+        let start: DateTime<Utc> = "2030-12-25T08:00:00Z".parse().unwrap();
+        let request = OccupancyRequest {
+            resource_ids: vec![],
+            window: Some(TimeWindow::new(start, start + Duration::minutes(100))),
+            bucket_secs: 1800,
+        };
+        assert!(request.validate().is_ok());
+        assert_eq!(request.bucket_count(), 4);
+
+        for bucket_secs in [0, 1] {
+            let request = OccupancyRequest {
+                bucket_secs,
+                ..request.clone()
+            };
+            assert!(matches!(request.validate(), Err(Error::InvalidReport(_))));
+        }
+    }
+}
@@ -7,7 +7,7 @@ use sqlx::{
 };
 
 use crate::{
-    Error, Id, Reservation, ReservationStatus, RsvpStatus,
+    Error, Id, Money, Reservation, ReservationStatus, RsvpStatus,
     utils::{convert_to_timestamp, convert_to_utc_time},
 };
 
@@ -27,6 +27,13 @@ impl Reservation {
             start: Some(convert_to_timestamp(start.with_timezone(&Utc))),
             end: Some(convert_to_timestamp(end.with_timezone(&Utc))),
             note: note.into(),
+            tenant_id: String::new(),
+            priority: 0,
+            checked_in_at: None,
+            checked_out_at: None,
+            transfer_to: String::new(),
+            overbooked: false,
+            price: None,
         }
     }
 
@@ -53,6 +60,14 @@ impl Reservation {
         Ok(())
     }
 
+    /// start and end of a validated reservation
+    pub fn bounds(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            convert_to_utc_time(&self.start.unwrap()),
+            convert_to_utc_time(&self.end.unwrap()),
+        )
+    }
+
     pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
         let start = convert_to_utc_time(&self.start.unwrap());
         let end = convert_to_utc_time(&self.end.unwrap());
@@ -77,13 +92,29 @@ impl FromRow<'_, sqlx::postgres::PgRow> for Reservation {
             start: window.start.map(convert_to_timestamp),
             end: window.end.map(convert_to_timestamp),
             note: row.get("note"),
+            tenant_id: row.get("tenant_id"),
+            priority: row.get("priority"),
+            checked_in_at: row
+                .get::<Option<DateTime<Utc>>, _>("checked_in_at")
+                .map(convert_to_timestamp),
+            checked_out_at: row
+                .get::<Option<DateTime<Utc>>, _>("checked_out_at")
+                .map(convert_to_timestamp),
+            transfer_to: row
+                .get::<Option<String>, _>("transfer_to")
+                .unwrap_or_default(),
+            overbooked: row.get("overbooked"),
+            price: match (row.get::<Option<String>, _>("currency"), row.get("price")) {
+                (Some(currency), Some(price)) => Some(Money::new(currency, price)),
+                _ => None,
+            },
         })
     }
 }
 
-struct NaiveRange<T> {
-    start: Option<T>,
-    end: Option<T>,
+pub(crate) struct NaiveRange<T> {
+    pub(crate) start: Option<T>,
+    pub(crate) end: Option<T>,
 }
 
 impl<T> From<PgRange<T>> for NaiveRange<T> {
@@ -9,6 +9,10 @@ impl Display for ReservationStatus {
             ReservationStatus::Pending => write!(f, "pending"),
             ReservationStatus::Confirmed => write!(f, "confirmed"),
             ReservationStatus::Blocked => write!(f, "blocked"),
+            ReservationStatus::Preempted => write!(f, "preempted"),
+            ReservationStatus::NoShow => write!(f, "no_show"),
+            ReservationStatus::AwaitingApproval => write!(f, "awaiting_approval"),
+            ReservationStatus::Rejected => write!(f, "rejected"),
         }
     }
 }
@@ -20,6 +24,25 @@ impl From<RsvpStatus> for ReservationStatus {
             RsvpStatus::Pending => ReservationStatus::Pending,
             RsvpStatus::Confirmed => ReservationStatus::Confirmed,
             RsvpStatus::Blocked => ReservationStatus::Blocked,
+            RsvpStatus::Preempted => ReservationStatus::Preempted,
+            RsvpStatus::NoShow => ReservationStatus::NoShow,
+            RsvpStatus::AwaitingApproval => ReservationStatus::AwaitingApproval,
+            RsvpStatus::Rejected => ReservationStatus::Rejected,
+        }
+    }
+}
+
+impl From<ReservationStatus> for RsvpStatus {
+    fn from(status: ReservationStatus) -> Self {
+        match status {
+            ReservationStatus::Unknown => RsvpStatus::Unknown,
+            ReservationStatus::Pending => RsvpStatus::Pending,
+            ReservationStatus::Confirmed => RsvpStatus::Confirmed,
+            ReservationStatus::Blocked => RsvpStatus::Blocked,
+            ReservationStatus::Preempted => RsvpStatus::Preempted,
+            ReservationStatus::NoShow => RsvpStatus::NoShow,
+            ReservationStatus::AwaitingApproval => RsvpStatus::AwaitingApproval,
+            ReservationStatus::Rejected => RsvpStatus::Rejected,
         }
     }
 }
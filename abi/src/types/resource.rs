@@ -0,0 +1,88 @@
+use sqlx::{
+    FromRow, Row,
+    postgres::{PgRow, types::PgInterval},
+};
+
+use crate::{Error, Resource, convert_interval_to_secs};
+
+/// resource type of resources without an explicit type, and of unregistered resources
+pub const DEFAULT_RESOURCE_TYPE: &str = "default";
+
+impl Resource {
+    pub fn new(id: impl Into<String>, resource_type: impl Into<String>) -> Self {
+        Resource {
+            id: id.into(),
+            resource_type: resource_type.into(),
+            tenant_id: String::new(),
+            pre_buffer_secs: 0,
+            post_buffer_secs: 0,
+            time_zone: String::new(),
+            opening_hours: vec![],
+            closures: vec![],
+            slot_granularity_secs: 0,
+            slot_offset_secs: 0,
+            min_slots: 0,
+            max_slots: 0,
+            requires_approval: false,
+            approvers: vec![],
+            parent_id: String::new(),
+            cancellation_tiers: vec![],
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.id.is_empty() || self.id.len() > 64 {
+            return Err(Error::InvalidResourceId(self.id.clone()));
+        }
+        if self.parent_id.len() > 64 || self.parent_id == self.id {
+            return Err(Error::InvalidResourceId(self.parent_id.clone()));
+        }
+        if let Some(approver) = self
+            .approvers
+            .iter()
+            .find(|uid| uid.is_empty() || uid.len() > 64)
+        {
+            return Err(Error::InvalidUserId(approver.clone()));
+        }
+        self.validate_cancellation_tiers()?;
+        self.validate_calendar()
+    }
+
+    /// resource type used to look up the booking policy
+    pub fn resource_type(&self) -> &str {
+        if self.resource_type.is_empty() {
+            DEFAULT_RESOURCE_TYPE
+        } else {
+            &self.resource_type
+        }
+    }
+}
+
+impl FromRow<'_, PgRow> for Resource {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let pre_buffer: PgInterval = row.get("pre_buffer");
+        let post_buffer: PgInterval = row.get("post_buffer");
+        let slot_granularity: PgInterval = row.get("slot_granularity");
+        let slot_offset: PgInterval = row.get("slot_offset");
+        Ok(Resource {
+            id: row.get("id"),
+            resource_type: row.get("resource_type"),
+            tenant_id: row.get("tenant_id"),
+            pre_buffer_secs: convert_interval_to_secs(&pre_buffer),
+            post_buffer_secs: convert_interval_to_secs(&post_buffer),
+            time_zone: row.get("time_zone"),
+            opening_hours: vec![],
+            closures: vec![],
+            slot_granularity_secs: convert_interval_to_secs(&slot_granularity),
+            slot_offset_secs: convert_interval_to_secs(&slot_offset),
+            min_slots: row.get::<i32, _>("min_slots") as u32,
+            max_slots: row.get::<i32, _>("max_slots") as u32,
+            requires_approval: row.get("requires_approval"),
+            approvers: vec![],
+            parent_id: row
+                .get::<Option<String>, _>("parent_id")
+                .unwrap_or_default(),
+            cancellation_tiers: vec![],
+        })
+    }
+}
@@ -0,0 +1,146 @@
+use chrono::{DateTime, Duration, Timelike, Utc};
+
+use crate::{Error, Resource, SlotViolation, UtcWindow};
+
+impl Resource {
+    /// the window must start and end on slot boundaries and last between min and max slots
+    pub fn check_slots(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<(), Error> {
+        if self.slot_granularity_secs == 0 {
+            return Ok(());
+        }
+        let granularity = self.slot_granularity_secs as i64;
+        let slots = ((end - start).num_seconds() as f64 / granularity as f64).round() as i64;
+
+        let aligned = |t| Ok::<_, Error>(self.slot_remainder(t)?.is_zero());
+        let reason = if !aligned(start)? || !aligned(end)? {
+            format!(
+                "reservation must start and end on {}s slot boundaries (offset {}s)",
+                granularity, self.slot_offset_secs
+            )
+        } else if slots < self.min_slots as i64 {
+            format!("reservation must last at least {} slots", self.min_slots)
+        } else if self.max_slots > 0 && slots > self.max_slots as i64 {
+            format!("reservation must last at most {} slots", self.max_slots)
+        } else {
+            return Ok(());
+        };
+
+        Err(Error::InvalidSlot(SlotViolation {
+            reason,
+            nearest: self.nearest_slots(start, slots)?,
+        }))
+    }
+
+    /// time past the last slot boundary, slots are aligned in the resource's local time
+    fn slot_remainder(&self, t: DateTime<Utc>) -> Result<Duration, Error> {
+        let local = t.with_timezone(&self.tz()?);
+        let secs_of_day = local.num_seconds_from_midnight() as i64 - self.slot_offset_secs as i64;
+        let secs = secs_of_day.rem_euclid(self.slot_granularity_secs as i64);
+        Ok(Duration::seconds(secs) + Duration::nanoseconds(local.nanosecond() as i64))
+    }
+
+    /// the valid windows starting on the slot boundaries around `start`, with the slot count
+    /// clamped to the allowed range
+    fn nearest_slots(&self, start: DateTime<Utc>, slots: i64) -> Result<Vec<UtcWindow>, Error> {
+        let granularity = Duration::seconds(self.slot_granularity_secs as i64);
+        let mut slots = slots.max(self.min_slots as i64).max(1);
+        if self.max_slots > 0 {
+            slots = slots.min(self.max_slots as i64);
+        }
+
+        let remainder = self.slot_remainder(start)?;
+        let floor = start - remainder;
+        let mut starts = vec![floor];
+        if !remainder.is_zero() {
+            starts.push(floor + granularity);
+        }
+        Ok(starts
+            .into_iter()
+            .map(|s| (s, s + granularity * slots as i32))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn tennis_court() -> Resource {
+        Resource {
+            slot_granularity_secs: 1800,
+            min_slots: 1,
+            max_slots: 4,
+            ..Resource::new("tennis-court-1", "court")
+        }
+    }
+
+    #[test]
+    fn aligned_window_should_be_accepted() {
+        let court = tennis_court();
+        assert!(
+            court
+                .check_slots(t("2025-06-01T10:30:00Z"), t("2025-06-01T11:30:00Z"))
+                .is_ok()
+        );
+        assert!(
+            Resource::new("desk", "desk")
+                .check_slots(t("2025-06-01T10:07:00Z"), t("2025-06-01T11:13:00Z"))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn misaligned_window_should_suggest_nearest_windows() {
+        let err = tennis_court()
+            .check_slots(t("2025-06-01T10:10:00Z"), t("2025-06-01T11:05:00Z"))
+            .unwrap_err();
+        let Error::InvalidSlot(violation) = err else {
+            panic!("expect slot violation");
+        };
+        assert_eq!(
+            violation.nearest,
+            vec![
+                (t("2025-06-01T10:00:00Z"), t("2025-06-01T11:00:00Z")),
+                (t("2025-06-01T10:30:00Z"), t("2025-06-01T11:30:00Z")),
+            ]
+        );
+    }
+
+    #[test]
+    fn too_many_slots_should_be_rejected() {
+        let err = tennis_court()
+            .check_slots(t("2025-06-01T10:00:00Z"), t("2025-06-01T13:00:00Z"))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::InvalidSlot(SlotViolation {
+                reason: "reservation must last at most 4 slots".into(),
+                nearest: vec![(t("2025-06-01T10:00:00Z"), t("2025-06-01T12:00:00Z"))],
+            })
+        );
+    }
+
+    #[test]
+    fn slots_should_align_in_local_time() {
+        // check-in at 14:00 local time, one day per slot
+        let room = Resource {
+            time_zone: "Asia/Kathmandu".into(),
+            slot_granularity_secs: 86400,
+            slot_offset_secs: 14 * 3600,
+            ..Resource::new("ocean-view-room-713", "room")
+        };
+        // 14:00 in Kathmandu (+05:45) is 08:15 UTC
+        assert!(
+            room.check_slots(t("2025-06-01T08:15:00Z"), t("2025-06-03T08:15:00Z"))
+                .is_ok()
+        );
+        assert!(
+            room.check_slots(t("2025-06-01T08:00:00Z"), t("2025-06-03T08:00:00Z"))
+                .is_err()
+        );
+    }
+}
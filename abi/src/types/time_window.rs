@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+
+use crate::{Error, TimeWindow, convert_to_timestamp, convert_to_utc_time};
+
+/// start and end of a window in UTC, used for window arithmetic
+pub type UtcWindow = (DateTime<Utc>, DateTime<Utc>);
+
+impl TimeWindow {
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        TimeWindow {
+            start: Some(convert_to_timestamp(start)),
+            end: Some(convert_to_timestamp(end)),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        match (self.start.as_ref(), self.end.as_ref()) {
+            (Some(start), Some(end)) if convert_to_utc_time(start) < convert_to_utc_time(end) => {
+                Ok(())
+            }
+            _ => Err(Error::InvalidTime),
+        }
+    }
+
+    /// start and end of a validated window
+    pub fn bounds(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            convert_to_utc_time(&self.start.unwrap()),
+            convert_to_utc_time(&self.end.unwrap()),
+        )
+    }
+}
@@ -0,0 +1,97 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sqlx::{
+    FromRow, Row,
+    postgres::{PgRow, types::PgRange},
+};
+
+use crate::{
+    Error, WaitlistEntry,
+    types::reservation::NaiveRange,
+    utils::{convert_to_timestamp, convert_to_utc_time},
+};
+
+impl WaitlistEntry {
+    pub fn new(
+        uid: impl Into<String>,
+        rid: impl Into<String>,
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+        note: impl Into<String>,
+    ) -> Self {
+        WaitlistEntry {
+            id: 0,
+            user_id: uid.into(),
+            resource_id: rid.into(),
+            start: Some(convert_to_timestamp(start.with_timezone(&Utc))),
+            end: Some(convert_to_timestamp(end.with_timezone(&Utc))),
+            note: note.into(),
+            tenant_id: String::new(),
+            reservation_id: 0,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.user_id.is_empty() {
+            return Err(Error::InvalidUserId(self.user_id.clone()));
+        }
+
+        if self.resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(self.resource_id.clone()));
+        }
+
+        match (self.start.as_ref(), self.end.as_ref()) {
+            (Some(start), Some(end)) if convert_to_utc_time(start) < convert_to_utc_time(end) => {
+                Ok(())
+            }
+            _ => Err(Error::InvalidTime),
+        }
+    }
+
+    /// start and end of a validated entry
+    pub fn bounds(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            convert_to_utc_time(&self.start.unwrap()),
+            convert_to_utc_time(&self.end.unwrap()),
+        )
+    }
+
+    pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
+        let (start, end) = self.bounds();
+        (start..end).into()
+    }
+}
+
+impl FromRow<'_, PgRow> for WaitlistEntry {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let timespan: PgRange<DateTime<Utc>> = row.try_get("timespan")?;
+        let window = NaiveRange::from(timespan);
+
+        Ok(WaitlistEntry {
+            id: row.get("id"),
+            user_id: row.get("user_id"),
+            resource_id: row.get("resource_id"),
+            start: window.start.map(convert_to_timestamp),
+            end: window.end.map(convert_to_timestamp),
+            note: row.get("note"),
+            tenant_id: row.get("tenant_id"),
+            reservation_id: row
+                .get::<Option<i64>, _>("reservation_id")
+                .unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waitlist_entry_validate_should_reject_empty_window() {
+        let start = "2030-01-01T10:00:00+0000".parse().unwrap();
+        let entry = WaitlistEntry::new("silwingsId", "ocean-view-room-713", start, start, "");
+        assert!(matches!(entry.validate(), Err(Error::InvalidTime)));
+
+        let entry = WaitlistEntry::new("", "ocean-view-room-713", start, start, "");
+        assert!(matches!(entry.validate(), Err(Error::InvalidUserId(_))));
+    }
+}
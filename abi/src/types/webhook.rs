@@ -0,0 +1,125 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Row, postgres::PgRow};
+
+use crate::{
+    Error, ReservationUpdateType, WebhookDeadLetter, WebhookSubscription, convert_to_timestamp,
+};
+
+impl WebhookSubscription {
+    pub fn new(url: impl Into<String>, secret: impl Into<String>) -> Self {
+        WebhookSubscription {
+            id: 0,
+            tenant_id: String::new(),
+            url: url.into(),
+            secret: secret.into(),
+            ops: vec![],
+            active: true,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        let scheme = self.url.starts_with("http://") || self.url.starts_with("https://");
+        if !scheme || self.url.len() > 2048 {
+            return Err(Error::InvalidWebhook(format!("invalid url {}", self.url)));
+        }
+        if self.id == 0 && self.secret.is_empty() {
+            return Err(Error::InvalidWebhook("secret required".into()));
+        }
+        if self.ops.iter().any(|op| update_type_name(*op).is_none()) {
+            return Err(Error::InvalidWebhook(format!("invalid ops {:?}", self.ops)));
+        }
+        Ok(())
+    }
+
+    /// names of the subscribed change types in the database
+    pub fn op_names(&self) -> Vec<&'static str> {
+        self.ops
+            .iter()
+            .filter_map(|op| update_type_name(*op))
+            .collect()
+    }
+}
+
+/// name of the change type in rsvp.reservation_update_type
+fn update_type_name(op: i32) -> Option<&'static str> {
+    match ReservationUpdateType::try_from(op).ok()? {
+        ReservationUpdateType::Create => Some("create"),
+        ReservationUpdateType::Update => Some("update"),
+        ReservationUpdateType::Delete => Some("delete"),
+        ReservationUpdateType::Unknown => None,
+    }
+}
+
+fn update_type(name: &str) -> ReservationUpdateType {
+    match name {
+        "create" => ReservationUpdateType::Create,
+        "update" => ReservationUpdateType::Update,
+        "delete" => ReservationUpdateType::Delete,
+        _ => ReservationUpdateType::Unknown,
+    }
+}
+
+/// the secret is never read back, rows must select ops as text[]
+impl FromRow<'_, PgRow> for WebhookSubscription {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let ops: Vec<String> = row.get("ops");
+        Ok(WebhookSubscription {
+            id: row.get("id"),
+            tenant_id: row.get("tenant_id"),
+            url: row.get("url"),
+            secret: String::new(),
+            ops: ops.iter().map(|op| update_type(op) as i32).collect(),
+            active: row.get("active"),
+        })
+    }
+}
+
+/// rows must select the payload as text
+impl FromRow<'_, PgRow> for WebhookDeadLetter {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let failed_at: DateTime<Utc> = row.get("failed_at");
+        Ok(WebhookDeadLetter {
+            id: row.get("id"),
+            subscription_id: row.get("subscription_id"),
+            change_id: row.get("change_id"),
+            payload: row.get("payload"),
+            attempts: row.get::<i32, _>("attempts") as u32,
+            last_error: row
+                .get::<Option<String>, _>("last_error")
+                .unwrap_or_default(),
+            failed_at: Some(convert_to_timestamp(failed_at)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn webhook_subscription_should_validate() {
+        let mut subscription = WebhookSubscription::new("https://example.com/hooks", "s3cret");
+        subscription.ops = vec![ReservationUpdateType::Create as i32];
+        assert!(subscription.validate().is_ok());
+        assert_eq!(subscription.op_names(), ["create"]);
+
+        let invalid = [
+            WebhookSubscription::new("ftp://example.com", "s3cret"),
+            WebhookSubscription::new("https://example.com/hooks", ""),
+            WebhookSubscription {
+                ops: vec![ReservationUpdateType::Unknown as i32],
+                ..subscription.clone()
+            },
+        ];
+        for subscription in invalid {
+            assert!(matches!(
+                subscription.validate(),
+                Err(Error::InvalidWebhook(_))
+            ));
+        }
+        // the secret is kept on update
+        subscription.id = 1;
+        subscription.secret.clear();
+        assert!(subscription.validate().is_ok());
+    }
+}
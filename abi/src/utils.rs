@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use prost_types::Timestamp;
+use sqlx::postgres::types::PgInterval;
 
 pub fn convert_to_utc_time(ts: &Timestamp) -> DateTime<Utc> {
     DateTime::<Utc>::from_timestamp(ts.seconds, ts.nanos as _).unwrap()
@@ -11,3 +12,16 @@ pub fn convert_to_timestamp(dt: DateTime<Utc>) -> Timestamp {
         nanos: 999999999.min(dt.timestamp_subsec_nanos()) as _,
     }
 }
+
+pub fn convert_to_interval(secs: u64) -> PgInterval {
+    PgInterval {
+        months: 0,
+        days: 0,
+        microseconds: (secs as i64).saturating_mul(1_000_000),
+    }
+}
+
+pub fn convert_interval_to_secs(interval: &PgInterval) -> u64 {
+    let days = interval.months as i64 * 30 + interval.days as i64;
+    (days * 86400 + interval.microseconds / 1_000_000).max(0) as u64
+}
@@ -0,0 +1,34 @@
+CREATE OR REPLACE FUNCTION rsvp.reservations_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        -- update reservation_changes
+        INSERT INTO rsvp.reservation_changes (reservation_id, old, new, op) VALUES (NEW.id, null, to_jsonb(NEW), 'create');
+    ELSIF TG_OP = 'UPDATE' THEN
+        -- if status changed, update reservation_changes
+        IF OLD.status <> NEW.status THEN
+            INSERT INTO rsvp.reservation_changes (reservation_id, old, new, op) VALUES (NEW.id, to_jsonb(OLD), to_jsonb(NEW), 'update');
+        END IF;
+    ELSIF TG_OP = 'DELETE' THEN
+        -- update reservation_changes
+        INSERT INTO rsvp.reservation_changes (reservation_id, old, new, op) VALUES (OLD.id, to_jsonb(OLD), null, 'delete');
+    END IF;
+    -- notify a channel called reservation_update
+    NOTIFY reservation_update;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP POLICY reservation_changes_tenant_isolation ON rsvp.reservation_changes;
+ALTER TABLE rsvp.reservation_changes NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.reservation_changes DISABLE ROW LEVEL SECURITY;
+DROP POLICY reservations_tenant_isolation ON rsvp.reservations;
+ALTER TABLE rsvp.reservations NO FORCE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.reservations DISABLE ROW LEVEL SECURITY;
+
+DROP INDEX rsvp.reservation_changes_tenant_id_idx;
+ALTER TABLE rsvp.reservation_changes DROP COLUMN tenant_id;
+
+DROP INDEX rsvp.reservations_tenant_id_idx;
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (resource_id WITH =, timespan WITH &&);
+ALTER TABLE rsvp.reservations DROP COLUMN tenant_id;
@@ -0,0 +1,47 @@
+-- every reservation belongs to a tenant, the empty string is the default tenant
+ALTER TABLE rsvp.reservations ADD COLUMN tenant_id VARCHAR(64) NOT NULL DEFAULT '';
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (tenant_id WITH =, resource_id WITH =, timespan WITH &&);
+CREATE INDEX reservations_tenant_id_idx ON rsvp.reservations (tenant_id);
+
+ALTER TABLE rsvp.reservation_changes ADD COLUMN tenant_id VARCHAR(64) NOT NULL DEFAULT '';
+CREATE INDEX reservation_changes_tenant_id_idx ON rsvp.reservation_changes (tenant_id, id);
+
+-- the application sets rsvp.tenant_id for every transaction, rows of other tenants are invisible
+ALTER TABLE rsvp.reservations ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.reservations FORCE ROW LEVEL SECURITY;
+CREATE POLICY reservations_tenant_isolation ON rsvp.reservations
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+
+ALTER TABLE rsvp.reservation_changes ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.reservation_changes FORCE ROW LEVEL SECURITY;
+CREATE POLICY reservation_changes_tenant_isolation ON rsvp.reservation_changes
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+
+-- record the tenant in the change queue, and use it as the notification payload
+CREATE OR REPLACE FUNCTION rsvp.reservations_trigger() RETURNS TRIGGER AS $$
+DECLARE
+    tid VARCHAR(64);
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        tid := NEW.tenant_id;
+        -- update reservation_changes
+        INSERT INTO rsvp.reservation_changes (tenant_id, reservation_id, old, new, op) VALUES (NEW.tenant_id, NEW.id, null, to_jsonb(NEW), 'create');
+    ELSIF TG_OP = 'UPDATE' THEN
+        tid := NEW.tenant_id;
+        -- if status changed, update reservation_changes
+        IF OLD.status <> NEW.status THEN
+            INSERT INTO rsvp.reservation_changes (tenant_id, reservation_id, old, new, op) VALUES (NEW.tenant_id, NEW.id, to_jsonb(OLD), to_jsonb(NEW), 'update');
+        END IF;
+    ELSIF TG_OP = 'DELETE' THEN
+        tid := OLD.tenant_id;
+        -- update reservation_changes
+        INSERT INTO rsvp.reservation_changes (tenant_id, reservation_id, old, new, op) VALUES (OLD.tenant_id, OLD.id, to_jsonb(OLD), null, 'delete');
+    END IF;
+    -- notify a channel called reservation_update, the payload is the tenant id
+    PERFORM pg_notify('reservation_update', tid);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,15 @@
+-- registered resources, a reservation may refer to a resource which is not registered
+CREATE TABLE rsvp.resources (
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    id VARCHAR(64) NOT NULL,
+    resource_type VARCHAR(64) NOT NULL DEFAULT 'default',
+
+    CONSTRAINT resources_pkey PRIMARY KEY (tenant_id, id)
+);
+CREATE INDEX resources_resource_type_idx ON rsvp.resources (tenant_id, resource_type);
+
+ALTER TABLE rsvp.resources ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.resources FORCE ROW LEVEL SECURITY;
+CREATE POLICY resources_tenant_isolation ON rsvp.resources
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
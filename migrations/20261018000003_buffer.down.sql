@@ -0,0 +1,9 @@
+DROP TRIGGER reservations_blocked_trigger ON rsvp.reservations;
+DROP FUNCTION rsvp.reservations_blocked_trigger();
+
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (tenant_id WITH =, resource_id WITH =, timespan WITH &&);
+ALTER TABLE rsvp.reservations DROP COLUMN blocked;
+
+ALTER TABLE rsvp.resources DROP COLUMN post_buffer;
+ALTER TABLE rsvp.resources DROP COLUMN pre_buffer;
@@ -0,0 +1,31 @@
+-- turnaround buffers before and after every reservation of the resource
+ALTER TABLE rsvp.resources ADD COLUMN pre_buffer INTERVAL NOT NULL DEFAULT '0';
+ALTER TABLE rsvp.resources ADD COLUMN post_buffer INTERVAL NOT NULL DEFAULT '0';
+
+-- the window a reservation actually blocks: its timespan extended by the resource buffers
+ALTER TABLE rsvp.reservations ADD COLUMN blocked TSTZRANGE;
+UPDATE rsvp.reservations SET blocked = timespan;
+ALTER TABLE rsvp.reservations ALTER COLUMN blocked SET NOT NULL;
+
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (tenant_id WITH =, resource_id WITH =, blocked WITH &&);
+
+-- compute the blocked window whenever a reservation is added or its timespan changes
+CREATE OR REPLACE FUNCTION rsvp.reservations_blocked_trigger() RETURNS TRIGGER AS $$
+DECLARE
+    pre INTERVAL;
+    post INTERVAL;
+BEGIN
+    SELECT pre_buffer, post_buffer INTO pre, post FROM rsvp.resources
+        WHERE tenant_id = NEW.tenant_id AND id = NEW.resource_id;
+    NEW.blocked := tstzrange(
+        lower(NEW.timespan) - COALESCE(pre, '0'),
+        upper(NEW.timespan) + COALESCE(post, '0')
+    );
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER reservations_blocked_trigger
+    BEFORE INSERT OR UPDATE OF timespan, resource_id ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservations_blocked_trigger();
@@ -0,0 +1,3 @@
+DROP TABLE rsvp.resource_closures CASCADE;
+DROP TABLE rsvp.resource_opening_hours CASCADE;
+ALTER TABLE rsvp.resources DROP COLUMN time_zone;
@@ -0,0 +1,44 @@
+-- opening hours and holidays are interpreted in the resource's time zone
+ALTER TABLE rsvp.resources ADD COLUMN time_zone VARCHAR(64) NOT NULL DEFAULT 'UTC';
+
+-- weekly opening hours, a resource without any is open all the time
+CREATE TABLE rsvp.resource_opening_hours (
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    resource_id VARCHAR(64) NOT NULL,
+    -- ISO weekday, 1 is Monday and 7 is Sunday
+    weekday SMALLINT NOT NULL,
+    -- local time in minutes since midnight
+    opens SMALLINT NOT NULL,
+    closes SMALLINT NOT NULL,
+
+    CONSTRAINT resource_opening_hours_pkey PRIMARY KEY (tenant_id, resource_id, weekday, opens),
+    CONSTRAINT resource_opening_hours_resource_fkey FOREIGN KEY (tenant_id, resource_id)
+        REFERENCES rsvp.resources (tenant_id, id) ON DELETE CASCADE,
+    CONSTRAINT resource_opening_hours_valid CHECK (
+        weekday BETWEEN 1 AND 7 AND opens >= 0 AND opens < closes AND closes <= 1440
+    )
+);
+
+-- local dates on which the resource is closed all day, e.g. holidays
+CREATE TABLE rsvp.resource_closures (
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    resource_id VARCHAR(64) NOT NULL,
+    day DATE NOT NULL,
+    note TEXT NOT NULL DEFAULT '',
+
+    CONSTRAINT resource_closures_pkey PRIMARY KEY (tenant_id, resource_id, day),
+    CONSTRAINT resource_closures_resource_fkey FOREIGN KEY (tenant_id, resource_id)
+        REFERENCES rsvp.resources (tenant_id, id) ON DELETE CASCADE
+);
+
+ALTER TABLE rsvp.resource_opening_hours ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.resource_opening_hours FORCE ROW LEVEL SECURITY;
+CREATE POLICY resource_opening_hours_tenant_isolation ON rsvp.resource_opening_hours
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+
+ALTER TABLE rsvp.resource_closures ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.resource_closures FORCE ROW LEVEL SECURITY;
+CREATE POLICY resource_closures_tenant_isolation ON rsvp.resource_closures
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
@@ -0,0 +1,32 @@
+-- slot rules: reservations start on a granularity boundary (shifted by the offset, in the
+-- resource's local time) and last a whole number of slots
+ALTER TABLE rsvp.resources ADD COLUMN slot_granularity INTERVAL NOT NULL DEFAULT '0';
+ALTER TABLE rsvp.resources ADD COLUMN slot_offset INTERVAL NOT NULL DEFAULT '0';
+ALTER TABLE rsvp.resources ADD COLUMN min_slots INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE rsvp.resources ADD COLUMN max_slots INTEGER NOT NULL DEFAULT 0;
+
+-- a rescheduled reservation is an update as well
+CREATE OR REPLACE FUNCTION rsvp.reservations_trigger() RETURNS TRIGGER AS $$
+DECLARE
+    tid VARCHAR(64);
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        tid := NEW.tenant_id;
+        -- update reservation_changes
+        INSERT INTO rsvp.reservation_changes (tenant_id, reservation_id, old, new, op) VALUES (NEW.tenant_id, NEW.id, null, to_jsonb(NEW), 'create');
+    ELSIF TG_OP = 'UPDATE' THEN
+        tid := NEW.tenant_id;
+        -- if status or timespan changed, update reservation_changes
+        IF OLD.status <> NEW.status OR OLD.timespan <> NEW.timespan THEN
+            INSERT INTO rsvp.reservation_changes (tenant_id, reservation_id, old, new, op) VALUES (NEW.tenant_id, NEW.id, to_jsonb(OLD), to_jsonb(NEW), 'update');
+        END IF;
+    ELSIF TG_OP = 'DELETE' THEN
+        tid := OLD.tenant_id;
+        -- update reservation_changes
+        INSERT INTO rsvp.reservation_changes (tenant_id, reservation_id, old, new, op) VALUES (OLD.tenant_id, OLD.id, to_jsonb(OLD), null, 'delete');
+    END IF;
+    -- notify a channel called reservation_update, the payload is the tenant id
+    PERFORM pg_notify('reservation_update', tid);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,4 @@
+DROP TRIGGER reservations_waitlist_trigger ON rsvp.reservations;
+DROP FUNCTION rsvp.reservations_waitlist_trigger();
+DROP FUNCTION rsvp.promote_waitlist(VARCHAR(64), VARCHAR(64), TSTZRANGE);
+DROP TABLE rsvp.waitlist CASCADE;
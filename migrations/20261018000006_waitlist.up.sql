@@ -0,0 +1,67 @@
+-- users waiting for a window of a resource, served in id order
+CREATE TABLE rsvp.waitlist (
+    id BIGSERIAL NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    user_id VARCHAR(64) NOT NULL,
+    resource_id VARCHAR(64) NOT NULL,
+    timespan TSTZRANGE NOT NULL,
+    note TEXT NOT NULL DEFAULT '',
+    -- set once the entry is converted into a reservation
+    reservation_id BIGINT,
+
+    CONSTRAINT waitlist_pkey PRIMARY KEY (id)
+);
+CREATE INDEX waitlist_resource_id_idx ON rsvp.waitlist (tenant_id, resource_id, id) WHERE reservation_id IS NULL;
+CREATE INDEX waitlist_user_id_idx ON rsvp.waitlist (tenant_id, user_id);
+
+ALTER TABLE rsvp.waitlist ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.waitlist FORCE ROW LEVEL SECURITY;
+CREATE POLICY waitlist_tenant_isolation ON rsvp.waitlist
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+
+-- convert waiting entries of the resource whose window overlaps the freed window into pending
+-- reservations, earliest entry first. Entries whose window has started are no longer served,
+-- entries which still conflict keep waiting
+CREATE OR REPLACE FUNCTION rsvp.promote_waitlist(tid VARCHAR(64), rid VARCHAR(64), freed TSTZRANGE) RETURNS VOID AS $$
+DECLARE
+    pre INTERVAL;
+    post INTERVAL;
+    entry rsvp.waitlist;
+    promoted BIGINT;
+BEGIN
+    SELECT pre_buffer, post_buffer INTO pre, post FROM rsvp.resources
+        WHERE tenant_id = tid AND id = rid;
+    pre := COALESCE(pre, '0');
+    post := COALESCE(post, '0');
+
+    FOR entry IN SELECT * FROM rsvp.waitlist
+        WHERE tenant_id = tid AND resource_id = rid AND reservation_id IS NULL
+            AND lower(timespan) > now()
+            AND tstzrange(lower(timespan) - pre, upper(timespan) + post) && freed
+        ORDER BY id FOR UPDATE SKIP LOCKED
+    LOOP
+        BEGIN
+            INSERT INTO rsvp.reservations (tenant_id, user_id, resource_id, timespan, note)
+                VALUES (entry.tenant_id, entry.user_id, entry.resource_id, entry.timespan, entry.note)
+                RETURNING id INTO promoted;
+            UPDATE rsvp.waitlist SET reservation_id = promoted WHERE id = entry.id;
+        EXCEPTION WHEN exclusion_violation THEN
+            -- the window is still taken, keep waiting
+            NULL;
+        END;
+    END LOOP;
+END;
+$$ LANGUAGE plpgsql;
+
+-- a cancelled or moved reservation frees its blocked window
+CREATE OR REPLACE FUNCTION rsvp.reservations_waitlist_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM rsvp.promote_waitlist(OLD.tenant_id, OLD.resource_id, OLD.blocked);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER reservations_waitlist_trigger
+    AFTER DELETE OR UPDATE OF timespan, resource_id ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservations_waitlist_trigger();
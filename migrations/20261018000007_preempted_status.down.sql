@@ -0,0 +1,2 @@
+-- enum values can not be dropped, 'preempted' is left in place and unused
+SELECT 1;
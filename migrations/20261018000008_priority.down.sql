@@ -0,0 +1,4 @@
+DELETE FROM rsvp.reservations WHERE status = 'preempted';
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (tenant_id WITH =, resource_id WITH =, blocked WITH &&);
+ALTER TABLE rsvp.reservations DROP COLUMN priority;
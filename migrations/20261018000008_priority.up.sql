@@ -0,0 +1,5 @@
+-- preempted reservations no longer hold their window
+ALTER TABLE rsvp.reservations ADD COLUMN priority INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (tenant_id WITH =, resource_id WITH =, blocked WITH &&) WHERE (status <> 'preempted');
@@ -0,0 +1,2 @@
+-- enum values can not be dropped, 'no_show' is left in place and unused
+SELECT 1;
@@ -0,0 +1,2 @@
+-- a new enum value can only be used once committed, so it is added on its own
+ALTER TYPE rsvp.reservation_status ADD VALUE IF NOT EXISTS 'no_show';
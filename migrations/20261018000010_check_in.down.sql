@@ -0,0 +1,23 @@
+ALTER POLICY reservations_tenant_isolation ON rsvp.reservations
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+ALTER POLICY reservation_changes_tenant_isolation ON rsvp.reservation_changes
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+ALTER POLICY resources_tenant_isolation ON rsvp.resources
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+ALTER POLICY resource_opening_hours_tenant_isolation ON rsvp.resource_opening_hours
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+ALTER POLICY resource_closures_tenant_isolation ON rsvp.resource_closures
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+ALTER POLICY waitlist_tenant_isolation ON rsvp.waitlist
+    USING (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''))
+    WITH CHECK (tenant_id = COALESCE(current_setting('rsvp.tenant_id', true), ''));
+DROP FUNCTION rsvp.tenant_visible(VARCHAR(64));
+
+DROP INDEX rsvp.reservations_no_show_idx;
+ALTER TABLE rsvp.reservations DROP COLUMN checked_out_at;
+ALTER TABLE rsvp.reservations DROP COLUMN checked_in_at;
@@ -0,0 +1,24 @@
+ALTER TABLE rsvp.reservations ADD COLUMN checked_in_at TIMESTAMPTZ;
+ALTER TABLE rsvp.reservations ADD COLUMN checked_out_at TIMESTAMPTZ;
+CREATE INDEX reservations_no_show_idx ON rsvp.reservations (lower(timespan))
+    WHERE status = 'confirmed' AND checked_in_at IS NULL;
+
+-- rows are visible to their tenant, and to background jobs which work across tenants by
+-- setting rsvp.system for their transaction
+CREATE OR REPLACE FUNCTION rsvp.tenant_visible(tid VARCHAR(64)) RETURNS BOOLEAN AS $$
+    SELECT tid = COALESCE(current_setting('rsvp.tenant_id', true), '')
+        OR COALESCE(current_setting('rsvp.system', true), '') = 'on'
+$$ LANGUAGE sql STABLE;
+
+ALTER POLICY reservations_tenant_isolation ON rsvp.reservations
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+ALTER POLICY reservation_changes_tenant_isolation ON rsvp.reservation_changes
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+ALTER POLICY resources_tenant_isolation ON rsvp.resources
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+ALTER POLICY resource_opening_hours_tenant_isolation ON rsvp.resource_opening_hours
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+ALTER POLICY resource_closures_tenant_isolation ON rsvp.resource_closures
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+ALTER POLICY waitlist_tenant_isolation ON rsvp.waitlist
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
@@ -0,0 +1,4 @@
+DROP TRIGGER reservations_waitlist_trigger ON rsvp.reservations;
+CREATE TRIGGER reservations_waitlist_trigger
+    AFTER DELETE OR UPDATE OF timespan, resource_id ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservations_waitlist_trigger();
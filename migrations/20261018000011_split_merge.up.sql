@@ -0,0 +1,7 @@
+-- serve the waitlist when the transaction commits, so operations which free and take windows in
+-- several steps (e.g. merging reservations) are not raced by the waitlist
+DROP TRIGGER reservations_waitlist_trigger ON rsvp.reservations;
+CREATE CONSTRAINT TRIGGER reservations_waitlist_trigger
+    AFTER DELETE OR UPDATE OF timespan, resource_id ON rsvp.reservations
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservations_waitlist_trigger();
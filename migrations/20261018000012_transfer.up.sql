@@ -0,0 +1,29 @@
+-- recipient of a transfer waiting for acceptance
+ALTER TABLE rsvp.reservations ADD COLUMN transfer_to VARCHAR(64);
+
+-- ownership changes and transfer requests are updates as well
+CREATE OR REPLACE FUNCTION rsvp.reservations_trigger() RETURNS TRIGGER AS $$
+DECLARE
+    tid VARCHAR(64);
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        tid := NEW.tenant_id;
+        -- update reservation_changes
+        INSERT INTO rsvp.reservation_changes (tenant_id, reservation_id, old, new, op) VALUES (NEW.tenant_id, NEW.id, null, to_jsonb(NEW), 'create');
+    ELSIF TG_OP = 'UPDATE' THEN
+        tid := NEW.tenant_id;
+        -- if status, timespan or owner changed, update reservation_changes
+        IF OLD.status <> NEW.status OR OLD.timespan <> NEW.timespan OR OLD.user_id <> NEW.user_id
+            OR OLD.transfer_to IS DISTINCT FROM NEW.transfer_to THEN
+            INSERT INTO rsvp.reservation_changes (tenant_id, reservation_id, old, new, op) VALUES (NEW.tenant_id, NEW.id, to_jsonb(OLD), to_jsonb(NEW), 'update');
+        END IF;
+    ELSIF TG_OP = 'DELETE' THEN
+        tid := OLD.tenant_id;
+        -- update reservation_changes
+        INSERT INTO rsvp.reservation_changes (tenant_id, reservation_id, old, new, op) VALUES (OLD.tenant_id, OLD.id, to_jsonb(OLD), null, 'delete');
+    END IF;
+    -- notify a channel called reservation_update, the payload is the tenant id
+    PERFORM pg_notify('reservation_update', tid);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,3 @@
+DROP TABLE rsvp.attendees CASCADE;
+DROP TYPE rsvp.attendee_response;
+DROP TYPE rsvp.attendee_role;
@@ -0,0 +1,24 @@
+CREATE TYPE rsvp.attendee_role AS ENUM ('unknown', 'required', 'optional', 'guest');
+CREATE TYPE rsvp.attendee_response AS ENUM ('unknown', 'needs_action', 'accepted', 'declined', 'tentative');
+
+-- participants of a reservation besides its owner, either a user or an external email
+CREATE TABLE rsvp.attendees (
+    id BIGSERIAL NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    reservation_id BIGINT NOT NULL REFERENCES rsvp.reservations (id) ON DELETE CASCADE,
+    user_id VARCHAR(64),
+    email VARCHAR(320),
+    role rsvp.attendee_role NOT NULL DEFAULT 'required',
+    response rsvp.attendee_response NOT NULL DEFAULT 'needs_action',
+
+    CONSTRAINT attendees_pkey PRIMARY KEY (id),
+    CONSTRAINT attendees_user_or_email CHECK ((user_id IS NULL) <> (email IS NULL))
+);
+CREATE UNIQUE INDEX attendees_user_id_idx ON rsvp.attendees (reservation_id, user_id) WHERE user_id IS NOT NULL;
+CREATE UNIQUE INDEX attendees_email_idx ON rsvp.attendees (reservation_id, email) WHERE email IS NOT NULL;
+CREATE INDEX attendees_tenant_user_id_idx ON rsvp.attendees (tenant_id, user_id);
+
+ALTER TABLE rsvp.attendees ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.attendees FORCE ROW LEVEL SECURITY;
+CREATE POLICY attendees_tenant_isolation ON rsvp.attendees
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
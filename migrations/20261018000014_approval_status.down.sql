@@ -0,0 +1,2 @@
+-- enum values can not be dropped, 'awaiting_approval' and 'rejected' are left in place and unused
+SELECT 1;
@@ -0,0 +1,3 @@
+-- new enum values can only be used once committed, so they are added on their own
+ALTER TYPE rsvp.reservation_status ADD VALUE IF NOT EXISTS 'awaiting_approval';
+ALTER TYPE rsvp.reservation_status ADD VALUE IF NOT EXISTS 'rejected';
@@ -0,0 +1,45 @@
+-- promote every waitlist entry to a pending reservation
+CREATE OR REPLACE FUNCTION rsvp.promote_waitlist(tid VARCHAR(64), rid VARCHAR(64), freed TSTZRANGE) RETURNS VOID AS $$
+DECLARE
+    pre INTERVAL;
+    post INTERVAL;
+    entry rsvp.waitlist;
+    promoted BIGINT;
+BEGIN
+    SELECT pre_buffer, post_buffer INTO pre, post FROM rsvp.resources
+        WHERE tenant_id = tid AND id = rid;
+    pre := COALESCE(pre, '0');
+    post := COALESCE(post, '0');
+
+    FOR entry IN SELECT * FROM rsvp.waitlist
+        WHERE tenant_id = tid AND resource_id = rid AND reservation_id IS NULL
+            AND lower(timespan) > now()
+            AND tstzrange(lower(timespan) - pre, upper(timespan) + post) && freed
+        ORDER BY id FOR UPDATE SKIP LOCKED
+    LOOP
+        BEGIN
+            INSERT INTO rsvp.reservations (tenant_id, user_id, resource_id, timespan, note)
+                VALUES (entry.tenant_id, entry.user_id, entry.resource_id, entry.timespan, entry.note)
+                RETURNING id INTO promoted;
+            UPDATE rsvp.waitlist SET reservation_id = promoted WHERE id = entry.id;
+        EXCEPTION WHEN exclusion_violation THEN
+            -- the window is still taken, keep waiting
+            NULL;
+        END;
+    END LOOP;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER reservations_waitlist_trigger ON rsvp.reservations;
+CREATE CONSTRAINT TRIGGER reservations_waitlist_trigger
+    AFTER DELETE OR UPDATE OF timespan, resource_id ON rsvp.reservations
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservations_waitlist_trigger();
+
+-- rejected reservations keep their history, like preempted ones they no longer hold the window
+UPDATE rsvp.reservations SET status = 'preempted' WHERE status = 'rejected';
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (tenant_id WITH =, resource_id WITH =, blocked WITH &&) WHERE (status <> 'preempted');
+
+DROP TABLE rsvp.resource_approvers CASCADE;
+ALTER TABLE rsvp.resources DROP COLUMN requires_approval;
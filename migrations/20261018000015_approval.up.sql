@@ -0,0 +1,63 @@
+-- reservations of such resources wait for one of the approvers
+ALTER TABLE rsvp.resources ADD COLUMN requires_approval BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TABLE rsvp.resource_approvers (
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    resource_id VARCHAR(64) NOT NULL,
+    user_id VARCHAR(64) NOT NULL,
+
+    CONSTRAINT resource_approvers_pkey PRIMARY KEY (tenant_id, resource_id, user_id),
+    CONSTRAINT resource_approvers_resource_fkey FOREIGN KEY (tenant_id, resource_id)
+        REFERENCES rsvp.resources (tenant_id, id) ON DELETE CASCADE
+);
+CREATE INDEX resource_approvers_user_id_idx ON rsvp.resource_approvers (tenant_id, user_id);
+
+ALTER TABLE rsvp.resource_approvers ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.resource_approvers FORCE ROW LEVEL SECURITY;
+CREATE POLICY resource_approvers_tenant_isolation ON rsvp.resource_approvers
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+
+-- rejected reservations release their window, awaiting ones keep holding it
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (tenant_id WITH =, resource_id WITH =, blocked WITH &&) WHERE (status NOT IN ('preempted', 'rejected'));
+
+-- a status change may release a window as well
+DROP TRIGGER reservations_waitlist_trigger ON rsvp.reservations;
+CREATE CONSTRAINT TRIGGER reservations_waitlist_trigger
+    AFTER DELETE OR UPDATE OF timespan, resource_id, status ON rsvp.reservations
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservations_waitlist_trigger();
+
+-- waitlist entries of such resources wait for approval once promoted
+CREATE OR REPLACE FUNCTION rsvp.promote_waitlist(tid VARCHAR(64), rid VARCHAR(64), freed TSTZRANGE) RETURNS VOID AS $$
+DECLARE
+    pre INTERVAL;
+    post INTERVAL;
+    approval BOOLEAN;
+    entry rsvp.waitlist;
+    promoted BIGINT;
+BEGIN
+    SELECT pre_buffer, post_buffer, requires_approval INTO pre, post, approval FROM rsvp.resources
+        WHERE tenant_id = tid AND id = rid;
+    pre := COALESCE(pre, '0');
+    post := COALESCE(post, '0');
+
+    FOR entry IN SELECT * FROM rsvp.waitlist
+        WHERE tenant_id = tid AND resource_id = rid AND reservation_id IS NULL
+            AND lower(timespan) > now()
+            AND tstzrange(lower(timespan) - pre, upper(timespan) + post) && freed
+        ORDER BY id FOR UPDATE SKIP LOCKED
+    LOOP
+        BEGIN
+            INSERT INTO rsvp.reservations (tenant_id, user_id, resource_id, timespan, note, status)
+                VALUES (entry.tenant_id, entry.user_id, entry.resource_id, entry.timespan, entry.note,
+                    CASE WHEN COALESCE(approval, false) THEN 'awaiting_approval' ELSE 'pending' END::rsvp.reservation_status)
+                RETURNING id INTO promoted;
+            UPDATE rsvp.waitlist SET reservation_id = promoted WHERE id = entry.id;
+        EXCEPTION WHEN exclusion_violation THEN
+            -- the window is still taken, keep waiting
+            NULL;
+        END;
+    END LOOP;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,7 @@
+DROP INDEX rsvp.reservations_overbooked_idx;
+
+DELETE FROM rsvp.reservations WHERE overbooked;
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (tenant_id WITH =, resource_id WITH =, blocked WITH &&) WHERE (status NOT IN ('preempted', 'rejected'));
+
+ALTER TABLE rsvp.reservations DROP COLUMN overbooked;
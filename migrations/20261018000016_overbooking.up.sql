@@ -0,0 +1,7 @@
+-- overbooked reservations are tentative, they don't hold their window until confirmed
+ALTER TABLE rsvp.reservations ADD COLUMN overbooked BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_conflict EXCLUDE USING gist (tenant_id WITH =, resource_id WITH =, blocked WITH &&) WHERE (status NOT IN ('preempted', 'rejected') AND NOT overbooked);
+
+CREATE INDEX reservations_overbooked_idx ON rsvp.reservations (tenant_id, resource_id) WHERE overbooked;
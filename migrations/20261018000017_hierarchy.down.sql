@@ -0,0 +1,9 @@
+DROP TRIGGER reservations_hierarchy_trigger ON rsvp.reservations;
+DROP FUNCTION rsvp.reservations_hierarchy_trigger();
+DROP FUNCTION rsvp.resource_root(VARCHAR(64), VARCHAR(64));
+DROP FUNCTION rsvp.resource_family(VARCHAR(64), VARCHAR(64));
+
+DROP INDEX rsvp.resources_parent_id_idx;
+ALTER TABLE rsvp.resources DROP CONSTRAINT resources_parent_check;
+ALTER TABLE rsvp.resources DROP CONSTRAINT resources_parent_fkey;
+ALTER TABLE rsvp.resources DROP COLUMN parent_id;
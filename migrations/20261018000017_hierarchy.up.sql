@@ -0,0 +1,73 @@
+-- resources form trees, e.g. building > floor > room > seat
+ALTER TABLE rsvp.resources ADD COLUMN parent_id VARCHAR(64);
+ALTER TABLE rsvp.resources ADD CONSTRAINT resources_parent_fkey FOREIGN KEY (tenant_id, parent_id)
+    REFERENCES rsvp.resources (tenant_id, id);
+ALTER TABLE rsvp.resources ADD CONSTRAINT resources_parent_check CHECK (parent_id <> id);
+CREATE INDEX resources_parent_id_idx ON rsvp.resources (tenant_id, parent_id);
+
+-- ancestors and descendants of the resource, the resource itself excluded
+CREATE OR REPLACE FUNCTION rsvp.resource_family(tid VARCHAR(64), rid VARCHAR(64)) RETURNS SETOF VARCHAR(64) AS $$
+    WITH RECURSIVE ancestors(id, parent_id) AS (
+        SELECT id, parent_id FROM rsvp.resources WHERE tenant_id = tid AND id = rid
+        UNION
+        SELECT r.id, r.parent_id FROM rsvp.resources r JOIN ancestors a ON r.id = a.parent_id
+            WHERE r.tenant_id = tid
+    ), descendants(id) AS (
+        SELECT rid::VARCHAR(64)
+        UNION
+        SELECT r.id FROM rsvp.resources r JOIN descendants d ON r.parent_id = d.id
+            WHERE r.tenant_id = tid
+    )
+    SELECT id FROM ancestors WHERE id <> rid
+    UNION
+    SELECT id FROM descendants WHERE id <> rid;
+$$ LANGUAGE sql STABLE;
+
+-- the topmost ancestor of the resource, or the resource itself
+CREATE OR REPLACE FUNCTION rsvp.resource_root(tid VARCHAR(64), rid VARCHAR(64)) RETURNS VARCHAR(64) AS $$
+    WITH RECURSIVE ancestors(id, parent_id, depth) AS (
+        SELECT id, parent_id, 0 FROM rsvp.resources WHERE tenant_id = tid AND id = rid
+        UNION
+        SELECT r.id, r.parent_id, a.depth + 1 FROM rsvp.resources r JOIN ancestors a ON r.id = a.parent_id
+            WHERE r.tenant_id = tid
+    )
+    SELECT COALESCE((SELECT id FROM ancestors ORDER BY depth DESC LIMIT 1), rid);
+$$ LANGUAGE sql STABLE;
+
+-- the exclusion constraint only compares reservations of the same resource, this trigger
+-- compares a reservation with those of the resource's ancestors and descendants. The error
+-- looks like the one of the constraint, so the conflict is reported the same way
+CREATE OR REPLACE FUNCTION rsvp.reservations_hierarchy_trigger() RETURNS TRIGGER AS $$
+DECLARE
+    other rsvp.reservations;
+BEGIN
+    IF NEW.status IN ('preempted', 'rejected') OR NEW.overbooked THEN
+        RETURN NULL;
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM rsvp.resource_family(NEW.tenant_id, NEW.resource_id)) THEN
+        RETURN NULL;
+    END IF;
+
+    -- serialize reservations of the same tree, so concurrent ones see each other
+    PERFORM pg_advisory_xact_lock(hashtext(NEW.tenant_id || '/tree/' || rsvp.resource_root(NEW.tenant_id, NEW.resource_id)));
+
+    SELECT * INTO other FROM rsvp.reservations
+        WHERE tenant_id = NEW.tenant_id AND id <> NEW.id
+            AND resource_id IN (SELECT rsvp.resource_family(NEW.tenant_id, NEW.resource_id))
+            AND status NOT IN ('preempted', 'rejected') AND NOT overbooked
+            AND blocked && NEW.blocked
+        ORDER BY lower(blocked), id LIMIT 1;
+    IF FOUND THEN
+        RAISE EXCEPTION 'conflicting key value violates exclusion constraint "reservations_conflict"'
+            USING ERRCODE = 'exclusion_violation', SCHEMA = 'rsvp', TABLE = 'reservations',
+                CONSTRAINT = 'reservations_conflict',
+                DETAIL = format('Key (tenant_id, resource_id, blocked)=(%s, %s, %s) conflicts with existing key (tenant_id, resource_id, blocked)=(%s, %s, %s).',
+                    NEW.tenant_id, NEW.resource_id, NEW.blocked, other.tenant_id, other.resource_id, other.blocked);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER reservations_hierarchy_trigger
+    AFTER INSERT OR UPDATE OF timespan, resource_id, status, overbooked ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservations_hierarchy_trigger();
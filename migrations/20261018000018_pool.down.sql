@@ -0,0 +1,3 @@
+DROP TABLE rsvp.pool_members CASCADE;
+DROP TABLE rsvp.pools CASCADE;
+DROP TYPE rsvp.pool_strategy;
@@ -0,0 +1,32 @@
+CREATE TYPE rsvp.pool_strategy AS ENUM ('unknown', 'first_fit', 'least_recently_used', 'best_fit');
+
+-- interchangeable resources, e.g. parking spots, a reservation gets any free member
+CREATE TABLE rsvp.pools (
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    id VARCHAR(64) NOT NULL,
+    strategy rsvp.pool_strategy NOT NULL DEFAULT 'first_fit',
+
+    CONSTRAINT pools_pkey PRIMARY KEY (tenant_id, id)
+);
+
+-- members may be unregistered resources, like reservations may refer to them
+CREATE TABLE rsvp.pool_members (
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    pool_id VARCHAR(64) NOT NULL,
+    resource_id VARCHAR(64) NOT NULL,
+    position INT NOT NULL,
+
+    CONSTRAINT pool_members_pkey PRIMARY KEY (tenant_id, pool_id, resource_id),
+    CONSTRAINT pool_members_pool_fkey FOREIGN KEY (tenant_id, pool_id)
+        REFERENCES rsvp.pools (tenant_id, id) ON DELETE CASCADE
+);
+
+ALTER TABLE rsvp.pools ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.pools FORCE ROW LEVEL SECURITY;
+CREATE POLICY pools_tenant_isolation ON rsvp.pools
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+
+ALTER TABLE rsvp.pool_members ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.pool_members FORCE ROW LEVEL SECURITY;
+CREATE POLICY pool_members_tenant_isolation ON rsvp.pool_members
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
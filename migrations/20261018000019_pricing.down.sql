@@ -0,0 +1,6 @@
+ALTER TABLE rsvp.reservations DROP COLUMN currency;
+ALTER TABLE rsvp.reservations DROP COLUMN price;
+
+DROP TABLE rsvp.tenant_settings CASCADE;
+DROP TABLE rsvp.rates CASCADE;
+DROP TYPE rsvp.rate_unit;
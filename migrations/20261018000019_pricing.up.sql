@@ -0,0 +1,40 @@
+CREATE TYPE rsvp.rate_unit AS ENUM ('unknown', 'hour', 'day');
+
+-- the rate plan of a resource, rates later in the plan take precedence
+CREATE TABLE rsvp.rates (
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    resource_id VARCHAR(64) NOT NULL,
+    position INT NOT NULL,
+    name VARCHAR(64) NOT NULL DEFAULT '',
+    unit rsvp.rate_unit NOT NULL,
+    amount NUMERIC(19, 4) NOT NULL,
+    weekdays SMALLINT[] NOT NULL DEFAULT '{}',
+    valid_from DATE,
+    valid_until DATE,
+
+    CONSTRAINT rates_pkey PRIMARY KEY (tenant_id, resource_id, position),
+    CONSTRAINT rates_resource_fkey FOREIGN KEY (tenant_id, resource_id)
+        REFERENCES rsvp.resources (tenant_id, id) ON DELETE CASCADE,
+    CONSTRAINT rates_amount_check CHECK (amount >= 0)
+);
+
+CREATE TABLE rsvp.tenant_settings (
+    tenant_id VARCHAR(64) NOT NULL,
+    currency CHAR(3) NOT NULL,
+
+    CONSTRAINT tenant_settings_pkey PRIMARY KEY (tenant_id)
+);
+
+-- the price computed when the reservation was made
+ALTER TABLE rsvp.reservations ADD COLUMN price NUMERIC(19, 4);
+ALTER TABLE rsvp.reservations ADD COLUMN currency CHAR(3);
+
+ALTER TABLE rsvp.rates ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.rates FORCE ROW LEVEL SECURITY;
+CREATE POLICY rates_tenant_isolation ON rsvp.rates
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+
+ALTER TABLE rsvp.tenant_settings ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.tenant_settings FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_settings_tenant_isolation ON rsvp.tenant_settings
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
@@ -0,0 +1,17 @@
+-- cancelling at least min_notice before the start costs fee_percent of the price
+CREATE TABLE rsvp.resource_cancellation_tiers (
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    resource_id VARCHAR(64) NOT NULL,
+    min_notice INTERVAL NOT NULL,
+    fee_percent SMALLINT NOT NULL,
+
+    CONSTRAINT resource_cancellation_tiers_pkey PRIMARY KEY (tenant_id, resource_id, min_notice),
+    CONSTRAINT resource_cancellation_tiers_resource_fkey FOREIGN KEY (tenant_id, resource_id)
+        REFERENCES rsvp.resources (tenant_id, id) ON DELETE CASCADE,
+    CONSTRAINT resource_cancellation_tiers_fee_check CHECK (fee_percent BETWEEN 0 AND 100)
+);
+
+ALTER TABLE rsvp.resource_cancellation_tiers ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.resource_cancellation_tiers FORCE ROW LEVEL SECURITY;
+CREATE POLICY resource_cancellation_tiers_tenant_isolation ON rsvp.resource_cancellation_tiers
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
@@ -0,0 +1,5 @@
+DROP TRIGGER webhook_outbox_trigger ON rsvp.reservation_changes;
+DROP FUNCTION rsvp.webhook_outbox_trigger();
+DROP TABLE rsvp.webhook_dead_letters;
+DROP TABLE rsvp.webhook_deliveries;
+DROP TABLE rsvp.webhook_subscriptions;
@@ -0,0 +1,82 @@
+-- downstream systems receiving reservation changes as signed HTTP callbacks
+CREATE TABLE rsvp.webhook_subscriptions (
+    id BIGSERIAL NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    -- change types to deliver, empty for all of them
+    ops rsvp.reservation_update_type[] NOT NULL DEFAULT '{}',
+    active BOOLEAN NOT NULL DEFAULT true,
+
+    CONSTRAINT webhook_subscriptions_pkey PRIMARY KEY (id)
+);
+CREATE INDEX webhook_subscriptions_tenant_id_idx ON rsvp.webhook_subscriptions (tenant_id);
+
+-- the outbox, one row per change and subscription waiting to be delivered
+CREATE TABLE rsvp.webhook_deliveries (
+    id BIGSERIAL NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    subscription_id BIGINT NOT NULL,
+    change_id BIGINT NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    last_error TEXT,
+
+    CONSTRAINT webhook_deliveries_pkey PRIMARY KEY (id),
+    CONSTRAINT webhook_deliveries_subscription_fkey FOREIGN KEY (subscription_id)
+        REFERENCES rsvp.webhook_subscriptions (id) ON DELETE CASCADE
+);
+CREATE INDEX webhook_deliveries_subscription_id_idx ON rsvp.webhook_deliveries (subscription_id, id);
+
+-- deliveries which kept failing until they ran out of attempts
+CREATE TABLE rsvp.webhook_dead_letters (
+    id BIGINT NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    subscription_id BIGINT NOT NULL,
+    change_id BIGINT NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error TEXT,
+    failed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT webhook_dead_letters_pkey PRIMARY KEY (id),
+    CONSTRAINT webhook_dead_letters_subscription_fkey FOREIGN KEY (subscription_id)
+        REFERENCES rsvp.webhook_subscriptions (id) ON DELETE CASCADE
+);
+CREATE INDEX webhook_dead_letters_tenant_id_idx ON rsvp.webhook_dead_letters (tenant_id, id);
+
+ALTER TABLE rsvp.webhook_subscriptions ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.webhook_subscriptions FORCE ROW LEVEL SECURITY;
+CREATE POLICY webhook_subscriptions_tenant_isolation ON rsvp.webhook_subscriptions
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+ALTER TABLE rsvp.webhook_deliveries ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.webhook_deliveries FORCE ROW LEVEL SECURITY;
+CREATE POLICY webhook_deliveries_tenant_isolation ON rsvp.webhook_deliveries
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+ALTER TABLE rsvp.webhook_dead_letters ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.webhook_dead_letters FORCE ROW LEVEL SECURITY;
+CREATE POLICY webhook_dead_letters_tenant_isolation ON rsvp.webhook_dead_letters
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+
+-- queue every change for the subscriptions of its tenant in the transaction making it
+CREATE OR REPLACE FUNCTION rsvp.webhook_outbox_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO rsvp.webhook_deliveries (tenant_id, subscription_id, change_id, payload)
+        SELECT NEW.tenant_id, s.id, NEW.id, jsonb_build_object(
+            'change_id', NEW.id,
+            'tenant_id', NEW.tenant_id,
+            'op', NEW.op,
+            'reservation_id', NEW.reservation_id,
+            'old', NEW.old,
+            'new', NEW.new)
+        FROM rsvp.webhook_subscriptions s
+        WHERE s.tenant_id = NEW.tenant_id AND s.active
+            AND (s.ops = '{}' OR NEW.op = ANY (s.ops));
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER webhook_outbox_trigger
+    AFTER INSERT ON rsvp.reservation_changes
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.webhook_outbox_trigger();
@@ -0,0 +1,2 @@
+DROP TABLE rsvp.notifications;
+DROP TYPE rsvp.notification_kind;
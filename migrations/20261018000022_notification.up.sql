@@ -0,0 +1,29 @@
+CREATE TYPE rsvp.notification_kind AS ENUM ('unknown', 'reminder', 'hold_expiring');
+
+-- notifications computed from reservations by the scheduler, kept until sent so they survive
+-- restarts. A reservation moved to another start gets new ones, the old ones are never sent
+CREATE TABLE rsvp.notifications (
+    id BIGSERIAL NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    reservation_id BIGINT NOT NULL,
+    kind rsvp.notification_kind NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL,
+    due_at TIMESTAMPTZ NOT NULL,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    sent_at TIMESTAMPTZ,
+    failed_at TIMESTAMPTZ,
+
+    CONSTRAINT notifications_pkey PRIMARY KEY (id),
+    CONSTRAINT notifications_reservation_fkey FOREIGN KEY (reservation_id)
+        REFERENCES rsvp.reservations (id) ON DELETE CASCADE,
+    CONSTRAINT notifications_unique UNIQUE (reservation_id, kind, starts_at)
+);
+CREATE INDEX notifications_due_idx ON rsvp.notifications (next_attempt_at)
+    WHERE sent_at IS NULL AND failed_at IS NULL;
+
+ALTER TABLE rsvp.notifications ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.notifications FORCE ROW LEVEL SECURITY;
+CREATE POLICY notifications_tenant_isolation ON rsvp.notifications
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
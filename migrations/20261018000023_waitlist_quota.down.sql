@@ -0,0 +1,32 @@
+CREATE OR REPLACE FUNCTION rsvp.promote_waitlist(tid VARCHAR(64), rid VARCHAR(64), freed TSTZRANGE) RETURNS VOID AS $$
+DECLARE
+    pre INTERVAL;
+    post INTERVAL;
+    approval BOOLEAN;
+    entry rsvp.waitlist;
+    promoted BIGINT;
+BEGIN
+    SELECT pre_buffer, post_buffer, requires_approval INTO pre, post, approval FROM rsvp.resources
+        WHERE tenant_id = tid AND id = rid;
+    pre := COALESCE(pre, '0');
+    post := COALESCE(post, '0');
+
+    FOR entry IN SELECT * FROM rsvp.waitlist
+        WHERE tenant_id = tid AND resource_id = rid AND reservation_id IS NULL
+            AND lower(timespan) > now()
+            AND tstzrange(lower(timespan) - pre, upper(timespan) + post) && freed
+        ORDER BY id FOR UPDATE SKIP LOCKED
+    LOOP
+        BEGIN
+            INSERT INTO rsvp.reservations (tenant_id, user_id, resource_id, timespan, note, status)
+                VALUES (entry.tenant_id, entry.user_id, entry.resource_id, entry.timespan, entry.note,
+                    CASE WHEN COALESCE(approval, false) THEN 'awaiting_approval' ELSE 'pending' END::rsvp.reservation_status)
+                RETURNING id INTO promoted;
+            UPDATE rsvp.waitlist SET reservation_id = promoted WHERE id = entry.id;
+        EXCEPTION WHEN exclusion_violation THEN
+            -- the window is still taken, keep waiting
+            NULL;
+        END;
+    END LOOP;
+END;
+$$ LANGUAGE plpgsql;
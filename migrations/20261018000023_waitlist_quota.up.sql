@@ -0,0 +1,59 @@
+-- promote only entries whose user is within the active reservation quota of the resource type.
+-- The booking policies live in the service, each transaction passes the quotas in effect as
+-- rsvp.active_quotas: {"defaults": {type: max}, "tenants": {tenant: {type: max or null}}}
+CREATE OR REPLACE FUNCTION rsvp.promote_waitlist(tid VARCHAR(64), rid VARCHAR(64), freed TSTZRANGE) RETURNS VOID AS $$
+DECLARE
+    pre INTERVAL;
+    post INTERVAL;
+    approval BOOLEAN;
+    kind VARCHAR(64);
+    quotas JSONB := COALESCE(NULLIF(current_setting('rsvp.active_quotas', true), ''), '{}');
+    quota INT;
+    active BIGINT;
+    entry rsvp.waitlist;
+    promoted BIGINT;
+BEGIN
+    SELECT pre_buffer, post_buffer, requires_approval, resource_type INTO pre, post, approval, kind
+        FROM rsvp.resources WHERE tenant_id = tid AND id = rid;
+    pre := COALESCE(pre, '0');
+    post := COALESCE(post, '0');
+    kind := COALESCE(kind, 'default');
+    IF quotas->'tenants'->tid ? kind THEN
+        quota := (quotas->'tenants'->tid->>kind)::INT;
+    ELSE
+        quota := (quotas->'defaults'->>kind)::INT;
+    END IF;
+
+    FOR entry IN SELECT * FROM rsvp.waitlist
+        WHERE tenant_id = tid AND resource_id = rid AND reservation_id IS NULL
+            AND lower(timespan) > now()
+            AND tstzrange(lower(timespan) - pre, upper(timespan) + post) && freed
+        ORDER BY id FOR UPDATE SKIP LOCKED
+    LOOP
+        IF quota IS NOT NULL THEN
+            -- serialized with the quota check of reserve
+            PERFORM pg_advisory_xact_lock(hashtext(tid || '/' || entry.user_id));
+            SELECT count(*) INTO active FROM rsvp.reservations r
+                LEFT JOIN rsvp.resources s ON s.tenant_id = r.tenant_id AND s.id = r.resource_id
+                WHERE r.tenant_id = tid AND r.user_id = entry.user_id
+                    AND COALESCE(s.resource_type, 'default') = kind
+                    AND r.status IN ('pending', 'confirmed', 'awaiting_approval')
+                    AND upper(r.timespan) > now();
+            IF active >= quota THEN
+                -- the user holds as many reservations as allowed, the entry keeps waiting
+                CONTINUE;
+            END IF;
+        END IF;
+        BEGIN
+            INSERT INTO rsvp.reservations (tenant_id, user_id, resource_id, timespan, note, status)
+                VALUES (entry.tenant_id, entry.user_id, entry.resource_id, entry.timespan, entry.note,
+                    CASE WHEN COALESCE(approval, false) THEN 'awaiting_approval' ELSE 'pending' END::rsvp.reservation_status)
+                RETURNING id INTO promoted;
+            UPDATE rsvp.waitlist SET reservation_id = promoted WHERE id = entry.id;
+        EXCEPTION WHEN exclusion_violation THEN
+            -- the window is still taken, keep waiting
+            NULL;
+        END;
+    END LOOP;
+END;
+$$ LANGUAGE plpgsql;
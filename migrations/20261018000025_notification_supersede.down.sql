@@ -0,0 +1,2 @@
+DROP TRIGGER notifications_supersede_trigger ON rsvp.reservations;
+DROP FUNCTION rsvp.supersede_notifications();
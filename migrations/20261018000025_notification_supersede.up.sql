@@ -0,0 +1,18 @@
+-- a rescheduled reservation or one whose status no longer qualifies drops its unsent
+-- notifications, the scheduler computes new ones for the new start
+CREATE OR REPLACE FUNCTION rsvp.supersede_notifications() RETURNS TRIGGER AS $$
+BEGIN
+    DELETE FROM rsvp.notifications
+    WHERE reservation_id = NEW.id AND sent_at IS NULL AND failed_at IS NULL
+        AND (starts_at <> lower(NEW.timespan)
+            OR NOT (NEW.status = 'pending'
+                OR (kind = 'reminder' AND NEW.status IN ('confirmed', 'awaiting_approval'))));
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER notifications_supersede_trigger
+    AFTER UPDATE OF timespan, status ON rsvp.reservations
+    FOR EACH ROW
+    WHEN (OLD.timespan IS DISTINCT FROM NEW.timespan OR OLD.status IS DISTINCT FROM NEW.status)
+    EXECUTE PROCEDURE rsvp.supersede_notifications();
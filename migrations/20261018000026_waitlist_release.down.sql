@@ -0,0 +1,70 @@
+DROP TRIGGER reservations_waitlist_trigger ON rsvp.reservations;
+CREATE OR REPLACE FUNCTION rsvp.reservations_waitlist_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM rsvp.promote_waitlist(OLD.tenant_id, OLD.resource_id, OLD.blocked);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+CREATE CONSTRAINT TRIGGER reservations_waitlist_trigger
+    AFTER DELETE OR UPDATE OF timespan, resource_id, status ON rsvp.reservations
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservations_waitlist_trigger();
+
+DROP TABLE rsvp.released_windows;
+
+CREATE OR REPLACE FUNCTION rsvp.promote_waitlist(tid VARCHAR(64), rid VARCHAR(64), freed TSTZRANGE) RETURNS VOID AS $$
+DECLARE
+    pre INTERVAL;
+    post INTERVAL;
+    approval BOOLEAN;
+    kind VARCHAR(64);
+    quotas JSONB := COALESCE(NULLIF(current_setting('rsvp.active_quotas', true), ''), '{}');
+    quota INT;
+    active BIGINT;
+    entry rsvp.waitlist;
+    promoted BIGINT;
+BEGIN
+    SELECT pre_buffer, post_buffer, requires_approval, resource_type INTO pre, post, approval, kind
+        FROM rsvp.resources WHERE tenant_id = tid AND id = rid;
+    pre := COALESCE(pre, '0');
+    post := COALESCE(post, '0');
+    kind := COALESCE(kind, 'default');
+    IF quotas->'tenants'->tid ? kind THEN
+        quota := (quotas->'tenants'->tid->>kind)::INT;
+    ELSE
+        quota := (quotas->'defaults'->>kind)::INT;
+    END IF;
+
+    FOR entry IN SELECT * FROM rsvp.waitlist
+        WHERE tenant_id = tid AND resource_id = rid AND reservation_id IS NULL
+            AND lower(timespan) > now()
+            AND tstzrange(lower(timespan) - pre, upper(timespan) + post) && freed
+        ORDER BY id FOR UPDATE SKIP LOCKED
+    LOOP
+        IF quota IS NOT NULL THEN
+            -- serialized with the quota check of reserve
+            PERFORM pg_advisory_xact_lock(hashtext(tid || '/' || entry.user_id));
+            SELECT count(*) INTO active FROM rsvp.reservations r
+                LEFT JOIN rsvp.resources s ON s.tenant_id = r.tenant_id AND s.id = r.resource_id
+                WHERE r.tenant_id = tid AND r.user_id = entry.user_id
+                    AND COALESCE(s.resource_type, 'default') = kind
+                    AND r.status IN ('pending', 'confirmed', 'awaiting_approval')
+                    AND upper(r.timespan) > now();
+            IF active >= quota THEN
+                -- the user holds as many reservations as allowed, the entry keeps waiting
+                CONTINUE;
+            END IF;
+        END IF;
+        BEGIN
+            INSERT INTO rsvp.reservations (tenant_id, user_id, resource_id, timespan, note, status)
+                VALUES (entry.tenant_id, entry.user_id, entry.resource_id, entry.timespan, entry.note,
+                    CASE WHEN COALESCE(approval, false) THEN 'awaiting_approval' ELSE 'pending' END::rsvp.reservation_status)
+                RETURNING id INTO promoted;
+            UPDATE rsvp.waitlist SET reservation_id = promoted WHERE id = entry.id;
+        EXCEPTION WHEN exclusion_violation THEN
+            -- the window is still taken, keep waiting
+            NULL;
+        END;
+    END LOOP;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,39 @@
+-- the waitlist is served by the service before it commits, so promoted entries pass the same
+-- rules, approval, quota and price as any reservation. The trigger only records the windows
+-- released meanwhile, if anyone is waiting
+DROP FUNCTION rsvp.promote_waitlist(VARCHAR(64), VARCHAR(64), TSTZRANGE);
+
+CREATE TABLE rsvp.released_windows (
+    id BIGSERIAL NOT NULL,
+    tenant_id VARCHAR(64) NOT NULL DEFAULT '',
+    resource_id VARCHAR(64) NOT NULL,
+    blocked TSTZRANGE NOT NULL,
+
+    CONSTRAINT released_windows_pkey PRIMARY KEY (id)
+);
+
+ALTER TABLE rsvp.released_windows ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.released_windows FORCE ROW LEVEL SECURITY;
+CREATE POLICY released_windows_tenant_isolation ON rsvp.released_windows
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
+
+CREATE OR REPLACE FUNCTION rsvp.reservations_waitlist_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    -- a status change only releases the window of a preempted or rejected reservation
+    IF TG_OP = 'UPDATE' AND OLD.timespan = NEW.timespan AND OLD.resource_id = NEW.resource_id
+        AND NEW.status NOT IN ('preempted', 'rejected') THEN
+        RETURN NULL;
+    END IF;
+    IF EXISTS (SELECT 1 FROM rsvp.waitlist WHERE tenant_id = OLD.tenant_id AND reservation_id IS NULL) THEN
+        INSERT INTO rsvp.released_windows (tenant_id, resource_id, blocked)
+            VALUES (OLD.tenant_id, OLD.resource_id, OLD.blocked);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+-- recorded right away, the service serves the waitlist before the commit
+DROP TRIGGER reservations_waitlist_trigger ON rsvp.reservations;
+CREATE TRIGGER reservations_waitlist_trigger
+    AFTER DELETE OR UPDATE OF timespan, resource_id, status ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservations_waitlist_trigger();
@@ -0,0 +1 @@
+DROP TABLE rsvp.booking_policies CASCADE;
@@ -0,0 +1,18 @@
+-- booking policies a tenant sets at runtime, they replace the ones loaded from config for the
+-- tenant's resources of the same type
+CREATE TABLE rsvp.booking_policies (
+    tenant_id VARCHAR(64) NOT NULL,
+    resource_type VARCHAR(64) NOT NULL,
+    max_active_reservations INTEGER,
+    max_duration_secs BIGINT,
+    max_advance_secs BIGINT,
+    min_lead_time_secs BIGINT,
+    overbooking_percent INTEGER,
+
+    CONSTRAINT booking_policies_pkey PRIMARY KEY (tenant_id, resource_type)
+);
+
+ALTER TABLE rsvp.booking_policies ENABLE ROW LEVEL SECURITY;
+ALTER TABLE rsvp.booking_policies FORCE ROW LEVEL SECURITY;
+CREATE POLICY booking_policies_tenant_isolation ON rsvp.booking_policies
+    USING (rsvp.tenant_visible(tenant_id)) WITH CHECK (rsvp.tenant_visible(tenant_id));
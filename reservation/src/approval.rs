@@ -0,0 +1,195 @@
+use abi::{Error, ReservationId, ReservationStatus, RsvpStatus, UserId, Validator};
+use async_trait::async_trait;
+
+use crate::{Approvals, ReservationManager};
+
+#[async_trait]
+impl Approvals for ReservationManager {
+    async fn approve(&self, id: ReservationId, uid: UserId) -> Result<abi::Reservation, Error> {
+        self.decide(id, uid, RsvpStatus::Confirmed).await
+    }
+
+    async fn reject(&self, id: ReservationId, uid: UserId) -> Result<abi::Reservation, Error> {
+        self.decide(id, uid, RsvpStatus::Rejected).await
+    }
+
+    async fn approval_inbox(&self, uid: UserId) -> Result<Vec<abi::Reservation>, Error> {
+        let mut tx = self.begin().await?;
+        let rsvps = sqlx::query_as(
+            r#"SELECT r.* FROM rsvp.reservations r
+            JOIN rsvp.resource_approvers a ON a.tenant_id = r.tenant_id AND a.resource_id = r.resource_id
+            WHERE r.tenant_id = $1 AND a.user_id = $2 AND r.status = 'awaiting_approval'
+            ORDER BY lower(r.timespan), r.id"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(uid)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(rsvps)
+    }
+}
+
+impl ReservationManager {
+    /// move a reservation awaiting approval to the approver's decision
+    async fn decide(
+        &self,
+        id: ReservationId,
+        uid: UserId,
+        status: RsvpStatus,
+    ) -> Result<abi::Reservation, Error> {
+        id.validate()?;
+        let mut tx = self.begin().await?;
+        let rsvp = self.lock_reservation(&mut tx, id).await?;
+        if rsvp.status() != ReservationStatus::AwaitingApproval {
+            return Err(Error::InvalidState(format!(
+                "reservation {id} is {}, only reservations awaiting approval can be decided",
+                rsvp.status()
+            )));
+        }
+        let approver: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM rsvp.resource_approvers WHERE tenant_id = $1 AND resource_id = $2 AND user_id = $3)",
+        )
+        .bind(&self.tenant_id)
+        .bind(&rsvp.resource_id)
+        .bind(&uid)
+        .fetch_one(&mut *tx)
+        .await?;
+        if !approver {
+            return Err(Error::PermissionDenied(format!(
+                "{uid} is not an approver of {}",
+                rsvp.resource_id
+            )));
+        }
+
+        let rsvp = sqlx::query_as(
+            "UPDATE rsvp.reservations SET status = $1 WHERE id = $2 AND tenant_id = $3 RETURNING *",
+        )
+        .bind(status)
+        .bind(id)
+        .bind(&self.tenant_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        self.commit(tx).await?;
+        Ok(rsvp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{Reservation, Resource};
+
+    use super::*;
+    use crate::test_util::get_tdb;
+    use crate::{ResourceRegistry, Rsvp};
+
+    #[tokio::test]
+    async fn approvers_should_decide_reservations_awaiting_approval() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        manager
+            .save_resource(Resource {
+                requires_approval: true,
+                approvers: vec!["bossId".into()],
+                ..Resource::new("board-room", "meeting_room")
+            })
+            .await
+            .unwrap();
+
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "board-room",
+                "2030-12-25T10:00:00+0000".parse().unwrap(),
+                "2030-12-25T11:00:00+0000".parse().unwrap(),
+                "quarterly review",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(rsvp.status(), ReservationStatus::AwaitingApproval);
+
+        // the window is held while waiting
+        let err = manager
+            .reserve(Reservation::new_pending(
+                "aliceId",
+                "board-room",
+                "2030-12-25T10:30:00+0000".parse().unwrap(),
+                "2030-12-25T11:30:00+0000".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::ConflictReservation(_)));
+
+        let inbox = manager.approval_inbox("bossId".into()).await.unwrap();
+        assert_eq!(inbox.len(), 1);
+        assert_eq!(inbox[0].id, rsvp.id);
+        assert!(
+            manager
+                .approval_inbox("aliceId".into())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let err = manager
+            .approve(rsvp.id, "aliceId".into())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::PermissionDenied(_)));
+        let confirmed = manager.approve(rsvp.id, "bossId".into()).await.unwrap();
+        assert_eq!(confirmed.status(), ReservationStatus::Confirmed);
+        let err = manager.reject(rsvp.id, "bossId".into()).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidState(_)));
+        assert!(
+            manager
+                .approval_inbox("bossId".into())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejected_reservation_should_release_its_window() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let resource = manager
+            .save_resource(Resource {
+                requires_approval: true,
+                approvers: vec!["bossId".into()],
+                ..Resource::new("board-room", "meeting_room")
+            })
+            .await
+            .unwrap();
+        assert_eq!(resource.approvers, vec!["bossId".to_string()]);
+        let loaded = manager.get_resource("board-room").await.unwrap();
+        assert!(loaded.requires_approval);
+        assert_eq!(loaded.approvers, vec!["bossId".to_string()]);
+
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "board-room",
+                "2030-12-25T10:00:00+0000".parse().unwrap(),
+                "2030-12-25T11:00:00+0000".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+        let rejected = manager.reject(rsvp.id, "bossId".into()).await.unwrap();
+        assert_eq!(rejected.status(), ReservationStatus::Rejected);
+
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "aliceId",
+                "board-room",
+                "2030-12-25T10:00:00+0000".parse().unwrap(),
+                "2030-12-25T11:00:00+0000".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(rsvp.status(), ReservationStatus::AwaitingApproval);
+    }
+}
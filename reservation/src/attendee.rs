@@ -0,0 +1,211 @@
+use abi::{
+    Attendee, AttendeeResponse, AttendeeRole, Error, ReservationId, RsvpAttendeeResponse,
+    RsvpAttendeeRole, UserId, Validator,
+};
+use async_trait::async_trait;
+use sqlx::Row;
+
+use crate::{Attendees, ReservationManager};
+
+#[async_trait]
+impl Attendees for ReservationManager {
+    async fn add_attendee(&self, mut attendee: Attendee) -> Result<Attendee, Error> {
+        attendee.validate()?;
+        attendee.tenant_id = self.tenant_id.clone();
+        let role = match attendee.role() {
+            AttendeeRole::Unknown => AttendeeRole::Required,
+            role => role,
+        };
+
+        let mut tx = self.begin().await?;
+        let rsvp = self
+            .lock_reservation(&mut tx, attendee.reservation_id)
+            .await?;
+        if rsvp.user_id == attendee.user_id {
+            return Err(Error::InvalidAttendee(format!(
+                "{} owns reservation {}",
+                rsvp.user_id, rsvp.id
+            )));
+        }
+        let added = sqlx::query_as(
+            r#"INSERT INTO rsvp.attendees (tenant_id, reservation_id, user_id, email, role)
+            VALUES ($1, $2, NULLIF($3, ''), NULLIF($4, ''), $5)
+            ON CONFLICT DO NOTHING RETURNING *"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(attendee.reservation_id)
+        .bind(&attendee.user_id)
+        .bind(&attendee.email)
+        .bind(RsvpAttendeeRole::from(role))
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(added) = added else {
+            return Err(Error::InvalidAttendee(format!(
+                "{} already attends reservation {}",
+                attendee.who(),
+                attendee.reservation_id
+            )));
+        };
+        tx.commit().await?;
+        Ok(added)
+    }
+
+    async fn remove_attendee(&self, id: i64) -> Result<Attendee, Error> {
+        id.validate()?;
+        let mut tx = self.begin().await?;
+        let attendee = sqlx::query_as(
+            "DELETE FROM rsvp.attendees WHERE id = $1 AND tenant_id = $2 RETURNING *",
+        )
+        .bind(id)
+        .bind(&self.tenant_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(attendee)
+    }
+
+    async fn respond(
+        &self,
+        id: i64,
+        uid: UserId,
+        response: AttendeeResponse,
+    ) -> Result<Attendee, Error> {
+        id.validate()?;
+        if response == AttendeeResponse::Unknown {
+            return Err(Error::InvalidAttendee("response required".to_string()));
+        }
+        let mut tx = self.begin().await?;
+        let row = sqlx::query(
+            r#"SELECT a.user_id, r.user_id AS owner FROM rsvp.attendees a
+            JOIN rsvp.reservations r ON r.id = a.reservation_id
+            WHERE a.id = $1 AND a.tenant_id = $2 FOR UPDATE OF a"#,
+        )
+        .bind(id)
+        .bind(&self.tenant_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        let attendee: Option<String> = row.get("user_id");
+        let owner: String = row.get("owner");
+        let allowed = match attendee {
+            Some(attendee) => attendee == uid,
+            // external attendees answer through the owner
+            None => owner == uid,
+        };
+        if !allowed {
+            return Err(Error::PermissionDenied(format!(
+                "{uid} can not respond for attendee {id}"
+            )));
+        }
+
+        let attendee = sqlx::query_as(
+            "UPDATE rsvp.attendees SET response = $1 WHERE id = $2 AND tenant_id = $3 RETURNING *",
+        )
+        .bind(RsvpAttendeeResponse::from(response))
+        .bind(id)
+        .bind(&self.tenant_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(attendee)
+    }
+
+    async fn list_attendees(&self, rid: ReservationId) -> Result<Vec<Attendee>, Error> {
+        rid.validate()?;
+        let mut tx = self.begin().await?;
+        let attendees = sqlx::query_as(
+            "SELECT * FROM rsvp.attendees WHERE reservation_id = $1 AND tenant_id = $2 ORDER BY id",
+        )
+        .bind(rid)
+        .bind(&self.tenant_id)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(attendees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{Reservation, ReservationQueryBuilder};
+
+    use super::*;
+    use crate::Rsvp;
+    use crate::test_util::get_tdb;
+
+    #[tokio::test]
+    async fn attendees_should_respond_and_be_queryable() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "meeting-room-1",
+                "2030-12-25T10:00:00+0000".parse().unwrap(),
+                "2030-12-25T11:00:00+0000".parse().unwrap(),
+                "weekly sync",
+            ))
+            .await
+            .unwrap();
+
+        let alice = manager
+            .add_attendee(Attendee::new_user(
+                rsvp.id,
+                "aliceId",
+                AttendeeRole::Required,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(alice.response(), AttendeeResponse::NeedsAction);
+        let err = manager
+            .add_attendee(Attendee::new_user(
+                rsvp.id,
+                "aliceId",
+                AttendeeRole::Optional,
+            ))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidAttendee(_)));
+        let guest = manager
+            .add_attendee(Attendee::new_email(
+                rsvp.id,
+                "bob@example.com",
+                AttendeeRole::Guest,
+            ))
+            .await
+            .unwrap();
+
+        let err = manager
+            .respond(alice.id, "eveId".into(), AttendeeResponse::Declined)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::PermissionDenied(_)));
+        let alice = manager
+            .respond(alice.id, "aliceId".into(), AttendeeResponse::Accepted)
+            .await
+            .unwrap();
+        assert_eq!(alice.response(), AttendeeResponse::Accepted);
+        let guest = manager
+            .respond(guest.id, "silwingsId".into(), AttendeeResponse::Tentative)
+            .await
+            .unwrap();
+        assert_eq!(guest.response(), AttendeeResponse::Tentative);
+
+        let query = ReservationQueryBuilder::default()
+            .attendee_id("aliceId")
+            .build()
+            .unwrap();
+        let rsvps = manager.query(query).await.unwrap();
+        assert_eq!(rsvps.len(), 1);
+        assert_eq!(rsvps[0].id, rsvp.id);
+        let query = ReservationQueryBuilder::default()
+            .attendee_id("silwingsId")
+            .build()
+            .unwrap();
+        assert!(manager.query(query).await.unwrap().is_empty());
+
+        manager.remove_attendee(alice.id).await.unwrap();
+        let attendees = manager.list_attendees(rsvp.id).await.unwrap();
+        assert_eq!(attendees.len(), 1);
+        assert_eq!(attendees[0].email, "bob@example.com");
+    }
+}
@@ -0,0 +1,111 @@
+use abi::UtcWindow;
+use chrono::Duration;
+
+/// free windows within `window` that can hold a reservation surrounded by the given buffers.
+/// `blocked` are the windows blocked by existing reservations, ordered by start time
+pub(crate) fn free_windows(
+    window: UtcWindow,
+    blocked: &[UtcWindow],
+    pre: Duration,
+    post: Duration,
+) -> Vec<UtcWindow> {
+    let (start, end) = window;
+    let mut windows = Vec::new();
+    let mut cursor = start;
+    for (lower, upper) in blocked {
+        // the post buffer of the new reservation must end before the existing one is blocked
+        let free_end = (*lower - post).min(end);
+        if cursor < free_end {
+            windows.push((cursor, free_end));
+        }
+        // and its pre buffer must not start before the existing one is released
+        cursor = cursor.max(*upper + pre);
+    }
+    if cursor < end {
+        windows.push((cursor, end));
+    }
+    windows
+}
+
+/// intersection of two ordered lists of disjoint windows
+pub(crate) fn intersect_windows(a: &[UtcWindow], b: &[UtcWindow]) -> Vec<UtcWindow> {
+    let (mut i, mut j) = (0, 0);
+    let mut windows = Vec::new();
+    while i < a.len() && j < b.len() {
+        let start = a[i].0.max(b[j].0);
+        let end = a[i].1.min(b[j].1);
+        if start < end {
+            windows.push((start, end));
+        }
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn t(s: &str) -> DateTime<Utc> {
+        format!("2025-06-01T{s}:00Z").parse().unwrap()
+    }
+
+    #[test]
+    fn free_windows_without_buffers_should_be_gaps() {
+        let windows = free_windows(
+            (t("08:00"), t("14:00")),
+            &[(t("07:00"), t("09:00")), (t("10:00"), t("11:00"))],
+            Duration::zero(),
+            Duration::zero(),
+        );
+        assert_eq!(
+            windows,
+            vec![(t("09:00"), t("10:00")), (t("11:00"), t("14:00"))]
+        );
+    }
+
+    #[test]
+    fn free_windows_should_honor_buffers() {
+        // existing reservation 10:00-11:00 with 30 minutes post buffer blocks 10:00-11:30
+        let windows = free_windows(
+            (t("08:00"), t("14:00")),
+            &[(t("10:00"), t("11:30"))],
+            Duration::minutes(15),
+            Duration::minutes(30),
+        );
+        assert_eq!(
+            windows,
+            vec![(t("08:00"), t("09:30")), (t("11:45"), t("14:00"))]
+        );
+    }
+
+    #[test]
+    fn free_windows_should_be_empty_when_fully_blocked() {
+        let windows = free_windows(
+            (t("10:00"), t("11:00")),
+            &[(t("09:00"), t("12:00"))],
+            Duration::zero(),
+            Duration::zero(),
+        );
+        assert!(windows.is_empty());
+    }
+
+    #[test]
+    fn intersect_windows_should_clip_to_both() {
+        let free = [(t("08:00"), t("09:30")), (t("11:45"), t("14:00"))];
+        let open = [(t("09:00"), t("12:00")), (t("13:00"), t("18:00"))];
+        assert_eq!(
+            intersect_windows(&free, &open),
+            vec![
+                (t("09:00"), t("09:30")),
+                (t("11:45"), t("12:00")),
+                (t("13:00"), t("14:00")),
+            ]
+        );
+    }
+}
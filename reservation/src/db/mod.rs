@@ -7,6 +7,8 @@ pub trait QueryBuilderExt<'q, DB>
 where
     DB: Database,
 {
+    fn push_if(&mut self, condition: bool, fragment: impl Display) -> &mut Self;
+
     fn push_and_bind_if<T>(
         &mut self,
         condition: bool,
@@ -5,6 +5,13 @@ use sqlx::{Encode, Postgres, QueryBuilder, Type};
 use super::QueryBuilderExt;
 
 impl<'args> QueryBuilderExt<'args, Postgres> for QueryBuilder<'args, Postgres> {
+    fn push_if(&mut self, condition: bool, fragment: impl Display) -> &mut Self {
+        if condition {
+            return self.push(fragment);
+        }
+        self
+    }
+
     fn push_and_bind_if<T>(
         &mut self,
         condition: bool,
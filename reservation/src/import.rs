@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use abi::{Error, ImportConflict, ImportMode, ImportResponse, ImportRow, ImportRowError, Resource};
+use async_trait::async_trait;
+use sqlx::{Acquire, Postgres, Transaction};
+use tokio::sync::mpsc;
+
+use crate::manager::requested;
+use crate::{Imports, ReservationManager};
+
+#[async_trait]
+impl Imports for ReservationManager {
+    async fn import(
+        &self,
+        mut rows: mpsc::Receiver<Result<ImportRow, Error>>,
+        mode: ImportMode,
+    ) -> Result<ImportResponse, Error> {
+        let mut tx = self.begin().await?;
+        let mut response = ImportResponse::default();
+        // resources of the rows, loaded once per import
+        let mut resources = HashMap::new();
+        while let Some(row) = rows.recv().await {
+            // an unreadable stream aborts the import, the transaction rolls back on drop
+            let row = row?;
+            match row.reservation {
+                Ok(rsvp) => {
+                    self.import_row(&mut tx, &mut resources, row.row, rsvp, &mut response)
+                        .await?
+                }
+                Err(message) => response.invalid.push(ImportRowError {
+                    row: row.row,
+                    message,
+                }),
+            }
+        }
+
+        response.committed = mode == ImportMode::BestEffort
+            || (response.conflicts.is_empty() && response.invalid.is_empty());
+        if response.committed {
+            tx.commit().await?;
+        } else {
+            tx.rollback().await?;
+            response.accepted = 0;
+        }
+        Ok(response)
+    }
+}
+
+impl ReservationManager {
+    /// reserve the row like a new reservation, with the rules, approval and price of its
+    /// resource, in a savepoint so a rejected row leaves the others alone
+    async fn import_row(
+        &self,
+        tx: &mut Transaction<'static, Postgres>,
+        resources: &mut HashMap<String, Resource>,
+        row: u64,
+        rsvp: abi::Reservation,
+        response: &mut ImportResponse,
+    ) -> Result<(), Error> {
+        let rsvp = abi::Reservation {
+            priority: 0,
+            ..requested(rsvp)
+        };
+        if !resources.contains_key(&rsvp.resource_id) {
+            let resource = self.load_resource(tx, &rsvp.resource_id).await?;
+            resources.insert(rsvp.resource_id.clone(), resource);
+        }
+        let resource = &resources[&rsvp.resource_id];
+
+        let mut savepoint = tx.begin().await?;
+        match self
+            .insert_or_overbook(&mut savepoint, resource, rsvp)
+            .await
+        {
+            Ok(_) => {
+                savepoint.commit().await?;
+                response.accepted += 1;
+            }
+            Err(Error::ConflictReservation(info)) => {
+                savepoint.rollback().await?;
+                response.conflicts.push(ImportConflict::new(row, info));
+            }
+            // the database went away, the import cannot go on
+            Err(Error::DbError(e)) if !matches!(e, sqlx::Error::Database(_)) => {
+                return Err(Error::DbError(e));
+            }
+            // breaks a rule of the resource, or a constraint or trigger of the table
+            Err(e) => {
+                savepoint.rollback().await?;
+                response.invalid.push(ImportRowError {
+                    row,
+                    message: e.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{
+        ImportReader, Money, OpeningHours, Rate, RatePlan, RateUnit, Reservation, ReservationStatus,
+    };
+
+    use super::*;
+    use crate::test_util::get_tdb;
+    use crate::{Pricing, ResourceRegistry, Rsvp};
+
+    const IMPORT: &str = "user_id,resource_id,start,end,note\n\
+        alice,room-1,2030-12-25T08:00:00Z,2030-12-25T10:00:00Z,first\n\
+        bob,room-1,2030-12-25T09:00:00Z,2030-12-25T11:00:00Z,overlaps the first\n\
+        carol,room-2,2030-12-25T09:00:00Z,2030-12-25T08:00:00Z,reversed\n\
+        dave,room-3,2030-12-25T09:00:00Z,2030-12-25T11:00:00Z,taken\n\
+        erin,room-2,2030-12-25T09:00:00Z,2030-12-25T11:00:00Z,\n";
+
+    async fn import(manager: &ReservationManager, data: &str, mode: ImportMode) -> ImportResponse {
+        let mut reader = ImportReader::new(abi::ExportFormat::Csv);
+        let (sender, receiver) = mpsc::channel(16);
+        for row in reader.push(data.as_bytes()).unwrap() {
+            sender.send(Ok(row)).await.unwrap();
+        }
+        drop(sender);
+        manager.import(receiver, mode).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn import_should_report_conflicts_and_invalid_rows() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await).with_tenant("hotel-a");
+        let taken = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "room-3",
+                "2030-12-25T08:00:00+0000".parse().unwrap(),
+                "2030-12-25T12:00:00+0000".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+
+        let response = import(&manager, IMPORT, ImportMode::AllOrNothing).await;
+        assert!(!response.committed);
+        assert_eq!(response.accepted, 0);
+        let invalid = response.invalid.iter().map(|e| e.row).collect::<Vec<_>>();
+        assert_eq!(invalid, [3]);
+        // a row conflicting with an earlier row of the import or with an existing reservation
+        let conflicts = response
+            .conflicts
+            .iter()
+            .map(|c| {
+                (
+                    c.row,
+                    c.new.as_ref().unwrap().resource_id.as_str(),
+                    c.existing.as_ref().unwrap().window.unwrap().bounds().0,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            conflicts,
+            [
+                (2, "room-1", "2030-12-25T08:00:00Z".parse().unwrap()),
+                (4, "room-3", taken.bounds().0),
+            ]
+        );
+        assert_eq!(notes(&manager).await, [""]);
+
+        let response = import(&manager, IMPORT, ImportMode::BestEffort).await;
+        assert!(response.committed);
+        assert_eq!(response.accepted, 2);
+        assert_eq!(response.conflicts.len(), 2);
+        assert_eq!(response.invalid.len(), 1);
+        assert_eq!(notes(&manager).await, ["", "", "first"]);
+    }
+
+    #[tokio::test]
+    async fn import_should_reserve_rows_like_new_reservations() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await).with_tenant("hotel-a");
+        manager
+            .save_resource(Resource {
+                requires_approval: true,
+                approvers: vec!["bossId".into()],
+                ..Resource::new("board-room", "meeting_room")
+            })
+            .await
+            .unwrap();
+        manager
+            .save_resource(Resource::new("room-1", "room"))
+            .await
+            .unwrap();
+        manager
+            .save_rate_plan(RatePlan::new(
+                "room-1",
+                vec![Rate::new("hourly", RateUnit::Hour, "10")],
+            ))
+            .await
+            .unwrap();
+        manager
+            .save_resource(Resource {
+                opening_hours: (1..=7)
+                    .map(|d| OpeningHours::new(d, "08:00", "22:00"))
+                    .collect(),
+                ..Resource::new("lab", "lab")
+            })
+            .await
+            .unwrap();
+
+        // status and priority of the file are not taken over
+        let data = "user_id,resource_id,start,end,note,status,priority\n\
+            alice,board-room,2030-12-25T08:00:00Z,2030-12-25T10:00:00Z,approval,confirmed,9\n\
+            bob,room-1,2030-12-25T08:00:00Z,2030-12-25T10:00:00Z,priced,confirmed,9\n\
+            carol,lab,2030-12-25T02:00:00Z,2030-12-25T04:00:00Z,closed,,\n";
+        let response = import(&manager, data, ImportMode::BestEffort).await;
+        assert!(response.committed);
+        assert_eq!(response.accepted, 2);
+        let invalid = response.invalid.iter().map(|e| e.row).collect::<Vec<_>>();
+        assert_eq!(invalid, [3]);
+
+        let rsvps: Vec<abi::Reservation> =
+            sqlx::query_as("SELECT * FROM rsvp.reservations WHERE tenant_id = $1 ORDER BY note")
+                .bind(&manager.tenant_id)
+                .fetch_all(&manager.pool)
+                .await
+                .unwrap();
+        let rsvps = rsvps
+            .into_iter()
+            .map(|r| (r.note.clone(), r.status(), r.priority, r.price))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rsvps,
+            [
+                (
+                    "approval".to_string(),
+                    ReservationStatus::AwaitingApproval,
+                    0,
+                    None
+                ),
+                (
+                    "priced".to_string(),
+                    ReservationStatus::Pending,
+                    0,
+                    Some(Money::new("USD", 20.into()))
+                ),
+            ]
+        );
+    }
+
+    async fn notes(manager: &ReservationManager) -> Vec<String> {
+        sqlx::query_scalar("SELECT note FROM rsvp.reservations WHERE tenant_id = $1 ORDER BY note")
+            .bind(&manager.tenant_id)
+            .fetch_all(&manager.pool)
+            .await
+            .unwrap()
+    }
+}
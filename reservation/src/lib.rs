@@ -3,23 +3,81 @@ use abi::Error;
 use abi::FilterPager;
 use abi::ReservationId;
 use abi::ReservationQuery;
+use abi::ResourceId;
+use abi::TenantId;
+use abi::UserId;
+use abi::export_request::Selection;
+use abi::{
+    Attendee, AttendeeResponse, BookingPolicy, ImportMode, ImportResponse, ImportRow, Money,
+    OccupancyRequest, OccupancyTimeline, OverbookedWindow, Pool, Quote, RatePlan, Resource,
+    ResourceUtilization, TenantSettings, UtilizationRequest, WaitlistEntry, WebhookDeadLetter,
+    WebhookSubscription,
+};
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
 use sqlx::postgres::PgPoolOptions;
+use sqlx::{PgPool, Postgres, Transaction};
+use tokio::sync::mpsc;
 
+mod approval;
+mod attendee;
+mod availability;
 mod db;
+mod import;
 mod manager;
+mod no_show;
+mod notification;
+mod overbooking;
+mod policy;
+mod pool;
+mod pricing;
+mod report;
+mod resource;
+#[cfg(test)]
+mod test_util;
+mod waitlist;
+mod webhook;
 
 pub use db::*;
+pub use notification::{Notification, NotificationKind};
+pub use policy::PolicyStore;
+pub use pool::{AssignmentStrategy, BestFit, Candidate, FirstFit, LeastRecentlyUsed};
+pub use webhook::Delivery;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReservationManager {
     pool: PgPool,
+    tenant_id: TenantId,
+    policies: PolicyStore,
 }
 
 impl ReservationManager {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            tenant_id: TenantId::default(),
+            policies: PolicyStore::default(),
+        }
+    }
+
+    /// replace the booking policies of every tenant without its own for the resource type, e.g.
+    /// with the ones loaded from config
+    pub fn with_policies(mut self, policies: impl IntoIterator<Item = BookingPolicy>) -> Self {
+        self.policies = PolicyStore::new(policies);
+        self
+    }
+
+    /// a manager sharing the same pool, whose operations only see the given tenant's reservations
+    pub fn with_tenant(&self, tenant_id: impl Into<TenantId>) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            tenant_id: tenant_id.into(),
+            policies: self.policies.clone(),
+        }
+    }
+
+    pub fn tenant_id(&self) -> &str {
+        &self.tenant_id
     }
 
     pub async fn from_config(config: &DbConfig) -> Result<Self, abi::Error> {
@@ -30,19 +88,103 @@ impl ReservationManager {
             .await?;
         Ok(Self::new(pool))
     }
+
+    /// begin a transaction scoped to the tenant, row level security relies on rsvp.tenant_id
+    async fn begin(&self) -> Result<Transaction<'static, Postgres>, Error> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("SELECT set_config('rsvp.tenant_id', $1, true)")
+            .bind(&self.tenant_id)
+            .execute(&mut *tx)
+            .await?;
+        Ok(tx)
+    }
+
+    /// begin a transaction which sees every tenant, only for background jobs
+    async fn begin_system(&self) -> Result<Transaction<'static, Postgres>, Error> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("SELECT set_config('rsvp.system', 'on', true)")
+            .execute(&mut *tx)
+            .await?;
+        Ok(tx)
+    }
 }
 
 #[async_trait]
 pub trait Rsvp {
     /// make a reservation
     async fn reserve(&self, rsvp: abi::Reservation) -> Result<abi::Reservation, Error>;
+    /// make a reservation, conflicting reservations of lower priority are moved to preempted
+    async fn preempt(
+        &self,
+        rsvp: abi::Reservation,
+    ) -> Result<(abi::Reservation, Vec<abi::Reservation>), Error>;
     /// change reservation status(if current status is pending , change it to confirmed)
     async fn change_status(&self, id: ReservationId) -> Result<abi::Reservation, Error>;
+    /// move the reservation to another window of the same resource
+    async fn reschedule(
+        &self,
+        id: ReservationId,
+        window: abi::TimeWindow,
+    ) -> Result<abi::Reservation, Error>;
+    /// shrink the reservation to end now, or at the given time inside its window
+    async fn end_early(
+        &self,
+        id: ReservationId,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<abi::Reservation, Error>;
+    /// make the reservation end later, with best effort it ends at the next reservation instead of
+    /// failing on the conflict
+    async fn extend(
+        &self,
+        id: ReservationId,
+        end: DateTime<Utc>,
+        best_effort: bool,
+    ) -> Result<abi::Reservation, Error>;
+    /// split the reservation at the given time, returns the shortened one and the new one
+    async fn split(
+        &self,
+        id: ReservationId,
+        at: DateTime<Utc>,
+    ) -> Result<(abi::Reservation, abi::Reservation), Error>;
+    /// merge contiguous reservations of the same user and resource into the earliest one
+    async fn merge(&self, ids: Vec<ReservationId>) -> Result<abi::Reservation, Error>;
+    /// hand the reservation over to another user, with acceptance it waits for the recipient.
+    /// Only the owner may transfer it, an admin acts without a user
+    async fn transfer(
+        &self,
+        id: ReservationId,
+        by: Option<UserId>,
+        to: UserId,
+        require_acceptance: bool,
+    ) -> Result<abi::Reservation, Error>;
+    /// accept a transfer as its recipient
+    async fn accept_transfer(
+        &self,
+        id: ReservationId,
+        uid: UserId,
+    ) -> Result<abi::Reservation, Error>;
+    /// decline a transfer as its recipient, or withdraw it as the owner
+    async fn decline_transfer(
+        &self,
+        id: ReservationId,
+        uid: UserId,
+    ) -> Result<abi::Reservation, Error>;
+    /// record the guest has arrived, only confirmed reservations which have not ended qualify
+    async fn check_in(&self, id: ReservationId) -> Result<abi::Reservation, Error>;
+    /// record the guest has left
+    async fn check_out(&self, id: ReservationId) -> Result<abi::Reservation, Error>;
     /// update note
     async fn update_note(&self, id: ReservationId, note: String)
     -> Result<abi::Reservation, Error>;
     /// delete reservation
     async fn delete(&self, id: ReservationId) -> Result<(), Error>;
+    /// delete the reservation if the cancellation policy of its resource allows it now, returns
+    /// the deleted reservation and the fee. An override skips the policy and waives the fee
+    async fn cancel(
+        &self,
+        id: ReservationId,
+        override_policy: bool,
+    ) -> Result<(abi::Reservation, Option<Money>), Error>;
     /// get reservation by id
     async fn get(&self, id: ReservationId) -> Result<abi::Reservation, Error>;
     /// query reservations
@@ -52,4 +194,158 @@ pub trait Rsvp {
         &self,
         filter: abi::ReservationFilter,
     ) -> Result<(FilterPager, Vec<abi::Reservation>), abi::Error>;
+    /// free windows of the resource within the given window
+    async fn availability(
+        &self,
+        rid: ResourceId,
+        window: abi::TimeWindow,
+    ) -> Result<Vec<abi::TimeWindow>, Error>;
+    /// reservations of the tenant as they are created, changed or deleted from now on, a deleted
+    /// one is received as it was before
+    async fn listen(&self) -> Result<mpsc::Receiver<Result<abi::Reservation, Error>>, Error>;
+}
+
+#[async_trait]
+pub trait ResourceRegistry {
+    /// register or update a resource
+    async fn save_resource(&self, resource: Resource) -> Result<Resource, Error>;
+    /// get resource by id
+    async fn get_resource(&self, id: &str) -> Result<Resource, Error>;
+}
+
+#[async_trait]
+pub trait Waitlist {
+    /// wait for a window, the entry is converted into a pending reservation once it is free
+    async fn join_waitlist(&self, entry: WaitlistEntry) -> Result<WaitlistEntry, Error>;
+    /// leave the waitlist, a reservation already made for the entry is kept
+    async fn leave_waitlist(&self, id: i64) -> Result<WaitlistEntry, Error>;
+    /// waitlist entries in the order they are served, empty ids match everything
+    async fn list_waitlist(
+        &self,
+        rid: ResourceId,
+        uid: UserId,
+    ) -> Result<Vec<WaitlistEntry>, Error>;
+}
+
+#[async_trait]
+pub trait Attendees {
+    /// invite a user or external email to a reservation
+    async fn add_attendee(&self, attendee: Attendee) -> Result<Attendee, Error>;
+    /// remove an attendee from its reservation
+    async fn remove_attendee(&self, id: i64) -> Result<Attendee, Error>;
+    /// answer an invitation as the attendee, or as the reservation owner for an external attendee
+    async fn respond(
+        &self,
+        id: i64,
+        uid: UserId,
+        response: AttendeeResponse,
+    ) -> Result<Attendee, Error>;
+    /// attendees of the reservation, ordered by id
+    async fn list_attendees(&self, rid: ReservationId) -> Result<Vec<Attendee>, Error>;
+}
+
+#[async_trait]
+pub trait Approvals {
+    /// confirm a reservation awaiting approval, only the resource's approvers may do so
+    async fn approve(&self, id: ReservationId, uid: UserId) -> Result<abi::Reservation, Error>;
+    /// reject a reservation awaiting approval, which releases its window
+    async fn reject(&self, id: ReservationId, uid: UserId) -> Result<abi::Reservation, Error>;
+    /// reservations awaiting the user's approval, earliest first
+    async fn approval_inbox(&self, uid: UserId) -> Result<Vec<abi::Reservation>, Error>;
+}
+
+#[async_trait]
+pub trait Overbooking {
+    /// overbooked reservations which have not ended yet, grouped into overlapping windows per
+    /// resource type, an empty type matches every type
+    async fn overbooked_windows(&self, resource_type: &str)
+    -> Result<Vec<OverbookedWindow>, Error>;
+}
+
+#[async_trait]
+pub trait Reporting {
+    /// booked time, status counts, peak hours and no-show rate of resources in a period,
+    /// bucketed in the time zone of the request
+    async fn utilization(
+        &self,
+        request: UtilizationRequest,
+    ) -> Result<Vec<ResourceUtilization>, Error>;
+
+    /// occupancy state of resources per fixed-size bucket of a window
+    async fn occupancy(&self, request: OccupancyRequest) -> Result<Vec<OccupancyTimeline>, Error>;
+
+    /// reservations matching the query or filter, received one by one as they are read
+    async fn export(
+        &self,
+        selection: Selection,
+    ) -> Result<mpsc::Receiver<Result<abi::Reservation, Error>>, Error>;
+}
+
+#[async_trait]
+pub trait Imports {
+    /// reserve the rows received until the channel closes like new reservations. Invalid and
+    /// conflicting rows are reported, in all-or-nothing mode they leave the import uncommitted
+    async fn import(
+        &self,
+        rows: mpsc::Receiver<Result<ImportRow, Error>>,
+        mode: ImportMode,
+    ) -> Result<ImportResponse, Error>;
+}
+
+#[async_trait]
+pub trait Pools {
+    /// register or replace a pool
+    async fn save_pool(&self, pool: Pool) -> Result<Pool, Error>;
+    /// get pool by id
+    async fn get_pool(&self, id: &str) -> Result<Pool, Error>;
+    /// reserve the free member of the pool its strategy picks, the resource id of the
+    /// reservation is ignored
+    async fn reserve_from_pool(
+        &self,
+        pool_id: &str,
+        rsvp: abi::Reservation,
+    ) -> Result<abi::Reservation, Error>;
+}
+
+#[async_trait]
+pub trait Pricing {
+    /// replace the rate plan of a registered resource
+    async fn save_rate_plan(&self, plan: RatePlan) -> Result<RatePlan, Error>;
+    /// rate plan of the resource, without rates if it has none
+    async fn get_rate_plan(&self, rid: &str) -> Result<RatePlan, Error>;
+    /// the price the reservation would get if it was made now
+    async fn quote(&self, rsvp: abi::Reservation) -> Result<Quote, Error>;
+    /// change the settings of the tenant
+    async fn save_tenant_settings(&self, settings: TenantSettings)
+    -> Result<TenantSettings, Error>;
+    /// settings of the tenant, defaults if never saved
+    async fn get_tenant_settings(&self) -> Result<TenantSettings, Error>;
+}
+
+#[async_trait]
+pub trait Policies {
+    /// add or replace the tenant's booking policy of its resource type, an empty type means
+    /// "default"
+    async fn set_policy(&self, policy: BookingPolicy) -> Result<BookingPolicy, Error>;
+    /// booking policies in effect for the tenant, its own and the configured ones it didn't
+    /// replace, ordered by resource type
+    async fn list_policies(&self) -> Result<Vec<BookingPolicy>, Error>;
+}
+
+#[async_trait]
+pub trait Webhooks {
+    /// register a subscription, or update it if it has an id
+    async fn save_webhook(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> Result<WebhookSubscription, Error>;
+    /// delete a subscription with its undelivered changes
+    async fn delete_webhook(&self, id: i64) -> Result<WebhookSubscription, Error>;
+    /// subscriptions of the tenant, without their secrets
+    async fn list_webhooks(&self) -> Result<Vec<WebhookSubscription>, Error>;
+    /// changes which could not be delivered, of one subscription or of all if 0
+    async fn list_dead_letters(
+        &self,
+        subscription_id: i64,
+    ) -> Result<Vec<WebhookDeadLetter>, Error>;
 }
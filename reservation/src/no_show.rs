@@ -0,0 +1,80 @@
+use abi::{Error, NoShowConfig, Reservation, convert_to_interval};
+
+use crate::ReservationManager;
+
+impl ReservationManager {
+    /// mark confirmed reservations of every tenant as no_show if nobody checked in within the
+    /// grace period. With release, the rest of their window is freed so others can book it
+    pub async fn mark_no_shows(&self, config: &NoShowConfig) -> Result<Vec<Reservation>, Error> {
+        let mut tx = self.begin_system().await?;
+        let rsvps = sqlx::query_as(
+            r#"UPDATE rsvp.reservations SET status = 'no_show',
+            timespan = CASE WHEN $2 AND upper(timespan) > now()
+                THEN tstzrange(lower(timespan), now()) ELSE timespan END
+            WHERE status = 'confirmed' AND checked_in_at IS NULL AND lower(timespan) + $1 < now()
+            RETURNING *"#,
+        )
+        .bind(convert_to_interval(config.grace_period_secs))
+        .bind(config.release)
+        .fetch_all(&mut *tx)
+        .await?;
+        self.commit(tx).await?;
+        Ok(rsvps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{ReservationStatus, convert_to_timestamp};
+    use chrono::{Duration, Utc};
+
+    use super::*;
+    use crate::Rsvp;
+    use crate::test_util::get_tdb;
+
+    #[tokio::test]
+    async fn mark_no_shows_should_skip_checked_in_and_release_window() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let now = Utc::now();
+        let make = |rid: &str| {
+            let mut rsvp = Reservation::new_pending(
+                "silwingsId",
+                rid,
+                now.fixed_offset(),
+                now.fixed_offset(),
+                "",
+            );
+            rsvp.start = Some(convert_to_timestamp(now - Duration::hours(1)));
+            rsvp.end = Some(convert_to_timestamp(now + Duration::hours(2)));
+            rsvp
+        };
+
+        let absent = manager.reserve(make("desk-1")).await.unwrap();
+        manager.change_status(absent.id).await.unwrap();
+        let present = manager
+            .with_tenant("lab-a")
+            .reserve(make("desk-2"))
+            .await
+            .unwrap();
+        let lab = manager.with_tenant("lab-a");
+        lab.change_status(present.id).await.unwrap();
+        lab.check_in(present.id).await.unwrap();
+
+        let config = NoShowConfig {
+            release: true,
+            ..Default::default()
+        };
+        let marked = manager.mark_no_shows(&config).await.unwrap();
+        assert_eq!(marked.len(), 1);
+        assert_eq!(marked[0].id, absent.id);
+        assert_eq!(marked[0].status, ReservationStatus::NoShow as i32);
+        assert!(marked[0].bounds().1 <= Utc::now());
+
+        // the released rest of the window can be booked again
+        let mut rest = make("desk-1");
+        rest.start = Some(convert_to_timestamp(Utc::now() + Duration::minutes(1)));
+        manager.reserve(rest).await.unwrap();
+        assert!(manager.mark_no_shows(&config).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,294 @@
+use abi::{Error, NotificationConfig, convert_to_interval};
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+use crate::ReservationManager;
+
+/// how far ahead notifications are computed and persisted
+const SCHEDULE_HORIZON_SECS: u64 = 86400;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "rsvp.notification_kind", rename_all = "snake_case")]
+pub enum NotificationKind {
+    Unknown,
+    /// the reservation starts soon
+    Reminder,
+    /// the reservation is still pending and must be confirmed before it starts
+    HoldExpiring,
+}
+
+/// a notification due to the current owner of a reservation, claimed by the scheduler
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct Notification {
+    pub id: i64,
+    pub tenant_id: String,
+    pub reservation_id: i64,
+    pub user_id: String,
+    pub resource_id: String,
+    pub kind: NotificationKind,
+    pub starts_at: DateTime<Utc>,
+    /// including the one being made
+    pub attempts: i32,
+}
+
+impl NotificationKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NotificationKind::Unknown => "unknown",
+            NotificationKind::Reminder => "reminder",
+            NotificationKind::HoldExpiring => "hold_expiring",
+        }
+    }
+}
+
+impl Notification {
+    pub fn subject(&self) -> String {
+        match self.kind {
+            NotificationKind::HoldExpiring => {
+                format!("Please confirm your reservation of {}", self.resource_id)
+            }
+            _ => format!("Your reservation of {} starts soon", self.resource_id),
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self.kind {
+            NotificationKind::HoldExpiring => format!(
+                "Reservation {} of {} starting at {} is still pending. Confirm it before it starts.",
+                self.reservation_id, self.resource_id, self.starts_at
+            ),
+            _ => format!(
+                "Reservation {} of {} starts at {}.",
+                self.reservation_id, self.resource_id, self.starts_at
+            ),
+        }
+    }
+}
+
+impl ReservationManager {
+    /// persist the notifications of every tenant falling due within the next day. Reservations
+    /// made shortly before their start get notified right away. Returns how many were added
+    pub async fn schedule_notifications(&self, config: &NotificationConfig) -> Result<u64, Error> {
+        let mut tx = self.begin_system().await?;
+        let scheduled = sqlx::query(
+            r#"INSERT INTO rsvp.notifications (tenant_id, reservation_id, kind, starts_at, due_at, next_attempt_at)
+            SELECT r.tenant_id, r.id, k.kind, lower(r.timespan), lower(r.timespan) - k.lead,
+                greatest(lower(r.timespan) - k.lead, now())
+            FROM rsvp.reservations r
+            JOIN (VALUES ('reminder'::rsvp.notification_kind, $1::interval, $2),
+                ('hold_expiring'::rsvp.notification_kind, $3::interval, $4)) AS k(kind, lead, enabled)
+                ON k.enabled
+            WHERE lower(r.timespan) > now() AND lower(r.timespan) - k.lead <= now() + $5
+                AND (r.status = 'pending' OR (k.kind = 'reminder' AND r.status IN ('confirmed', 'awaiting_approval')))
+            ON CONFLICT DO NOTHING"#,
+        )
+        .bind(convert_to_interval(config.reminder_secs))
+        .bind(config.reminder_secs > 0)
+        .bind(convert_to_interval(config.hold_notice_secs))
+        .bind(config.hold_notice_secs > 0)
+        .bind(convert_to_interval(SCHEDULE_HORIZON_SECS))
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        tx.commit().await?;
+        Ok(scheduled)
+    }
+
+    /// claim at most `limit` due notifications, the longest due first, whose reservation still
+    /// starts when it was computed for and still qualifies, e.g. holds which were not confirmed
+    /// meanwhile. A claimed notification is not due again until the lease is over, concurrent
+    /// schedulers skip the ones being claimed
+    pub async fn claim_notifications(
+        &self,
+        lease_secs: u64,
+        limit: u32,
+    ) -> Result<Vec<Notification>, Error> {
+        let mut tx = self.begin_system().await?;
+        let notifications = sqlx::query_as(
+            r#"UPDATE rsvp.notifications n SET attempts = n.attempts + 1, next_attempt_at = now() + $1
+            FROM rsvp.reservations r
+            WHERE r.id = n.reservation_id AND n.id IN (
+                SELECT d.id FROM rsvp.notifications d
+                JOIN rsvp.reservations dr ON dr.id = d.reservation_id
+                WHERE d.sent_at IS NULL AND d.failed_at IS NULL AND d.next_attempt_at <= now()
+                    AND lower(dr.timespan) = d.starts_at AND d.starts_at > now()
+                    AND (dr.status = 'pending' OR (d.kind = 'reminder' AND dr.status IN ('confirmed', 'awaiting_approval')))
+                ORDER BY d.next_attempt_at
+                LIMIT $2
+                FOR UPDATE OF d SKIP LOCKED)
+            RETURNING n.id, n.tenant_id, n.reservation_id, r.user_id, r.resource_id, n.kind,
+                n.starts_at, n.attempts"#,
+        )
+        .bind(convert_to_interval(lease_secs))
+        .bind(i64::from(limit))
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(notifications)
+    }
+
+    pub async fn complete_notification(&self, id: i64) -> Result<(), Error> {
+        let mut tx = self.begin_system().await?;
+        sqlx::query("UPDATE rsvp.notifications SET sent_at = now() WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// retry the notification later, or give it up once it ran out of attempts. Returns whether
+    /// it was given up
+    pub async fn fail_notification(
+        &self,
+        notification: &Notification,
+        error: &str,
+        config: &NotificationConfig,
+    ) -> Result<bool, Error> {
+        let failed = notification.attempts.max(0) as u32 >= config.max_attempts;
+        let mut tx = self.begin_system().await?;
+        sqlx::query(
+            r#"UPDATE rsvp.notifications SET last_error = $2, next_attempt_at = now() + $3,
+            failed_at = CASE WHEN $4 THEN now() END WHERE id = $1"#,
+        )
+        .bind(notification.id)
+        .bind(error)
+        .bind(convert_to_interval(config.retry_secs))
+        .bind(failed)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{Reservation, TimeWindow};
+    use chrono::Duration;
+
+    use super::*;
+    use crate::Rsvp;
+    use crate::test_util::get_tdb;
+
+    #[tokio::test]
+    async fn notifications_should_follow_the_reservation() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await).with_tenant("hotel-a");
+        let start = Utc::now() + Duration::minutes(30);
+        let soon = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "ocean-view-room-713",
+                start.fixed_offset(),
+                (start + Duration::hours(1)).fixed_offset(),
+                "",
+            ))
+            .await
+            .unwrap();
+        let later = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "ocean-view-room-714",
+                (start + Duration::hours(2)).fixed_offset(),
+                (start + Duration::hours(3)).fixed_offset(),
+                "",
+            ))
+            .await
+            .unwrap();
+        manager.change_status(later.id).await.unwrap();
+        let config = NotificationConfig::default();
+
+        // the pending one gets both, the confirmed one only its reminder, all within the day
+        assert_eq!(manager.schedule_notifications(&config).await.unwrap(), 3);
+        assert_eq!(manager.schedule_notifications(&config).await.unwrap(), 0);
+        // a run claims no more than its batch
+        let mut claimed = manager.claim_notifications(60, 1).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        claimed.extend(manager.claim_notifications(60, 100).await.unwrap());
+        let mut claimed = claimed
+            .into_iter()
+            .map(|n| (n.reservation_id, n.kind))
+            .collect::<Vec<_>>();
+        claimed.sort_by_key(|(id, kind)| (*id, *kind as i32));
+        assert_eq!(
+            claimed,
+            [
+                (soon.id, NotificationKind::Reminder),
+                (soon.id, NotificationKind::HoldExpiring)
+            ]
+        );
+        assert!(
+            manager
+                .claim_notifications(60, 100)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // a rescheduled reservation is notified again for its new start
+        let moved = start + Duration::minutes(10);
+        manager
+            .reschedule(soon.id, TimeWindow::new(moved, moved + Duration::hours(1)))
+            .await
+            .unwrap();
+        // the unsent notifications of the old start are dropped
+        let mut tx = manager.begin_system().await.unwrap();
+        let (left,): (i64,) =
+            sqlx::query_as("SELECT count(*) FROM rsvp.notifications WHERE reservation_id = $1")
+                .bind(soon.id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+        tx.rollback().await.unwrap();
+        assert_eq!(left, 0);
+        manager.change_status(soon.id).await.unwrap();
+        assert_eq!(manager.schedule_notifications(&config).await.unwrap(), 1);
+        let claimed = manager.claim_notifications(60, 100).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].kind, NotificationKind::Reminder);
+        assert_eq!(claimed[0].starts_at.timestamp(), moved.timestamp());
+        manager.complete_notification(claimed[0].id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_notifications_should_be_retried_then_given_up() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let start = Utc::now() + Duration::minutes(30);
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "ocean-view-room-713",
+                start.fixed_offset(),
+                (start + Duration::hours(1)).fixed_offset(),
+                "",
+            ))
+            .await
+            .unwrap();
+        manager.change_status(rsvp.id).await.unwrap();
+        let config = NotificationConfig {
+            max_attempts: 2,
+            retry_secs: 0,
+            ..Default::default()
+        };
+        manager.schedule_notifications(&config).await.unwrap();
+
+        for given_up in [false, true] {
+            let claimed = manager.claim_notifications(0, 100).await.unwrap();
+            assert_eq!(claimed.len(), 1);
+            let failed = manager
+                .fail_notification(&claimed[0], "mail server down", &config)
+                .await
+                .unwrap();
+            assert_eq!(failed, given_up);
+        }
+        assert!(
+            manager
+                .claim_notifications(0, 100)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+
+use abi::{
+    DEFAULT_RESOURCE_TYPE, Error, OverbookedWindow, ReservationStatus, Resource, TimeWindow,
+};
+use async_trait::async_trait;
+use chrono::Duration;
+use sqlx::{Acquire, FromRow, Postgres, Row, Transaction, postgres::types::PgRange};
+
+use crate::{Overbooking, ReservationManager};
+
+#[async_trait]
+impl Overbooking for ReservationManager {
+    async fn overbooked_windows(
+        &self,
+        resource_type: &str,
+    ) -> Result<Vec<OverbookedWindow>, Error> {
+        let mut tx = self.begin().await?;
+        let rows = sqlx::query(
+            r#"SELECT r.*, COALESCE(s.resource_type, $2) AS resource_type FROM rsvp.reservations r
+            LEFT JOIN rsvp.resources s ON s.tenant_id = r.tenant_id AND s.id = r.resource_id
+            WHERE r.tenant_id = $1 AND r.overbooked AND r.status = 'pending' AND upper(r.timespan) > now()
+            AND ($3 = '' OR COALESCE(s.resource_type, $2) = $3)
+            ORDER BY 2, lower(r.timespan), r.id"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(DEFAULT_RESOURCE_TYPE)
+        .bind(resource_type)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        // rows are ordered by type and start, so a reservation either extends the last window
+        // or starts a new one
+        let mut groups: Vec<(String, TimeWindow, Vec<abi::Reservation>)> = Vec::new();
+        for row in rows {
+            let resource_type: String = row.get("resource_type");
+            let rsvp = abi::Reservation::from_row(&row)?;
+            let (start, end) = rsvp.bounds();
+            match groups.last_mut() {
+                Some((last_type, window, rsvps))
+                    if *last_type == resource_type && window.bounds().1 > start =>
+                {
+                    let (first, last) = window.bounds();
+                    *window = TimeWindow::new(first, last.max(end));
+                    rsvps.push(rsvp);
+                }
+                _ => groups.push((resource_type, TimeWindow::new(start, end), vec![rsvp])),
+            }
+        }
+
+        let mut capacities = HashMap::new();
+        let mut windows = Vec::with_capacity(groups.len());
+        for (resource_type, window, reservations) in groups {
+            if !capacities.contains_key(&resource_type) {
+                let capacity = self.capacity(&mut tx, &resource_type).await?;
+                capacities.insert(resource_type.clone(), capacity);
+            }
+            windows.push(OverbookedWindow {
+                capacity: capacities[&resource_type],
+                resource_type,
+                window: Some(window),
+                reservations,
+            });
+        }
+        tx.commit().await?;
+        Ok(windows)
+    }
+}
+
+impl ReservationManager {
+    /// insert the reservation, a pending one conflicting with another may still be accepted as
+    /// overbooked if the policy of the resource type allows it
+    pub(crate) async fn insert_or_overbook(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+        mut rsvp: abi::Reservation,
+    ) -> Result<abi::Reservation, Error> {
+        let resource_type = resource.resource_type();
+        let policy = self.policy(tx, resource_type).await?;
+        let overbookable = policy
+            .as_ref()
+            .is_some_and(|policy| policy.overbooking_percent.unwrap_or_default() > 0);
+        if !overbookable
+            || resource.requires_approval
+            || rsvp.status() != ReservationStatus::Pending
+        {
+            return self.insert_reservation(tx, resource, rsvp).await;
+        }
+
+        // the failed insert must not abort the whole transaction
+        let mut savepoint = tx.begin().await?;
+        let conflict = match self
+            .insert_reservation(&mut savepoint, resource, rsvp.clone())
+            .await
+        {
+            Ok(rsvp) => {
+                savepoint.commit().await?;
+                return Ok(rsvp);
+            }
+            Err(Error::ConflictReservation(info)) => info,
+            Err(e) => return Err(e),
+        };
+        savepoint.rollback().await?;
+
+        // serialize overbooking of the same resource type, so concurrent requests can't exceed
+        // the allowance
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1 || '/overbooking/' || $2))")
+            .bind(&self.tenant_id)
+            .bind(resource_type)
+            .execute(&mut **tx)
+            .await?;
+        let capacity = self.capacity(tx, resource_type).await?;
+        let allowance = policy
+            .map(|policy| policy.overbooking_allowance(capacity))
+            .unwrap_or_default();
+        // turnaround buffers count, like they do for the conflicts the allowance makes up for
+        let (start, end) = rsvp.bounds();
+        let pre = Duration::seconds(resource.pre_buffer_secs as i64);
+        let post = Duration::seconds(resource.post_buffer_secs as i64);
+        let overbooked: i64 = sqlx::query_scalar(
+            r#"SELECT count(*) FROM rsvp.reservations r
+            LEFT JOIN rsvp.resources s ON s.tenant_id = r.tenant_id AND s.id = r.resource_id
+            WHERE r.tenant_id = $1 AND COALESCE(s.resource_type, $2) = $3
+            AND r.overbooked AND r.status = 'pending' AND r.blocked && $4"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(DEFAULT_RESOURCE_TYPE)
+        .bind(resource_type)
+        .bind(PgRange::from((start - pre)..(end + post)))
+        .fetch_one(&mut **tx)
+        .await?;
+        if overbooked >= allowance {
+            return Err(Error::ConflictReservation(conflict));
+        }
+
+        rsvp.overbooked = true;
+        self.insert_reservation(tx, resource, rsvp).await
+    }
+
+    /// the number of registered resources of the type, an unregistered resource counts as one
+    async fn capacity(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource_type: &str,
+    ) -> Result<i64, Error> {
+        let capacity: i64 = sqlx::query_scalar(
+            "SELECT count(*) FROM rsvp.resources WHERE tenant_id = $1 AND resource_type = $2",
+        )
+        .bind(&self.tenant_id)
+        .bind(resource_type)
+        .fetch_one(&mut **tx)
+        .await?;
+        Ok(capacity.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{BookingPolicy, Reservation};
+
+    use super::*;
+    use crate::test_util::get_tdb;
+    use crate::{ResourceRegistry, Rsvp};
+
+    #[tokio::test]
+    async fn pending_reservations_should_overbook_up_to_the_allowance() {
+        let tdb = get_tdb();
+        let manager =
+            ReservationManager::new(tdb.get_pool().await).with_policies([BookingPolicy {
+                resource_type: "hotel-room".into(),
+                overbooking_percent: Some(50),
+                ..Default::default()
+            }]);
+        for rid in ["room-101", "room-102"] {
+            manager
+                .save_resource(Resource::new(rid, "hotel-room"))
+                .await
+                .unwrap();
+        }
+        let booking = |uid: &str, start: &str, end: &str| {
+            Reservation::new_pending(
+                uid,
+                "room-101",
+                start.parse().unwrap(),
+                end.parse().unwrap(),
+                "",
+            )
+        };
+
+        let held = manager
+            .reserve(booking(
+                "silwingsId",
+                "2030-12-25T10:00:00+0000",
+                "2030-12-27T10:00:00+0000",
+            ))
+            .await
+            .unwrap();
+        assert!(!held.overbooked);
+        let overbooked = manager
+            .reserve(booking(
+                "aliceId",
+                "2030-12-26T10:00:00+0000",
+                "2030-12-28T10:00:00+0000",
+            ))
+            .await
+            .unwrap();
+        assert!(overbooked.overbooked);
+        // the allowance of two rooms at 50% is used up
+        let err = manager
+            .reserve(booking(
+                "bobId",
+                "2030-12-25T10:00:00+0000",
+                "2030-12-26T12:00:00+0000",
+            ))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::ConflictReservation(_)));
+
+        let windows = manager.overbooked_windows("hotel-room").await.unwrap();
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].capacity, 2);
+        assert_eq!(windows[0].reservations[0].id, overbooked.id);
+        assert!(manager.overbooked_windows("desk").await.unwrap().is_empty());
+
+        let err = manager.change_status(overbooked.id).await.unwrap_err();
+        assert!(matches!(err, Error::OverCapacity(_)));
+        manager.delete(held.id).await.unwrap();
+        let confirmed = manager.change_status(overbooked.id).await.unwrap();
+        assert_eq!(confirmed.status(), ReservationStatus::Confirmed);
+        assert!(!confirmed.overbooked);
+        assert!(manager.overbooked_windows("").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn overbooking_allowance_should_count_turnaround_buffers() {
+        let tdb = get_tdb();
+        let manager =
+            ReservationManager::new(tdb.get_pool().await).with_policies([BookingPolicy {
+                resource_type: "hotel-room".into(),
+                overbooking_percent: Some(50),
+                ..Default::default()
+            }]);
+        for rid in ["room-101", "room-102"] {
+            manager
+                .save_resource(Resource {
+                    post_buffer_secs: 3600,
+                    ..Resource::new(rid, "hotel-room")
+                })
+                .await
+                .unwrap();
+        }
+        let booking = |uid: &str, start: &str, end: &str| {
+            Reservation::new_pending(
+                uid,
+                "room-101",
+                start.parse().unwrap(),
+                end.parse().unwrap(),
+                "",
+            )
+        };
+
+        manager
+            .reserve(booking(
+                "silwingsId",
+                "2030-12-25T10:00:00+0000",
+                "2030-12-25T16:00:00+0000",
+            ))
+            .await
+            .unwrap();
+        let overbooked = manager
+            .reserve(booking(
+                "aliceId",
+                "2030-12-25T11:00:00+0000",
+                "2030-12-25T13:00:00+0000",
+            ))
+            .await
+            .unwrap();
+        assert!(overbooked.overbooked);
+        // it only overlaps the turnaround after the overbooked stay, which still uses up the
+        // allowance
+        let err = manager
+            .reserve(booking(
+                "bobId",
+                "2030-12-25T13:30:00+0000",
+                "2030-12-25T15:00:00+0000",
+            ))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::ConflictReservation(_)));
+    }
+
+    #[tokio::test]
+    async fn reservations_without_overbooking_policy_should_conflict() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let booking = |uid: &str| {
+            Reservation::new_pending(
+                uid,
+                "room-101",
+                "2030-12-25T10:00:00+0000".parse().unwrap(),
+                "2030-12-26T10:00:00+0000".parse().unwrap(),
+                "",
+            )
+        };
+        manager.reserve(booking("silwingsId")).await.unwrap();
+        let err = manager.reserve(booking("aliceId")).await.unwrap_err();
+        assert!(matches!(err, Error::ConflictReservation(_)));
+        // the caller can't skip the conflict check by asking for an overbooked reservation
+        let overbooked = Reservation {
+            overbooked: true,
+            ..booking("aliceId")
+        };
+        let err = manager.reserve(overbooked.clone()).await.unwrap_err();
+        assert!(matches!(err, Error::ConflictReservation(_)));
+        let err = manager.preempt(overbooked).await.unwrap_err();
+        assert!(matches!(err, Error::ConflictReservation(_)));
+    }
+}
@@ -0,0 +1,207 @@
+use std::{collections::HashMap, sync::Arc};
+
+use abi::{BookingPolicy, DEFAULT_RESOURCE_TYPE, Error, Resource};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Row, Transaction};
+
+use crate::{Policies, ReservationManager};
+
+/// booking policies by resource type loaded from config. They apply to every tenant which didn't
+/// set its own for the resource type, shared by all managers cloned from the same one
+#[derive(Debug, Clone, Default)]
+pub struct PolicyStore {
+    defaults: Arc<HashMap<String, BookingPolicy>>,
+}
+
+impl PolicyStore {
+    pub fn new(policies: impl IntoIterator<Item = BookingPolicy>) -> Self {
+        let defaults = policies
+            .into_iter()
+            .map(normalize)
+            .map(|policy| (policy.resource_type.clone(), policy))
+            .collect();
+        Self {
+            defaults: Arc::new(defaults),
+        }
+    }
+
+    /// configured policy of the resource type, if any
+    pub fn get(&self, resource_type: &str) -> Option<BookingPolicy> {
+        self.defaults.get(resource_type).cloned()
+    }
+}
+
+fn normalize(mut policy: BookingPolicy) -> BookingPolicy {
+    if policy.resource_type.is_empty() {
+        policy.resource_type = DEFAULT_RESOURCE_TYPE.to_string();
+    }
+    policy
+}
+
+#[async_trait]
+impl Policies for ReservationManager {
+    async fn set_policy(&self, policy: BookingPolicy) -> Result<BookingPolicy, Error> {
+        let policy = normalize(policy);
+        let mut tx = self.begin().await?;
+        let policy = sqlx::query_as(
+            r#"INSERT INTO rsvp.booking_policies (tenant_id, resource_type, max_active_reservations, max_duration_secs, max_advance_secs, min_lead_time_secs, overbooking_percent)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (tenant_id, resource_type) DO UPDATE SET
+                max_active_reservations = EXCLUDED.max_active_reservations,
+                max_duration_secs = EXCLUDED.max_duration_secs,
+                max_advance_secs = EXCLUDED.max_advance_secs,
+                min_lead_time_secs = EXCLUDED.min_lead_time_secs,
+                overbooking_percent = EXCLUDED.overbooking_percent
+            RETURNING *"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(&policy.resource_type)
+        .bind(policy.max_active_reservations.map(|limit| limit as i32))
+        .bind(policy.max_duration_secs.map(|limit| limit as i64))
+        .bind(policy.max_advance_secs.map(|limit| limit as i64))
+        .bind(policy.min_lead_time_secs.map(|limit| limit as i64))
+        .bind(policy.overbooking_percent.map(|percent| percent as i32))
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(policy)
+    }
+
+    async fn list_policies(&self) -> Result<Vec<BookingPolicy>, Error> {
+        let mut tx = self.begin().await?;
+        let own: Vec<BookingPolicy> =
+            sqlx::query_as("SELECT * FROM rsvp.booking_policies WHERE tenant_id = $1")
+                .bind(&self.tenant_id)
+                .fetch_all(&mut *tx)
+                .await?;
+        tx.commit().await?;
+
+        let mut policies = (*self.policies.defaults).clone();
+        for policy in own {
+            policies.insert(policy.resource_type.clone(), policy);
+        }
+        let mut policies = policies.into_values().collect::<Vec<_>>();
+        policies.sort_by(|a, b| a.resource_type.cmp(&b.resource_type));
+        Ok(policies)
+    }
+}
+
+impl ReservationManager {
+    /// the tenant's booking policy of the resource type, or the configured one if it has none
+    pub(crate) async fn policy(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource_type: &str,
+    ) -> Result<Option<BookingPolicy>, Error> {
+        let policy = sqlx::query_as(
+            "SELECT * FROM rsvp.booking_policies WHERE tenant_id = $1 AND resource_type = $2",
+        )
+        .bind(&self.tenant_id)
+        .bind(resource_type)
+        .fetch_optional(&mut **tx)
+        .await?;
+        Ok(policy.or_else(|| self.policies.get(resource_type)))
+    }
+
+    /// rules every reservation window must satisfy: opening hours, slot rules and the
+    /// duration, advance and lead time limits of the booking policy
+    pub(crate) async fn check_window(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        resource.check_opening_hours(start, end)?;
+        resource.check_slots(start, end)?;
+        if let Some(policy) = self.policy(tx, resource.resource_type()).await? {
+            policy
+                .check_window(Utc::now(), start, end)
+                .map_err(Error::PolicyViolation)?;
+        }
+        Ok(())
+    }
+
+    /// rules a window split from or merged of existing reservations must satisfy: opening hours,
+    /// slot rules and the duration limit. The advance and lead time limits were met when the
+    /// reservations were made, which may since have started
+    pub(crate) async fn check_reshape(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        resource.check_opening_hours(start, end)?;
+        resource.check_slots(start, end)?;
+        if let Some(policy) = self.policy(tx, resource.resource_type()).await? {
+            policy
+                .check_duration(start, end)
+                .map_err(Error::PolicyViolation)?;
+        }
+        Ok(())
+    }
+
+    /// rules extending a reservation from `current_end` to `end` must satisfy. Only the added
+    /// time has to be open and fit the slots, the lead time and advance limits were met when the
+    /// reservation was made, which may since have started. The duration limit still applies to
+    /// the whole reservation, so it can't grow past it by repeated extensions
+    pub(crate) async fn check_extension(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+        start: DateTime<Utc>,
+        current_end: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        resource.check_opening_hours(current_end, end)?;
+        resource.check_slots(current_end, end)?;
+        if let Some(policy) = self.policy(tx, resource.resource_type()).await? {
+            policy
+                .check_duration(start, end)
+                .map_err(Error::PolicyViolation)?;
+        }
+        Ok(())
+    }
+
+    /// check the user's active reservations against the booking policy within the reserve
+    /// transaction
+    pub(crate) async fn check_active_quota(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+        uid: &str,
+    ) -> Result<(), Error> {
+        let resource_type = resource.resource_type();
+        let Some(policy) = self.policy(tx, resource_type).await? else {
+            return Ok(());
+        };
+        if policy.max_active_reservations.is_none() {
+            return Ok(());
+        }
+
+        // serialize reservations of the same user, so concurrent requests can't exceed the quota
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1 || '/' || $2))")
+            .bind(&self.tenant_id)
+            .bind(uid)
+            .execute(&mut **tx)
+            .await?;
+        let active: i64 = sqlx::query(
+            r#"SELECT count(*) FROM rsvp.reservations r
+            LEFT JOIN rsvp.resources s ON s.tenant_id = r.tenant_id AND s.id = r.resource_id
+            WHERE r.tenant_id = $1 AND r.user_id = $2 AND COALESCE(s.resource_type, $3) = $4
+            AND r.status IN ('pending', 'confirmed', 'awaiting_approval') AND upper(r.timespan) > now()"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(uid)
+        .bind(DEFAULT_RESOURCE_TYPE)
+        .bind(resource_type)
+        .fetch_one(&mut **tx)
+        .await?
+        .get(0);
+        policy
+            .check_active_reservations(active)
+            .map_err(Error::PolicyViolation)
+    }
+}
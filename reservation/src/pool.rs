@@ -0,0 +1,440 @@
+use abi::{Error, Pool, PoolStrategy, RsvpPoolStrategy};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::postgres::types::PgRange;
+use sqlx::{Acquire, Postgres, QueryBuilder, Row, Transaction};
+
+use crate::manager::requested;
+use crate::{Pools, ReservationManager};
+
+/// a member of a pool which is free in the requested window
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub resource_id: String,
+    /// position of the member in the pool definition
+    pub position: i32,
+    /// end of the member's latest reservation which has ended, none if it was never used
+    pub last_used: Option<DateTime<Utc>>,
+    /// free seconds between the previous reservation and the window, none if unbounded
+    pub free_before: Option<i64>,
+    /// free seconds between the window and the next reservation, none if unbounded
+    pub free_after: Option<i64>,
+}
+
+/// decides which free member of a pool a reservation gets
+pub trait AssignmentStrategy: Send + Sync {
+    /// order the candidates by preference, they are tried in this order as a candidate may
+    /// still turn out to be taken, e.g. by a reservation of a parent resource
+    fn rank(&self, candidates: &mut [Candidate]);
+}
+
+/// the first free member in the order of the pool definition
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FirstFit;
+
+/// the free member whose last reservation ended the longest time ago, never used ones first
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LeastRecentlyUsed;
+
+/// the free member with the least free time around the window, so longer gaps stay available
+/// for longer reservations
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BestFit;
+
+impl AssignmentStrategy for FirstFit {
+    fn rank(&self, candidates: &mut [Candidate]) {
+        candidates.sort_by_key(|c| c.position);
+    }
+}
+
+impl AssignmentStrategy for LeastRecentlyUsed {
+    fn rank(&self, candidates: &mut [Candidate]) {
+        // None sorts before Some, so never used members come first
+        candidates.sort_by_key(|c| (c.last_used, c.position));
+    }
+}
+
+impl AssignmentStrategy for BestFit {
+    fn rank(&self, candidates: &mut [Candidate]) {
+        // an unbounded side counts as the longest gap, summed without saturating so a member
+        // bounded on one side still fits better than one free on both
+        let length = |free: Option<i64>| free.unwrap_or(i64::MAX) as i128;
+        candidates.sort_by_key(|c| {
+            let gap = length(c.free_before) + length(c.free_after);
+            (gap, c.position)
+        });
+    }
+}
+
+/// the built in strategy of a pool
+fn strategy_of(pool: &Pool) -> &'static dyn AssignmentStrategy {
+    match pool.strategy() {
+        PoolStrategy::Unknown | PoolStrategy::FirstFit => &FirstFit,
+        PoolStrategy::LeastRecentlyUsed => &LeastRecentlyUsed,
+        PoolStrategy::BestFit => &BestFit,
+    }
+}
+
+#[async_trait]
+impl Pools for ReservationManager {
+    async fn save_pool(&self, mut pool: Pool) -> Result<Pool, Error> {
+        pool.validate()?;
+        pool.tenant_id = self.tenant_id.clone();
+        if pool.strategy() == PoolStrategy::Unknown {
+            pool.strategy = PoolStrategy::FirstFit as i32;
+        }
+
+        let mut tx = self.begin().await?;
+        sqlx::query(
+            "INSERT INTO rsvp.pools (tenant_id, id, strategy) VALUES ($1, $2, $3) ON CONFLICT (tenant_id, id) DO UPDATE SET strategy = EXCLUDED.strategy",
+        )
+        .bind(&pool.tenant_id)
+        .bind(&pool.id)
+        .bind(RsvpPoolStrategy::from(pool.strategy()))
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM rsvp.pool_members WHERE tenant_id = $1 AND pool_id = $2")
+            .bind(&pool.tenant_id)
+            .bind(&pool.id)
+            .execute(&mut *tx)
+            .await?;
+        QueryBuilder::new(
+            "INSERT INTO rsvp.pool_members (tenant_id, pool_id, resource_id, position) ",
+        )
+        .push_values(
+            pool.resource_ids.iter().enumerate(),
+            |mut b, (position, rid)| {
+                b.push_bind(&pool.tenant_id)
+                    .push_bind(&pool.id)
+                    .push_bind(rid)
+                    .push_bind(position as i32);
+            },
+        )
+        .build()
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(pool)
+    }
+
+    async fn get_pool(&self, id: &str) -> Result<Pool, Error> {
+        let mut tx = self.begin().await?;
+        let pool = self.load_pool(&mut tx, id).await?;
+        tx.commit().await?;
+        Ok(pool)
+    }
+
+    async fn reserve_from_pool(
+        &self,
+        pool_id: &str,
+        rsvp: abi::Reservation,
+    ) -> Result<abi::Reservation, Error> {
+        let mut tx = self.begin().await?;
+        let pool = self.load_pool(&mut tx, pool_id).await?;
+        let rsvp = self
+            .assign_from_pool(&mut tx, &pool, rsvp, strategy_of(&pool))
+            .await?;
+        tx.commit().await?;
+        Ok(rsvp)
+    }
+}
+
+impl ReservationManager {
+    /// reserve the free member of the pool the given strategy picks, instead of the pool's own
+    pub async fn reserve_from_pool_with(
+        &self,
+        pool_id: &str,
+        rsvp: abi::Reservation,
+        strategy: &dyn AssignmentStrategy,
+    ) -> Result<abi::Reservation, Error> {
+        let mut tx = self.begin().await?;
+        let pool = self.load_pool(&mut tx, pool_id).await?;
+        let rsvp = self
+            .assign_from_pool(&mut tx, &pool, rsvp, strategy)
+            .await?;
+        tx.commit().await?;
+        Ok(rsvp)
+    }
+
+    async fn load_pool(&self, tx: &mut Transaction<'_, Postgres>, id: &str) -> Result<Pool, Error> {
+        let mut pool: Pool =
+            sqlx::query_as("SELECT * FROM rsvp.pools WHERE tenant_id = $1 AND id = $2")
+                .bind(&self.tenant_id)
+                .bind(id)
+                .fetch_one(&mut **tx)
+                .await?;
+        pool.resource_ids = sqlx::query_scalar(
+            "SELECT resource_id FROM rsvp.pool_members WHERE tenant_id = $1 AND pool_id = $2 ORDER BY position",
+        )
+        .bind(&self.tenant_id)
+        .bind(id)
+        .fetch_all(&mut **tx)
+        .await?;
+        Ok(pool)
+    }
+
+    async fn assign_from_pool(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        pool: &Pool,
+        rsvp: abi::Reservation,
+        strategy: &dyn AssignmentStrategy,
+    ) -> Result<abi::Reservation, Error> {
+        let mut rsvp = requested(rsvp);
+        rsvp.resource_id = pool.id.clone();
+        rsvp.validate()?;
+        let (start, end) = rsvp.bounds();
+
+        // serialize assignments from the same pool, so concurrent requests pick different members
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1 || '/pool/' || $2))")
+            .bind(&self.tenant_id)
+            .bind(&pool.id)
+            .execute(&mut **tx)
+            .await?;
+        let mut candidates = self.candidates(tx, pool, start, end).await?;
+        strategy.rank(&mut candidates);
+
+        for candidate in candidates {
+            rsvp.resource_id = candidate.resource_id;
+            let resource = self.load_resource(tx, &rsvp.resource_id).await?;
+            // a failed attempt must not abort the whole transaction
+            let mut savepoint = tx.begin().await?;
+            match self
+                .insert_reservation(&mut savepoint, &resource, rsvp.clone())
+                .await
+            {
+                Ok(rsvp) => {
+                    savepoint.commit().await?;
+                    return Ok(rsvp);
+                }
+                // the member is taken or closed in the window, try the next one
+                Err(
+                    Error::ConflictReservation(_)
+                    | Error::OutsideOpeningHours(_)
+                    | Error::InvalidSlot(_),
+                ) => savepoint.rollback().await?,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(Error::PoolExhausted(pool.id.clone()))
+    }
+
+    /// members of the pool without an active reservation in the window
+    async fn candidates(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        pool: &Pool,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candidate>, Error> {
+        let rows = sqlx::query(
+            r#"SELECT m.resource_id, m.position,
+            (SELECT max(upper(r.timespan)) FROM rsvp.reservations r
+                WHERE r.tenant_id = m.tenant_id AND r.resource_id = m.resource_id
+                AND r.status NOT IN ('preempted', 'rejected') AND upper(r.timespan) <= now()) AS last_used,
+            (SELECT max(upper(r.blocked)) FROM rsvp.reservations r
+                WHERE r.tenant_id = m.tenant_id AND r.resource_id = m.resource_id
+                AND r.status NOT IN ('preempted', 'rejected') AND NOT r.overbooked
+                AND upper(r.blocked) <= $3) AS previous_end,
+            (SELECT min(lower(r.blocked)) FROM rsvp.reservations r
+                WHERE r.tenant_id = m.tenant_id AND r.resource_id = m.resource_id
+                AND r.status NOT IN ('preempted', 'rejected') AND NOT r.overbooked
+                AND lower(r.blocked) >= $4) AS next_start
+            FROM rsvp.pool_members m
+            WHERE m.tenant_id = $1 AND m.pool_id = $2
+            AND NOT EXISTS (SELECT 1 FROM rsvp.reservations r
+                WHERE r.tenant_id = m.tenant_id AND r.resource_id = m.resource_id
+                AND r.status NOT IN ('preempted', 'rejected') AND NOT r.overbooked
+                AND r.blocked && $5)
+            ORDER BY m.position"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(&pool.id)
+        .bind(start)
+        .bind(end)
+        .bind(PgRange::from(start..end))
+        .fetch_all(&mut **tx)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let previous_end: Option<DateTime<Utc>> = row.get("previous_end");
+                let next_start: Option<DateTime<Utc>> = row.get("next_start");
+                Candidate {
+                    resource_id: row.get("resource_id"),
+                    position: row.get("position"),
+                    last_used: row.get("last_used"),
+                    free_before: previous_end.map(|t| (start - t).num_seconds()),
+                    free_after: next_start.map(|t| (t - end).num_seconds()),
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use abi::Reservation;
+
+    use super::*;
+    use crate::Rsvp;
+    use crate::test_util::get_tdb;
+
+    fn candidate(rid: &str, position: i32) -> Candidate {
+        Candidate {
+            resource_id: rid.into(),
+            position,
+            last_used: None,
+            free_before: None,
+            free_after: None,
+        }
+    }
+
+    fn booking(uid: &str, start: &str, end: &str) -> Reservation {
+        Reservation::new_pending(uid, "", start.parse().unwrap(), end.parse().unwrap(), "")
+    }
+
+    #[test]
+    fn strategies_should_rank_candidates() {
+        let t = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let candidates = vec![
+            Candidate {
+                last_used: Some(t("2025-06-02T00:00:00Z")),
+                free_before: Some(3600),
+                ..candidate("p1", 0)
+            },
+            Candidate {
+                last_used: Some(t("2025-06-01T00:00:00Z")),
+                free_before: Some(600),
+                free_after: Some(600),
+                ..candidate("p2", 1)
+            },
+            candidate("p3", 2),
+        ];
+        let ranked = |strategy: &dyn AssignmentStrategy| {
+            let mut candidates = candidates.clone();
+            strategy.rank(&mut candidates);
+            candidates
+                .into_iter()
+                .map(|c| c.resource_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ranked(&FirstFit), ["p1", "p2", "p3"]);
+        assert_eq!(ranked(&LeastRecentlyUsed), ["p3", "p2", "p1"]);
+        assert_eq!(ranked(&BestFit), ["p2", "p1", "p3"]);
+    }
+
+    #[tokio::test]
+    async fn reserve_from_pool_should_pick_free_members_by_strategy() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let pool = manager
+            .save_pool(Pool::new(
+                "car-park",
+                ["p1", "p2", "p3"],
+                PoolStrategy::BestFit,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(manager.get_pool("car-park").await.unwrap(), pool);
+
+        // p2 is busy right before the window, so it fits best
+        manager
+            .reserve(Reservation::new_pending(
+                "aliceId",
+                "p2",
+                "2030-12-25T08:00:00+0000".parse().unwrap(),
+                "2030-12-25T09:00:00+0000".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+        let rsvp = manager
+            .reserve_from_pool(
+                "car-park",
+                booking(
+                    "silwingsId",
+                    "2030-12-25T09:00:00+0000",
+                    "2030-12-25T10:00:00+0000",
+                ),
+            )
+            .await
+            .unwrap();
+        assert_eq!(rsvp.resource_id, "p2");
+
+        let rsvp = manager
+            .reserve_from_pool_with(
+                "car-park",
+                booking(
+                    "silwingsId",
+                    "2030-12-25T09:00:00+0000",
+                    "2030-12-25T10:00:00+0000",
+                ),
+                &FirstFit,
+            )
+            .await
+            .unwrap();
+        assert_eq!(rsvp.resource_id, "p1");
+
+        let err = manager
+            .reserve_from_pool(
+                "garage",
+                booking(
+                    "silwingsId",
+                    "2030-12-25T09:00:00+0000",
+                    "2030-12-25T10:00:00+0000",
+                ),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err, Error::NotFound);
+    }
+
+    #[tokio::test]
+    async fn concurrent_reserve_from_pool_should_not_double_assign() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        manager
+            .save_pool(Pool::new(
+                "desks",
+                ["desk-1", "desk-2", "desk-3"],
+                PoolStrategy::FirstFit,
+            ))
+            .await
+            .unwrap();
+
+        let tasks = (0..5)
+            .map(|i| {
+                let manager = manager.clone();
+                tokio::spawn(async move {
+                    manager
+                        .reserve_from_pool(
+                            "desks",
+                            booking(
+                                &format!("user-{i}"),
+                                "2030-12-25T09:00:00+0000",
+                                "2030-12-25T17:00:00+0000",
+                            ),
+                        )
+                        .await
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut assigned = HashSet::new();
+        let mut exhausted = 0;
+        for task in tasks {
+            match task.await.unwrap() {
+                Ok(rsvp) => assert!(assigned.insert(rsvp.resource_id)),
+                Err(e) => {
+                    assert_eq!(e, Error::PoolExhausted("desks".into()));
+                    exhausted += 1;
+                }
+            }
+        }
+        assert_eq!(assigned.len(), 3);
+        assert_eq!(exhausted, 2);
+    }
+}
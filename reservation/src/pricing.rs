@@ -0,0 +1,326 @@
+use abi::{
+    DEFAULT_CURRENCY, Error, Money, Quote, Rate, RatePlan, Resource, RsvpRateUnit, TenantSettings,
+    validate_currency,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, QueryBuilder, Transaction};
+
+use crate::{Pricing, ReservationManager};
+
+#[async_trait]
+impl Pricing for ReservationManager {
+    async fn save_rate_plan(&self, mut plan: RatePlan) -> Result<RatePlan, Error> {
+        plan.validate()?;
+        plan.tenant_id = self.tenant_id.clone();
+
+        let mut tx = self.begin().await?;
+        let registered: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM rsvp.resources WHERE tenant_id = $1 AND id = $2)",
+        )
+        .bind(&self.tenant_id)
+        .bind(&plan.resource_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        // rates are matched in the resource's time zone, so it has to be registered
+        if !registered {
+            return Err(Error::InvalidResourceId(plan.resource_id));
+        }
+
+        sqlx::query("DELETE FROM rsvp.rates WHERE tenant_id = $1 AND resource_id = $2")
+            .bind(&self.tenant_id)
+            .bind(&plan.resource_id)
+            .execute(&mut *tx)
+            .await?;
+        if !plan.rates.is_empty() {
+            let rates = plan
+                .rates
+                .iter()
+                .enumerate()
+                .map(|(position, rate)| {
+                    Ok((
+                        position as i32,
+                        rate,
+                        rate.amount.parse::<abi::Decimal>().ok(),
+                        rate.valid_from()?,
+                        rate.valid_until()?,
+                    ))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            QueryBuilder::new(
+                "INSERT INTO rsvp.rates (tenant_id, resource_id, position, name, unit, amount, weekdays, valid_from, valid_until) ",
+            )
+            .push_values(rates, |mut b, (position, rate, amount, from, until)| {
+                b.push_bind(&self.tenant_id)
+                    .push_bind(&plan.resource_id)
+                    .push_bind(position)
+                    .push_bind(&rate.name)
+                    .push_bind(RsvpRateUnit::from(rate.unit()))
+                    .push_bind(amount)
+                    .push_bind(rate.weekdays.iter().map(|day| *day as i16).collect::<Vec<_>>())
+                    .push_bind(from)
+                    .push_bind(until);
+            })
+            .build()
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(plan)
+    }
+
+    async fn get_rate_plan(&self, rid: &str) -> Result<RatePlan, Error> {
+        let mut tx = self.begin().await?;
+        let rates = self.load_rates(&mut tx, rid).await?;
+        tx.commit().await?;
+        Ok(RatePlan {
+            tenant_id: self.tenant_id.clone(),
+            ..RatePlan::new(rid, rates)
+        })
+    }
+
+    async fn quote(&self, rsvp: abi::Reservation) -> Result<Quote, Error> {
+        rsvp.validate()?;
+        let mut tx = self.begin().await?;
+        let resource = self.load_resource(&mut tx, &rsvp.resource_id).await?;
+        let rates = self.load_rates(&mut tx, &resource.id).await?;
+        let currency = self.currency(&mut tx).await?;
+        tx.commit().await?;
+
+        let (start, end) = rsvp.bounds();
+        RatePlan::new(&resource.id, rates).quote(resource.tz()?, start, end, &currency)
+    }
+
+    async fn save_tenant_settings(
+        &self,
+        mut settings: TenantSettings,
+    ) -> Result<TenantSettings, Error> {
+        validate_currency(&settings.currency)?;
+        settings.tenant_id = self.tenant_id.clone();
+
+        let mut tx = self.begin().await?;
+        sqlx::query(
+            "INSERT INTO rsvp.tenant_settings (tenant_id, currency) VALUES ($1, $2) ON CONFLICT (tenant_id) DO UPDATE SET currency = EXCLUDED.currency",
+        )
+        .bind(&settings.tenant_id)
+        .bind(&settings.currency)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(settings)
+    }
+
+    async fn get_tenant_settings(&self) -> Result<TenantSettings, Error> {
+        let mut tx = self.begin().await?;
+        let currency = self.currency(&mut tx).await?;
+        tx.commit().await?;
+        Ok(TenantSettings {
+            tenant_id: self.tenant_id.clone(),
+            currency,
+        })
+    }
+}
+
+impl ReservationManager {
+    /// price of a reservation window by the rate plan of the resource, none if it has no rates
+    pub(crate) async fn price(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Option<Money>, Error> {
+        let rates = self.load_rates(tx, &resource.id).await?;
+        if rates.is_empty() {
+            return Ok(None);
+        }
+        let currency = self.currency(tx).await?;
+        let quote =
+            RatePlan::new(&resource.id, rates).quote(resource.tz()?, start, end, &currency)?;
+        Ok(quote.total)
+    }
+
+    async fn load_rates(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        rid: &str,
+    ) -> Result<Vec<Rate>, Error> {
+        let rates = sqlx::query_as(
+            "SELECT * FROM rsvp.rates WHERE tenant_id = $1 AND resource_id = $2 ORDER BY position",
+        )
+        .bind(&self.tenant_id)
+        .bind(rid)
+        .fetch_all(&mut **tx)
+        .await?;
+        Ok(rates)
+    }
+
+    /// currency of the tenant, prices are always in it
+    async fn currency(&self, tx: &mut Transaction<'_, Postgres>) -> Result<String, Error> {
+        let currency: Option<String> =
+            sqlx::query_scalar("SELECT currency FROM rsvp.tenant_settings WHERE tenant_id = $1")
+                .bind(&self.tenant_id)
+                .fetch_optional(&mut **tx)
+                .await?;
+        Ok(currency.unwrap_or_else(|| DEFAULT_CURRENCY.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{Decimal, RateUnit, Reservation, WaitlistEntry};
+
+    use super::*;
+    use crate::test_util::get_tdb;
+    use crate::{ResourceRegistry, Rsvp, Waitlist};
+
+    #[tokio::test]
+    async fn reservation_should_store_the_quoted_price() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await).with_tenant("hotel-a");
+        manager
+            .save_resource(Resource::new("ocean-view-room-713", "room"))
+            .await
+            .unwrap();
+        manager
+            .save_tenant_settings(TenantSettings {
+                currency: "EUR".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let plan = manager
+            .save_rate_plan(RatePlan::new(
+                "ocean-view-room-713",
+                vec![
+                    Rate::new("hourly", RateUnit::Hour, "12.5"),
+                    Rate {
+                        weekdays: vec![6, 7],
+                        ..Rate::new("weekend", RateUnit::Hour, "15")
+                    },
+                ],
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            manager.get_rate_plan("ocean-view-room-713").await.unwrap(),
+            plan
+        );
+
+        // Friday 23:00 to Saturday 01:00
+        let rsvp = Reservation::new_pending(
+            "silwingsId",
+            "ocean-view-room-713",
+            "2030-12-20T23:00:00+0000".parse().unwrap(),
+            "2030-12-21T01:00:00+0000".parse().unwrap(),
+            "",
+        );
+        let quote = manager.quote(rsvp.clone()).await.unwrap();
+        assert_eq!(
+            quote.total,
+            Some(Money::new("EUR", "27.5".parse().unwrap()))
+        );
+        assert_eq!(quote.lines.len(), 2);
+
+        let rsvp = manager.reserve(rsvp).await.unwrap();
+        assert_eq!(rsvp.price, quote.total);
+        assert_eq!(manager.get(rsvp.id).await.unwrap().price, quote.total);
+    }
+
+    #[tokio::test]
+    async fn promoted_waitlist_entry_should_be_priced_like_a_reservation() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await).with_tenant("hotel-a");
+        manager
+            .save_resource(Resource {
+                time_zone: "Europe/Berlin".into(),
+                ..Resource::new("ocean-view-room-713", "room")
+            })
+            .await
+            .unwrap();
+        manager
+            .save_tenant_settings(TenantSettings {
+                currency: "EUR".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        manager
+            .save_rate_plan(RatePlan::new(
+                "ocean-view-room-713",
+                vec![
+                    Rate::new("hourly", RateUnit::Hour, "12.5"),
+                    Rate::new("daily", RateUnit::Day, "100"),
+                    Rate {
+                        weekdays: vec![6, 7],
+                        ..Rate::new("weekend", RateUnit::Day, "150")
+                    },
+                ],
+            ))
+            .await
+            .unwrap();
+
+        // Friday 23:00 to Monday 00:00 in Berlin, three days of which two fall on the weekend
+        let start = "2030-12-20T22:00:00+0000".parse().unwrap();
+        let end = "2030-12-22T23:00:00+0000".parse().unwrap();
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "ocean-view-room-713",
+                start,
+                end,
+                "",
+            ))
+            .await
+            .unwrap();
+        manager
+            .join_waitlist(WaitlistEntry::new(
+                "aliceId",
+                "ocean-view-room-713",
+                start,
+                end,
+                "",
+            ))
+            .await
+            .unwrap();
+        manager.delete(rsvp.id).await.unwrap();
+
+        let entries = manager
+            .list_waitlist("ocean-view-room-713".into(), "aliceId".into())
+            .await
+            .unwrap();
+        let promoted = manager.get(entries[0].reservation_id).await.unwrap();
+        let quote = manager.quote(promoted.clone()).await.unwrap();
+        assert_eq!(quote.total, Some(Money::new("EUR", Decimal::from(400))));
+        assert_eq!(promoted.price, quote.total);
+    }
+
+    #[tokio::test]
+    async fn resources_without_rate_plan_should_not_be_priced() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "ocean-view-room-713",
+                "2030-12-20T23:00:00+0000".parse().unwrap(),
+                "2030-12-21T01:00:00+0000".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(rsvp.price, None);
+        assert_eq!(
+            manager.get_tenant_settings().await.unwrap().currency,
+            DEFAULT_CURRENCY
+        );
+        let err = manager
+            .save_rate_plan(RatePlan::new(
+                "unregistered",
+                vec![Rate::new("hourly", RateUnit::Hour, "1")],
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(err, Error::InvalidResourceId("unregistered".into()));
+    }
+}
@@ -0,0 +1,572 @@
+use std::collections::HashMap;
+
+use abi::export_request::Selection;
+use abi::{
+    Error, OccupancyRequest, OccupancyState, OccupancyTimeline, ReportBucket, ReservationStatus,
+    ResourceUtilization, RsvpStatus, StatusCount, TimeWindow, UtilizationBucket,
+    UtilizationRequest, convert_to_interval,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::TryStreamExt;
+use sqlx::{FromRow, Postgres, Transaction};
+use tokio::sync::mpsc;
+
+use crate::{Reporting, ReservationManager};
+
+/// reservations an export reads ahead of the consumer
+const EXPORT_BUFFER: usize = 1024;
+
+/// booked time of a resource within one bucket, clipped to the period
+#[derive(Debug, FromRow)]
+struct BookedBucket {
+    resource_id: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    /// local hour of day the bucket starts at
+    hour: i32,
+    booked_secs: f64,
+}
+
+#[derive(Debug, FromRow)]
+struct StatusRow {
+    resource_id: String,
+    status: RsvpStatus,
+    count: i64,
+    /// confirmed or no_show reservations which started already
+    due: i64,
+}
+
+#[async_trait]
+impl Reporting for ReservationManager {
+    async fn utilization(
+        &self,
+        request: UtilizationRequest,
+    ) -> Result<Vec<ResourceUtilization>, Error> {
+        request.validate()?;
+        let (start, end) = request.period.as_ref().unwrap().bounds();
+        let bucket = ReportBucket::try_from(request.bucket).unwrap_or_default();
+
+        let mut tx = self.begin().await?;
+        // date_trunc() and interval arithmetic follow the session time zone, so buckets start at
+        // local midnight and days stay days across DST changes
+        sqlx::query("SELECT set_config('TimeZone', $1, true)")
+            .bind(request.tz()?.name())
+            .execute(&mut *tx)
+            .await?;
+        let resource_ids = self.report_resources(&mut tx, request.resource_ids).await?;
+
+        let buckets = self
+            .booked_buckets(&mut tx, &resource_ids, start, end, bucket)
+            .await?;
+        let hours = if bucket == ReportBucket::Hour {
+            None
+        } else {
+            let hours = self
+                .booked_buckets(&mut tx, &resource_ids, start, end, ReportBucket::Hour)
+                .await?;
+            Some(hours)
+        };
+        let statuses: Vec<StatusRow> = sqlx::query_as(
+            r#"SELECT resource_id, status, count(*) AS count,
+                count(*) FILTER (WHERE status IN ('confirmed', 'no_show') AND lower(timespan) <= now()) AS due
+            FROM rsvp.reservations
+            WHERE tenant_id = $1 AND resource_id = ANY($2) AND timespan && tstzrange($3, $4)
+            GROUP BY resource_id, status"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource_ids)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        let period_secs = (end - start).num_seconds() as f64;
+        let mut report: Vec<ResourceUtilization> = resource_ids
+            .into_iter()
+            .map(|resource_id| ResourceUtilization {
+                resource_id,
+                ..Default::default()
+            })
+            .collect();
+        let index: HashMap<String, usize> = report
+            .iter()
+            .enumerate()
+            .map(|(i, r)| (r.resource_id.clone(), i))
+            .collect();
+
+        for booked in &buckets {
+            let utilization = &mut report[index[&booked.resource_id]];
+            let bucket_secs = (booked.end - booked.start).num_seconds() as f64;
+            utilization.booked_hours += booked.booked_secs / 3600.0;
+            utilization.buckets.push(UtilizationBucket {
+                window: Some(TimeWindow::new(booked.start, booked.end)),
+                booked_hours: booked.booked_secs / 3600.0,
+                utilization_percent: booked.booked_secs / bucket_secs * 100.0,
+            });
+        }
+
+        let mut by_hour: HashMap<(usize, i32), f64> = HashMap::new();
+        for booked in hours.as_ref().unwrap_or(&buckets) {
+            *by_hour
+                .entry((index[&booked.resource_id], booked.hour))
+                .or_default() += booked.booked_secs;
+        }
+        let mut no_shows = vec![(0, 0); report.len()];
+        for row in statuses {
+            let i = index[&row.resource_id];
+            let status = ReservationStatus::from(row.status);
+            report[i].status_counts.push(StatusCount {
+                status: status as i32,
+                count: row.count,
+            });
+            if status == ReservationStatus::NoShow {
+                no_shows[i].0 += row.count;
+            }
+            no_shows[i].1 += row.due;
+        }
+
+        for (i, utilization) in report.iter_mut().enumerate() {
+            utilization.utilization_percent =
+                utilization.booked_hours * 3600.0 / period_secs * 100.0;
+            utilization.status_counts.sort_by_key(|count| count.status);
+            let (no_show, due) = no_shows[i];
+            if due > 0 {
+                utilization.no_show_rate = no_show as f64 / due as f64;
+            }
+            let peak = (0..24)
+                .filter_map(|hour| by_hour.get(&(i, hour)))
+                .fold(0.0, |max: f64, secs| max.max(*secs));
+            if peak > 0.0 {
+                utilization.peak_hours = (0..24)
+                    .filter(|hour| by_hour.get(&(i, *hour)) == Some(&peak))
+                    .map(|hour| hour as u32)
+                    .collect();
+            }
+        }
+        Ok(report)
+    }
+
+    async fn occupancy(&self, request: OccupancyRequest) -> Result<Vec<OccupancyTimeline>, Error> {
+        request.validate()?;
+        let (start, end) = request.window.as_ref().unwrap().bounds();
+        let bucket_count = request.bucket_count() as i64;
+
+        let mut tx = self.begin().await?;
+        let resource_ids = self.report_resources(&mut tx, request.resource_ids).await?;
+        // a bucket takes the state of the strongest reservation overlapping it, reservations
+        // are found through the gist index of their blocked windows. Those of the resource's
+        // ancestors and descendants block it
+        let cells: Vec<(String, i32)> = sqlx::query_as(
+            r#"WITH buckets AS (
+                SELECT i, tstzrange($3 + i * $5, least($3 + (i + 1) * $5, $4)) AS span
+                FROM generate_series(0, $6 - 1) i
+            )
+            SELECT s.id, max(CASE
+                WHEN r.id IS NULL THEN $7
+                WHEN r.status = 'blocked' OR r.resource_id <> s.id OR NOT r.timespan && b.span THEN $10
+                WHEN r.status IN ('confirmed', 'no_show') THEN $9
+                ELSE $8 END)
+            FROM unnest($2::text[]) AS s(id) CROSS JOIN buckets b
+            LEFT JOIN rsvp.reservations r ON r.tenant_id = $1
+                AND (r.resource_id = s.id OR r.resource_id IN (SELECT rsvp.resource_family($1, s.id)))
+                AND r.blocked && b.span AND r.status NOT IN ('preempted', 'rejected')
+            GROUP BY s.id, b.i ORDER BY s.id, b.i"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource_ids)
+        .bind(start)
+        .bind(end)
+        .bind(convert_to_interval(request.bucket_secs))
+        .bind(bucket_count)
+        .bind(OccupancyState::Free as i32)
+        .bind(OccupancyState::Pending as i32)
+        .bind(OccupancyState::Confirmed as i32)
+        .bind(OccupancyState::Blocked as i32)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        let mut timelines: Vec<OccupancyTimeline> = Vec::with_capacity(resource_ids.len());
+        for (resource_id, state) in cells {
+            match timelines.last_mut() {
+                Some(timeline) if timeline.resource_id == resource_id => {
+                    timeline.states.push(state)
+                }
+                _ => timelines.push(OccupancyTimeline {
+                    resource_id,
+                    states: vec![state],
+                }),
+            }
+        }
+        Ok(timelines)
+    }
+
+    async fn export(
+        &self,
+        selection: Selection,
+    ) -> Result<mpsc::Receiver<Result<abi::Reservation, Error>>, Error> {
+        let tx = self.begin().await?;
+        let (sender, receiver) = mpsc::channel(EXPORT_BUFFER);
+        let manager = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = manager.stream_export(tx, &selection, &sender).await {
+                let _ = sender.send(Err(e)).await;
+            }
+        });
+        Ok(receiver)
+    }
+}
+
+impl ReservationManager {
+    /// send the selected reservations row by row as the database returns them, the bounded
+    /// channel keeps the export from running ahead of a slow consumer
+    async fn stream_export(
+        &self,
+        mut tx: Transaction<'static, Postgres>,
+        selection: &Selection,
+        sender: &mpsc::Sender<Result<abi::Reservation, Error>>,
+    ) -> Result<(), Error> {
+        let mut builder = match selection {
+            Selection::Query(query) => self.query_sql(query),
+            Selection::Filter(filter) => self.filter_sql(filter, false),
+        };
+        let mut rows = builder.build_query_as::<abi::Reservation>().fetch(&mut *tx);
+        while let Some(rsvp) = rows.try_next().await? {
+            // the consumer went away
+            if sender.send(Ok(rsvp)).await.is_err() {
+                return Ok(());
+            }
+        }
+        drop(rows);
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// the requested resources in order, every registered resource if none is requested
+    async fn report_resources(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        mut resource_ids: Vec<String>,
+    ) -> Result<Vec<String>, Error> {
+        if resource_ids.is_empty() {
+            resource_ids = sqlx::query_scalar("SELECT id FROM rsvp.resources WHERE tenant_id = $1")
+                .bind(&self.tenant_id)
+                .fetch_all(&mut **tx)
+                .await?;
+        }
+        resource_ids.sort();
+        resource_ids.dedup();
+        Ok(resource_ids)
+    }
+
+    /// booked time of every resource in every bucket of the period, in the time zone of the
+    /// session. Reservations of the resource's ancestors and descendants hold it as well.
+    /// Reservations no longer holding their window are left out, overlapping ones count once
+    async fn booked_buckets(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource_ids: &[String],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        bucket: ReportBucket,
+    ) -> Result<Vec<BookedBucket>, Error> {
+        let buckets = sqlx::query_as(
+            r#"WITH buckets AS (
+                SELECT b, greatest(b, $3) AS start, least(b + $6::interval, $4) AS "end"
+                FROM generate_series(date_trunc($5, $3), $4, $6::interval) b
+                WHERE b < $4
+            ), booked AS (
+                SELECT s.id AS resource_id, b.b, b.start, b."end",
+                    range_agg(r.timespan * tstzrange(b.start, b."end")) FILTER (WHERE r.id IS NOT NULL) AS spans
+                FROM unnest($2::text[]) AS s(id) CROSS JOIN buckets b
+                LEFT JOIN rsvp.reservations r ON r.tenant_id = $1
+                    AND (r.resource_id = s.id OR r.resource_id IN (SELECT rsvp.resource_family($1, s.id)))
+                    AND r.status NOT IN ('preempted', 'rejected')
+                    AND r.timespan && tstzrange(b.start, b."end")
+                GROUP BY s.id, b.b, b.start, b."end"
+            )
+            SELECT resource_id, start, "end", extract(hour FROM b)::int4 AS hour,
+                COALESCE((SELECT extract(epoch FROM sum(upper(x) - lower(x))) FROM unnest(spans) x), 0)::float8 AS booked_secs
+            FROM booked ORDER BY resource_id, start"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(resource_ids)
+        .bind(start)
+        .bind(end)
+        .bind(bucket.unit())
+        .bind(format!("1 {}", bucket.unit()))
+        .fetch_all(&mut **tx)
+        .await?;
+        Ok(buckets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{NoShowConfig, Reservation, Resource};
+
+    use super::*;
+    use crate::test_util::get_tdb;
+    use crate::{ResourceRegistry, Rsvp};
+
+    #[tokio::test]
+    async fn utilization_should_aggregate_booked_time_in_the_time_zone() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await).with_tenant("hotel-a");
+        let reserve = |manager: ReservationManager, start: &'static str, end: &'static str| async move {
+            manager
+                .reserve(Reservation::new_pending(
+                    "silwingsId",
+                    "room-1",
+                    start.parse().unwrap(),
+                    end.parse().unwrap(),
+                    "",
+                ))
+                .await
+                .unwrap()
+        };
+        let absent = reserve(
+            manager.clone(),
+            "2025-12-25T09:00:00+0800",
+            "2025-12-25T11:00:00+0800",
+        )
+        .await;
+        manager.change_status(absent.id).await.unwrap();
+        manager
+            .mark_no_shows(&NoShowConfig::default())
+            .await
+            .unwrap();
+        let confirmed = reserve(
+            manager.clone(),
+            "2025-12-26T09:30:00+0800",
+            "2025-12-26T10:30:00+0800",
+        )
+        .await;
+        manager.change_status(confirmed.id).await.unwrap();
+        // only its first hour lies within the period
+        reserve(
+            manager.clone(),
+            "2025-12-26T23:00:00+0800",
+            "2025-12-27T01:00:00+0800",
+        )
+        .await;
+        // other tenants' reservations are left out
+        reserve(
+            manager.with_tenant("hotel-b"),
+            "2025-12-25T12:00:00+0800",
+            "2025-12-25T18:00:00+0800",
+        )
+        .await;
+
+        let start: DateTime<Utc> = "2025-12-24T16:00:00Z".parse().unwrap();
+        let end: DateTime<Utc> = "2025-12-26T16:00:00Z".parse().unwrap();
+        let request = UtilizationRequest {
+            resource_ids: vec!["room-2".into(), "room-1".into()],
+            period: Some(TimeWindow::new(start, end)),
+            bucket: ReportBucket::Day as i32,
+            time_zone: "Asia/Shanghai".into(),
+        };
+        let report = manager.utilization(request.clone()).await.unwrap();
+        assert_eq!(report.len(), 2);
+        let room = &report[0];
+        assert_eq!(room.resource_id, "room-1");
+        assert_eq!(room.booked_hours, 4.0);
+        assert!((room.utilization_percent - 4.0 / 48.0 * 100.0).abs() < 1e-9);
+        let buckets = room
+            .buckets
+            .iter()
+            .map(|b| (b.window.unwrap().bounds().0, b.booked_hours))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            buckets,
+            [(start, 2.0), (start + chrono::Duration::days(1), 2.0)]
+        );
+        assert_eq!(room.peak_hours, [9, 10]);
+        let counts = room
+            .status_counts
+            .iter()
+            .map(|c| (c.status, c.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            counts,
+            [
+                (ReservationStatus::Pending as i32, 1),
+                (ReservationStatus::Confirmed as i32, 1),
+                (ReservationStatus::NoShow as i32, 1)
+            ]
+        );
+        assert_eq!(room.no_show_rate, 0.5);
+
+        let idle = &report[1];
+        assert_eq!(idle.resource_id, "room-2");
+        assert_eq!(idle.booked_hours, 0.0);
+        assert_eq!(idle.buckets.len(), 2);
+        assert!(idle.peak_hours.is_empty());
+
+        // the week started before the period, so its bucket is clipped to the period
+        let report = manager
+            .utilization(UtilizationRequest {
+                resource_ids: vec!["room-1".into()],
+                bucket: ReportBucket::Week as i32,
+                ..request
+            })
+            .await
+            .unwrap();
+        let buckets = &report[0].buckets;
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].window.unwrap().bounds(), (start, end));
+        assert_eq!(buckets[0].booked_hours, 4.0);
+    }
+
+    #[tokio::test]
+    async fn utilization_should_count_reservations_of_the_resource_family() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await).with_tenant("hotel-a");
+        for (rid, parent) in [("floor-1", ""), ("room-1a", "floor-1")] {
+            manager
+                .save_resource(Resource {
+                    parent_id: parent.into(),
+                    ..Resource::new(rid, "space")
+                })
+                .await
+                .unwrap();
+        }
+        // the floor booking holds the room, which can't be reserved meanwhile
+        manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "floor-1",
+                "2030-12-25T08:00:00+0000".parse().unwrap(),
+                "2030-12-25T10:00:00+0000".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+
+        let start: DateTime<Utc> = "2030-12-25T00:00:00Z".parse().unwrap();
+        let report = manager
+            .utilization(UtilizationRequest {
+                resource_ids: vec!["room-1a".into()],
+                period: Some(TimeWindow::new(start, start + chrono::Duration::days(1))),
+                bucket: ReportBucket::Day as i32,
+                time_zone: "".into(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(report[0].booked_hours, 2.0);
+        assert_eq!(report[0].peak_hours, [8, 9]);
+    }
+
+    #[tokio::test]
+    async fn occupancy_should_map_buckets_to_the_strongest_state() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await).with_tenant("hotel-a");
+        manager
+            .save_resource(Resource {
+                post_buffer_secs: 1800,
+                ..Resource::new("room-1", "room")
+            })
+            .await
+            .unwrap();
+        for (rid, parent) in [("floor-2", ""), ("room-2a", "floor-2")] {
+            manager
+                .save_resource(Resource {
+                    parent_id: parent.into(),
+                    ..Resource::new(rid, "space")
+                })
+                .await
+                .unwrap();
+        }
+        let reserve = |manager: ReservationManager,
+                       rid: &'static str,
+                       start: &'static str,
+                       end: &'static str| async move {
+            manager
+                .reserve(Reservation::new_pending(
+                    "silwingsId",
+                    rid,
+                    start.parse().unwrap(),
+                    end.parse().unwrap(),
+                    "",
+                ))
+                .await
+                .unwrap()
+        };
+        reserve(
+            manager.clone(),
+            "room-1",
+            "2030-12-25T08:00:00+0000",
+            "2030-12-25T09:00:00+0000",
+        )
+        .await;
+        let confirmed = reserve(
+            manager.clone(),
+            "room-1",
+            "2030-12-25T10:00:00+0000",
+            "2030-12-25T10:30:00+0000",
+        )
+        .await;
+        manager.change_status(confirmed.id).await.unwrap();
+        // the floor booking blocks its room
+        reserve(
+            manager.clone(),
+            "floor-2",
+            "2030-12-25T11:00:00+0000",
+            "2030-12-25T12:00:00+0000",
+        )
+        .await;
+        // other tenants' reservations are left out
+        reserve(
+            manager.with_tenant("hotel-b"),
+            "room-2",
+            "2030-12-25T08:00:00+0000",
+            "2030-12-25T12:00:00+0000",
+        )
+        .await;
+
+        let start: DateTime<Utc> = "2030-12-25T08:00:00Z".parse().unwrap();
+        let end: DateTime<Utc> = "2030-12-25T12:30:00Z".parse().unwrap();
+        let timelines = manager
+            .occupancy(OccupancyRequest {
+                resource_ids: vec!["room-2".into(), "room-1".into(), "room-2a".into()],
+                window: Some(TimeWindow::new(start, end)),
+                bucket_secs: 3600,
+            })
+            .await
+            .unwrap();
+        let timelines = timelines
+            .into_iter()
+            .map(|t| (t.resource_id, t.states))
+            .collect::<Vec<_>>();
+        let states = |states: &[OccupancyState]| states.iter().map(|s| *s as i32).collect();
+        assert_eq!(
+            timelines,
+            [
+                (
+                    "room-1".to_string(),
+                    // the buffer after the first reservation blocks the second bucket
+                    states(&[
+                        OccupancyState::Pending,
+                        OccupancyState::Blocked,
+                        OccupancyState::Confirmed,
+                        OccupancyState::Free,
+                        OccupancyState::Free
+                    ])
+                ),
+                ("room-2".to_string(), states(&[OccupancyState::Free; 5])),
+                (
+                    "room-2a".to_string(),
+                    states(&[
+                        OccupancyState::Free,
+                        OccupancyState::Free,
+                        OccupancyState::Free,
+                        OccupancyState::Blocked,
+                        OccupancyState::Free
+                    ])
+                )
+            ]
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use abi::{
+    CancellationTier, Closure, DEFAULT_RESOURCE_TYPE, Error, OpeningHours, Resource,
+    convert_interval_to_secs, convert_to_interval,
+};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{Postgres, QueryBuilder, Row, Transaction, postgres::types::PgInterval};
+
+use crate::{ReservationManager, ResourceRegistry};
+
+#[async_trait]
+impl ResourceRegistry for ReservationManager {
+    async fn save_resource(&self, mut resource: Resource) -> Result<Resource, Error> {
+        resource.validate()?;
+        resource.resource_type = resource.resource_type().to_string();
+        resource.tenant_id = self.tenant_id.clone();
+
+        let mut tx = self.begin().await?;
+        self.check_parent(&mut tx, &resource).await?;
+        let mut saved: Resource = sqlx::query_as(
+            r#"INSERT INTO rsvp.resources (tenant_id, id, resource_type, pre_buffer, post_buffer, time_zone,
+            slot_granularity, slot_offset, min_slots, max_slots, requires_approval, parent_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, NULLIF($12, ''))
+            ON CONFLICT (tenant_id, id) DO UPDATE SET resource_type = EXCLUDED.resource_type,
+            pre_buffer = EXCLUDED.pre_buffer, post_buffer = EXCLUDED.post_buffer,
+            time_zone = EXCLUDED.time_zone, slot_granularity = EXCLUDED.slot_granularity,
+            slot_offset = EXCLUDED.slot_offset, min_slots = EXCLUDED.min_slots,
+            max_slots = EXCLUDED.max_slots, requires_approval = EXCLUDED.requires_approval,
+            parent_id = EXCLUDED.parent_id
+            RETURNING *"#,
+        )
+        .bind(&resource.tenant_id)
+        .bind(&resource.id)
+        .bind(&resource.resource_type)
+        .bind(convert_to_interval(resource.pre_buffer_secs))
+        .bind(convert_to_interval(resource.post_buffer_secs))
+        .bind(resource.tz()?.name())
+        .bind(convert_to_interval(resource.slot_granularity_secs))
+        .bind(convert_to_interval(resource.slot_offset_secs))
+        .bind(resource.min_slots as i32)
+        .bind(resource.max_slots as i32)
+        .bind(resource.requires_approval)
+        .bind(&resource.parent_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        self.save_calendar(&mut tx, &resource).await?;
+        self.save_approvers(&mut tx, &resource).await?;
+        self.save_cancellation_tiers(&mut tx, &resource).await?;
+        tx.commit().await?;
+
+        saved.opening_hours = resource.opening_hours;
+        saved.closures = resource.closures;
+        saved.approvers = resource.approvers;
+        saved.cancellation_tiers = resource.cancellation_tiers;
+        Ok(saved)
+    }
+
+    async fn get_resource(&self, id: &str) -> Result<Resource, Error> {
+        let mut tx = self.begin().await?;
+        let mut resource =
+            sqlx::query_as("SELECT * FROM rsvp.resources WHERE tenant_id = $1 AND id = $2")
+                .bind(&self.tenant_id)
+                .bind(id)
+                .fetch_one(&mut *tx)
+                .await?;
+        self.load_calendar(&mut tx, &mut resource).await?;
+        self.load_approvers(&mut tx, &mut resource).await?;
+        self.load_cancellation_tiers(&mut tx, &mut resource).await?;
+        tx.commit().await?;
+        Ok(resource)
+    }
+}
+
+impl ReservationManager {
+    /// the parent must be registered and must not be the resource or one of its descendants
+    async fn check_parent(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+    ) -> Result<(), Error> {
+        if resource.parent_id.is_empty() {
+            return Ok(());
+        }
+        let ancestors: Vec<String> = sqlx::query_scalar(
+            r#"WITH RECURSIVE ancestors(id, parent_id) AS (
+                SELECT id, parent_id FROM rsvp.resources WHERE tenant_id = $1 AND id = $2
+                UNION
+                SELECT r.id, r.parent_id FROM rsvp.resources r JOIN ancestors a ON r.id = a.parent_id
+                WHERE r.tenant_id = $1
+            ) SELECT id FROM ancestors"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource.parent_id)
+        .fetch_all(&mut **tx)
+        .await?;
+        if ancestors.is_empty() || ancestors.contains(&resource.id) {
+            return Err(Error::InvalidResourceId(resource.parent_id.clone()));
+        }
+        Ok(())
+    }
+
+    /// the registered resource, or a default one if the resource is not registered
+    pub(crate) async fn load_resource(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        rid: &str,
+    ) -> Result<Resource, Error> {
+        let resource: Option<Resource> =
+            sqlx::query_as("SELECT * FROM rsvp.resources WHERE tenant_id = $1 AND id = $2")
+                .bind(&self.tenant_id)
+                .bind(rid)
+                .fetch_optional(&mut **tx)
+                .await?;
+        let Some(mut resource) = resource else {
+            return Ok(Resource {
+                tenant_id: self.tenant_id.clone(),
+                ..Resource::new(rid, DEFAULT_RESOURCE_TYPE)
+            });
+        };
+        self.load_calendar(tx, &mut resource).await?;
+        self.load_approvers(tx, &mut resource).await?;
+        self.load_cancellation_tiers(tx, &mut resource).await?;
+        Ok(resource)
+    }
+
+    async fn load_calendar(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &mut Resource,
+    ) -> Result<(), Error> {
+        let format_minutes = |m: i16| format!("{:02}:{:02}", m / 60, m % 60);
+        resource.opening_hours = sqlx::query(
+            "SELECT weekday, opens, closes FROM rsvp.resource_opening_hours WHERE tenant_id = $1 AND resource_id = $2 ORDER BY weekday, opens",
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource.id)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|row| {
+            OpeningHours::new(
+                row.get::<i16, _>("weekday") as u32,
+                format_minutes(row.get("opens")),
+                format_minutes(row.get("closes")),
+            )
+        })
+        .collect();
+
+        resource.closures = sqlx::query(
+            "SELECT day, note FROM rsvp.resource_closures WHERE tenant_id = $1 AND resource_id = $2 ORDER BY day",
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource.id)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|row| {
+            let day: NaiveDate = row.get("day");
+            Closure::new(day.format("%Y-%m-%d").to_string(), row.get::<String, _>("note"))
+        })
+        .collect();
+        Ok(())
+    }
+
+    /// replace opening hours and closures of the resource
+    async fn save_calendar(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+    ) -> Result<(), Error> {
+        sqlx::query(
+            "DELETE FROM rsvp.resource_opening_hours WHERE tenant_id = $1 AND resource_id = $2",
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource.id)
+        .execute(&mut **tx)
+        .await?;
+        sqlx::query("DELETE FROM rsvp.resource_closures WHERE tenant_id = $1 AND resource_id = $2")
+            .bind(&self.tenant_id)
+            .bind(&resource.id)
+            .execute(&mut **tx)
+            .await?;
+
+        if !resource.opening_hours.is_empty() {
+            let hours = resource
+                .opening_hours
+                .iter()
+                .map(|hours| Ok((hours.weekday as i16, hours.minutes()?)))
+                .collect::<Result<Vec<_>, Error>>()?;
+            QueryBuilder::new(
+                "INSERT INTO rsvp.resource_opening_hours (tenant_id, resource_id, weekday, opens, closes) ",
+            )
+            .push_values(hours, |mut b, (weekday, (opens, closes))| {
+                b.push_bind(&self.tenant_id)
+                    .push_bind(&resource.id)
+                    .push_bind(weekday)
+                    .push_bind(opens as i16)
+                    .push_bind(closes as i16);
+            })
+            .push(" ON CONFLICT DO NOTHING")
+            .build()
+            .execute(&mut **tx)
+            .await?;
+        }
+
+        if !resource.closures.is_empty() {
+            let closures = resource
+                .closures
+                .iter()
+                .map(|closure| Ok((closure.date()?, &closure.note)))
+                .collect::<Result<Vec<_>, Error>>()?;
+            QueryBuilder::new(
+                "INSERT INTO rsvp.resource_closures (tenant_id, resource_id, day, note) ",
+            )
+            .push_values(closures, |mut b, (day, note)| {
+                b.push_bind(&self.tenant_id)
+                    .push_bind(&resource.id)
+                    .push_bind(day)
+                    .push_bind(note);
+            })
+            .push(" ON CONFLICT DO NOTHING")
+            .build()
+            .execute(&mut **tx)
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn load_approvers(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &mut Resource,
+    ) -> Result<(), Error> {
+        resource.approvers = sqlx::query_scalar(
+            "SELECT user_id FROM rsvp.resource_approvers WHERE tenant_id = $1 AND resource_id = $2 ORDER BY user_id",
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource.id)
+        .fetch_all(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    /// replace the approvers of the resource
+    async fn save_approvers(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+    ) -> Result<(), Error> {
+        sqlx::query(
+            "DELETE FROM rsvp.resource_approvers WHERE tenant_id = $1 AND resource_id = $2",
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource.id)
+        .execute(&mut **tx)
+        .await?;
+
+        if !resource.approvers.is_empty() {
+            QueryBuilder::new(
+                "INSERT INTO rsvp.resource_approvers (tenant_id, resource_id, user_id) ",
+            )
+            .push_values(&resource.approvers, |mut b, uid| {
+                b.push_bind(&self.tenant_id)
+                    .push_bind(&resource.id)
+                    .push_bind(uid);
+            })
+            .push(" ON CONFLICT DO NOTHING")
+            .build()
+            .execute(&mut **tx)
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn load_cancellation_tiers(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &mut Resource,
+    ) -> Result<(), Error> {
+        resource.cancellation_tiers = sqlx::query(
+            "SELECT min_notice, fee_percent FROM rsvp.resource_cancellation_tiers WHERE tenant_id = $1 AND resource_id = $2 ORDER BY min_notice DESC",
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource.id)
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|row| {
+            CancellationTier::new(
+                convert_interval_to_secs(&row.get::<PgInterval, _>("min_notice")),
+                row.get::<i16, _>("fee_percent") as u32,
+            )
+        })
+        .collect();
+        Ok(())
+    }
+
+    /// replace the cancellation tiers of the resource
+    async fn save_cancellation_tiers(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        resource: &Resource,
+    ) -> Result<(), Error> {
+        sqlx::query(
+            "DELETE FROM rsvp.resource_cancellation_tiers WHERE tenant_id = $1 AND resource_id = $2",
+        )
+        .bind(&self.tenant_id)
+        .bind(&resource.id)
+        .execute(&mut **tx)
+        .await?;
+
+        if !resource.cancellation_tiers.is_empty() {
+            QueryBuilder::new(
+                "INSERT INTO rsvp.resource_cancellation_tiers (tenant_id, resource_id, min_notice, fee_percent) ",
+            )
+            .push_values(&resource.cancellation_tiers, |mut b, tier| {
+                b.push_bind(&self.tenant_id)
+                    .push_bind(&resource.id)
+                    .push_bind(convert_to_interval(tier.min_notice_secs))
+                    .push_bind(tier.fee_percent as i16);
+            })
+            .build()
+            .execute(&mut **tx)
+            .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+
+use abi::{Error, ReservationStatus, ResourceId, UserId, Validator, WaitlistEntry};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, Postgres, Transaction, postgres::types::PgRange};
+
+use crate::{ReservationManager, Waitlist};
+
+#[async_trait]
+impl Waitlist for ReservationManager {
+    async fn join_waitlist(&self, mut entry: WaitlistEntry) -> Result<WaitlistEntry, Error> {
+        entry.validate()?;
+        entry.tenant_id = self.tenant_id.clone();
+
+        let mut tx = self.begin().await?;
+        // the entry becomes a reservation without the caller, so it has to pass the same rules
+        let resource = self.load_resource(&mut tx, &entry.resource_id).await?;
+        let (start, end) = entry.bounds();
+        self.check_window(&mut tx, &resource, start, end).await?;
+
+        let queued: WaitlistEntry = sqlx::query_as(
+            "INSERT INTO rsvp.waitlist (tenant_id, user_id, resource_id, timespan, note) VALUES ($1, $2, $3, $4, $5) RETURNING *",
+        )
+        .bind(&entry.tenant_id)
+        .bind(&entry.user_id)
+        .bind(&entry.resource_id)
+        .bind(entry.get_timespan())
+        .bind(&entry.note)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        // the window may already be free, serve the queue right away
+        self.promote_waitlist(&mut tx, &entry.resource_id, entry.get_timespan())
+            .await?;
+        let entry = self.get_entry(&mut tx, queued.id).await?;
+        tx.commit().await?;
+        Ok(entry)
+    }
+
+    async fn leave_waitlist(&self, id: i64) -> Result<WaitlistEntry, Error> {
+        id.validate()?;
+        let mut tx = self.begin().await?;
+        let entry = sqlx::query_as(
+            "DELETE FROM rsvp.waitlist WHERE id = $1 AND tenant_id = $2 RETURNING *",
+        )
+        .bind(id)
+        .bind(&self.tenant_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(entry)
+    }
+
+    async fn list_waitlist(
+        &self,
+        rid: ResourceId,
+        uid: UserId,
+    ) -> Result<Vec<WaitlistEntry>, Error> {
+        let mut tx = self.begin().await?;
+        let entries = sqlx::query_as(
+            "SELECT * FROM rsvp.waitlist WHERE tenant_id = $1 AND ($2 = '' OR resource_id = $2) AND ($3 = '' OR user_id = $3) ORDER BY id",
+        )
+        .bind(&self.tenant_id)
+        .bind(rid)
+        .bind(uid)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(entries)
+    }
+}
+
+impl ReservationManager {
+    /// serve the waitlist for the windows released in the transaction, then commit it
+    pub(crate) async fn commit(&self, mut tx: Transaction<'static, Postgres>) -> Result<(), Error> {
+        let released: Vec<(String, String, PgRange<DateTime<Utc>>)> = sqlx::query_as(
+            "DELETE FROM rsvp.released_windows RETURNING tenant_id, resource_id, blocked",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        for (tenant_id, rid, blocked) in released {
+            // a background job releases windows of every tenant
+            self.with_tenant(tenant_id)
+                .promote_waitlist(&mut tx, &rid, blocked)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// reserve waiting entries of the resource, its ancestors and descendants whose window
+    /// overlaps the released one, earliest entry first, like new reservations. Entries whose window has started are no longer
+    /// served, entries which still conflict or break a rule keep waiting
+    async fn promote_waitlist(
+        &self,
+        tx: &mut Transaction<'static, Postgres>,
+        rid: &str,
+        released: PgRange<DateTime<Utc>>,
+    ) -> Result<(), Error> {
+        let entries: Vec<WaitlistEntry> = sqlx::query_as(
+            r#"SELECT w.* FROM rsvp.waitlist w
+            LEFT JOIN rsvp.resources s ON s.tenant_id = w.tenant_id AND s.id = w.resource_id
+            WHERE w.tenant_id = $1 AND w.reservation_id IS NULL
+                AND (w.resource_id = $2 OR w.resource_id IN (SELECT rsvp.resource_family($1, $2)))
+                AND lower(w.timespan) > now()
+                AND tstzrange(lower(w.timespan) - COALESCE(s.pre_buffer, '0'),
+                    upper(w.timespan) + COALESCE(s.post_buffer, '0')) && $3
+            ORDER BY w.id FOR UPDATE OF w SKIP LOCKED"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(rid)
+        .bind(released)
+        .fetch_all(&mut **tx)
+        .await?;
+
+        let mut resources = HashMap::new();
+        for entry in entries {
+            if !resources.contains_key(&entry.resource_id) {
+                let resource = self.load_resource(tx, &entry.resource_id).await?;
+                resources.insert(entry.resource_id.clone(), resource);
+            }
+            let rsvp = abi::Reservation {
+                user_id: entry.user_id.clone(),
+                resource_id: entry.resource_id.clone(),
+                status: ReservationStatus::Pending as i32,
+                start: entry.start,
+                end: entry.end,
+                note: entry.note.clone(),
+                ..Default::default()
+            };
+
+            let mut savepoint = tx.begin().await?;
+            let resource = &resources[&entry.resource_id];
+            match self
+                .insert_reservation(&mut savepoint, resource, rsvp)
+                .await
+            {
+                Ok(rsvp) => {
+                    sqlx::query("UPDATE rsvp.waitlist SET reservation_id = $1 WHERE id = $2")
+                        .bind(rsvp.id)
+                        .bind(entry.id)
+                        .execute(&mut *savepoint)
+                        .await?;
+                    savepoint.commit().await?;
+                }
+                Err(Error::DbError(e)) if !matches!(e, sqlx::Error::Database(_)) => {
+                    return Err(Error::DbError(e));
+                }
+                // still taken, over the user's quota or no longer within the rules
+                Err(_) => savepoint.rollback().await?,
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_entry(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: i64,
+    ) -> Result<WaitlistEntry, Error> {
+        let entry = sqlx::query_as("SELECT * FROM rsvp.waitlist WHERE id = $1 AND tenant_id = $2")
+            .bind(id)
+            .bind(&self.tenant_id)
+            .fetch_one(&mut **tx)
+            .await?;
+        Ok(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::get_tdb;
+    use crate::{ResourceRegistry, Rsvp};
+    use abi::{BookingPolicy, Reservation, ReservationStatus, Resource, TimeWindow};
+    use chrono::{DateTime, Utc};
+
+    #[tokio::test]
+    async fn cancelled_reservation_should_promote_earliest_waitlist_entry() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "ocean-view-room-713",
+                "2030-12-25T15:00:00-0700".parse().unwrap(),
+                "2030-12-28T12:00:00-0700".parse().unwrap(),
+                "hello.",
+            ))
+            .await
+            .unwrap();
+
+        let first = manager
+            .join_waitlist(WaitlistEntry::new(
+                "aliceId",
+                "ocean-view-room-713",
+                "2030-12-26T15:00:00-0700".parse().unwrap(),
+                "2030-12-30T12:00:00-0700".parse().unwrap(),
+                "first",
+            ))
+            .await
+            .unwrap();
+        let second = manager
+            .join_waitlist(WaitlistEntry::new(
+                "bobId",
+                "ocean-view-room-713",
+                "2030-12-27T15:00:00-0700".parse().unwrap(),
+                "2030-12-29T12:00:00-0700".parse().unwrap(),
+                "second",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(first.reservation_id, 0);
+        assert_eq!(second.reservation_id, 0);
+
+        manager.delete(rsvp.id).await.unwrap();
+
+        let entries = manager
+            .list_waitlist("ocean-view-room-713".into(), "".into())
+            .await
+            .unwrap();
+        assert_ne!(entries[0].reservation_id, 0);
+        assert_eq!(entries[1].reservation_id, 0);
+
+        let promoted = manager.get(entries[0].reservation_id).await.unwrap();
+        assert_eq!(promoted.user_id, "aliceId");
+        assert_eq!(promoted.status, ReservationStatus::Pending as i32);
+        assert_eq!(promoted.note, "first");
+    }
+
+    #[tokio::test]
+    async fn promotion_should_skip_users_over_their_quota() {
+        let tdb = get_tdb();
+        let manager =
+            ReservationManager::new(tdb.get_pool().await).with_policies([BookingPolicy {
+                max_active_reservations: Some(1),
+                ..Default::default()
+            }]);
+        let rsvp = |uid: &str, rid: &str| {
+            Reservation::new_pending(
+                uid,
+                rid,
+                "2030-12-25T15:00:00-0700".parse().unwrap(),
+                "2030-12-28T12:00:00-0700".parse().unwrap(),
+                "",
+            )
+        };
+        let taken = manager
+            .reserve(rsvp("silwingsId", "ocean-view-room-713"))
+            .await
+            .unwrap();
+        manager
+            .reserve(rsvp("aliceId", "ocean-view-room-714"))
+            .await
+            .unwrap();
+        for uid in ["aliceId", "bobId"] {
+            manager
+                .join_waitlist(WaitlistEntry::new(
+                    uid,
+                    "ocean-view-room-713",
+                    "2030-12-26T15:00:00-0700".parse().unwrap(),
+                    "2030-12-27T12:00:00-0700".parse().unwrap(),
+                    "",
+                ))
+                .await
+                .unwrap();
+        }
+
+        manager.delete(taken.id).await.unwrap();
+
+        let entries = manager
+            .list_waitlist("ocean-view-room-713".into(), "".into())
+            .await
+            .unwrap();
+        assert_eq!(entries[0].user_id, "aliceId");
+        assert_eq!(entries[0].reservation_id, 0);
+        assert_ne!(entries[1].reservation_id, 0);
+    }
+
+    #[tokio::test]
+    async fn rescheduled_reservation_should_promote_waitlist_entry() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "ocean-view-room-713",
+                "2030-12-25T15:00:00-0700".parse().unwrap(),
+                "2030-12-28T12:00:00-0700".parse().unwrap(),
+                "hello.",
+            ))
+            .await
+            .unwrap();
+        let entry = manager
+            .join_waitlist(WaitlistEntry::new(
+                "aliceId",
+                "ocean-view-room-713",
+                "2030-12-25T15:00:00-0700".parse().unwrap(),
+                "2030-12-26T12:00:00-0700".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+
+        let t = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        manager
+            .reschedule(
+                rsvp.id,
+                TimeWindow::new(t("2031-01-25T15:00:00Z"), t("2031-01-28T12:00:00Z")),
+            )
+            .await
+            .unwrap();
+
+        let entries = manager
+            .list_waitlist("".into(), "aliceId".into())
+            .await
+            .unwrap();
+        assert_eq!(entries[0].id, entry.id);
+        assert_ne!(entries[0].reservation_id, 0);
+    }
+
+    #[tokio::test]
+    async fn cancelled_reservation_should_promote_waitlist_entries_of_the_resource_family() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        for (rid, parent) in [("floor-1", ""), ("room-1a", "floor-1")] {
+            manager
+                .save_resource(Resource {
+                    parent_id: parent.into(),
+                    ..Resource::new(rid, "space")
+                })
+                .await
+                .unwrap();
+        }
+        let floor = manager
+            .reserve(Reservation::new_pending(
+                "silwingsId",
+                "floor-1",
+                "2030-12-25T08:00:00+0000".parse().unwrap(),
+                "2030-12-25T18:00:00+0000".parse().unwrap(),
+                "offsite",
+            ))
+            .await
+            .unwrap();
+        // the floor booking blocks the room
+        let entry = manager
+            .join_waitlist(WaitlistEntry::new(
+                "aliceId",
+                "room-1a",
+                "2030-12-25T09:00:00+0000".parse().unwrap(),
+                "2030-12-25T10:00:00+0000".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(entry.reservation_id, 0);
+
+        manager.delete(floor.id).await.unwrap();
+
+        let entries = manager
+            .list_waitlist("room-1a".into(), "".into())
+            .await
+            .unwrap();
+        assert_ne!(entries[0].reservation_id, 0);
+        let promoted = manager.get(entries[0].reservation_id).await.unwrap();
+        assert_eq!(promoted.resource_id, "room-1a");
+    }
+
+    #[tokio::test]
+    async fn join_waitlist_for_free_window_should_reserve_right_away() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let entry = manager
+            .join_waitlist(WaitlistEntry::new(
+                "aliceId",
+                "ocean-view-room-713",
+                "2030-12-25T15:00:00-0700".parse().unwrap(),
+                "2030-12-26T12:00:00-0700".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+        assert_ne!(entry.reservation_id, 0);
+
+        let left = manager.leave_waitlist(entry.id).await.unwrap();
+        assert_eq!(left.id, entry.id);
+        // leaving keeps the reservation
+        manager.get(entry.reservation_id).await.unwrap();
+    }
+}
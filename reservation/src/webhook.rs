@@ -0,0 +1,292 @@
+use abi::{Error, WebhookConfig, WebhookDeadLetter, WebhookSubscription, convert_to_interval};
+use async_trait::async_trait;
+use sqlx::FromRow;
+
+use crate::{ReservationManager, Webhooks};
+
+const SUBSCRIPTION_COLUMNS: &str = "id, tenant_id, url, ops::text[] AS ops, active";
+
+/// a change on its way to a subscriber, claimed by the delivery worker
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct Delivery {
+    pub id: i64,
+    pub subscription_id: i64,
+    pub url: String,
+    pub secret: String,
+    /// the JSON body of the callback
+    pub payload: String,
+    /// including the one being made
+    pub attempts: i32,
+}
+
+#[async_trait]
+impl Webhooks for ReservationManager {
+    async fn save_webhook(
+        &self,
+        mut subscription: WebhookSubscription,
+    ) -> Result<WebhookSubscription, Error> {
+        subscription.validate()?;
+        subscription.tenant_id = self.tenant_id.clone();
+
+        let mut tx = self.begin().await?;
+        let saved = if subscription.id == 0 {
+            sqlx::query_as(&format!(
+                "INSERT INTO rsvp.webhook_subscriptions (tenant_id, url, secret, ops, active) VALUES ($1, $2, $3, $4::text[]::rsvp.reservation_update_type[], $5) RETURNING {SUBSCRIPTION_COLUMNS}"
+            ))
+            .bind(&self.tenant_id)
+            .bind(&subscription.url)
+            .bind(&subscription.secret)
+            .bind(subscription.op_names())
+            .bind(subscription.active)
+            .fetch_one(&mut *tx)
+            .await?
+        } else {
+            sqlx::query_as(&format!(
+                "UPDATE rsvp.webhook_subscriptions SET url = $3, secret = COALESCE(NULLIF($4, ''), secret), ops = $5::text[]::rsvp.reservation_update_type[], active = $6 WHERE tenant_id = $1 AND id = $2 RETURNING {SUBSCRIPTION_COLUMNS}"
+            ))
+            .bind(&self.tenant_id)
+            .bind(subscription.id)
+            .bind(&subscription.url)
+            .bind(&subscription.secret)
+            .bind(subscription.op_names())
+            .bind(subscription.active)
+            .fetch_one(&mut *tx)
+            .await?
+        };
+        tx.commit().await?;
+        Ok(saved)
+    }
+
+    async fn delete_webhook(&self, id: i64) -> Result<WebhookSubscription, Error> {
+        let mut tx = self.begin().await?;
+        let deleted = sqlx::query_as(&format!(
+            "DELETE FROM rsvp.webhook_subscriptions WHERE tenant_id = $1 AND id = $2 RETURNING {SUBSCRIPTION_COLUMNS}"
+        ))
+        .bind(&self.tenant_id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(deleted)
+    }
+
+    async fn list_webhooks(&self) -> Result<Vec<WebhookSubscription>, Error> {
+        let mut tx = self.begin().await?;
+        let subscriptions = sqlx::query_as(&format!(
+            "SELECT {SUBSCRIPTION_COLUMNS} FROM rsvp.webhook_subscriptions WHERE tenant_id = $1 ORDER BY id"
+        ))
+        .bind(&self.tenant_id)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(subscriptions)
+    }
+
+    async fn list_dead_letters(
+        &self,
+        subscription_id: i64,
+    ) -> Result<Vec<WebhookDeadLetter>, Error> {
+        let mut tx = self.begin().await?;
+        let dead_letters = sqlx::query_as(
+            r#"SELECT id, subscription_id, change_id, payload::text AS payload, attempts, last_error, failed_at
+            FROM rsvp.webhook_dead_letters WHERE tenant_id = $1 AND ($2 = 0 OR subscription_id = $2)
+            ORDER BY id"#,
+        )
+        .bind(&self.tenant_id)
+        .bind(subscription_id)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(dead_letters)
+    }
+}
+
+impl ReservationManager {
+    /// claim the oldest undelivered change of every active subscription of every tenant if it is
+    /// due. Later changes of a subscription wait until it is delivered or dead, so callbacks
+    /// arrive in order. A claimed delivery is not due again until the lease is over
+    pub async fn claim_webhook_deliveries(&self, lease_secs: u64) -> Result<Vec<Delivery>, Error> {
+        let mut tx = self.begin_system().await?;
+        let deliveries = sqlx::query_as(
+            r#"WITH heads AS (
+                SELECT DISTINCT ON (d.subscription_id) d.id FROM rsvp.webhook_deliveries d
+                JOIN rsvp.webhook_subscriptions s ON s.id = d.subscription_id AND s.active
+                ORDER BY d.subscription_id, d.id
+            ), claimed AS (
+                UPDATE rsvp.webhook_deliveries d SET attempts = d.attempts + 1,
+                    next_attempt_at = now() + $1
+                FROM heads WHERE d.id = heads.id AND d.next_attempt_at <= now()
+                RETURNING d.*
+            )
+            SELECT c.id, c.subscription_id, s.url, s.secret, c.payload::text AS payload, c.attempts
+            FROM claimed c JOIN rsvp.webhook_subscriptions s ON s.id = c.subscription_id
+            ORDER BY c.id"#,
+        )
+        .bind(convert_to_interval(lease_secs))
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(deliveries)
+    }
+
+    /// the subscriber accepted the delivery
+    pub async fn complete_webhook_delivery(&self, id: i64) -> Result<(), Error> {
+        let mut tx = self.begin_system().await?;
+        sqlx::query("DELETE FROM rsvp.webhook_deliveries WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// retry the delivery after a backoff, or move it to the dead letters once it ran out of
+    /// attempts. Returns whether it is dead
+    pub async fn fail_webhook_delivery(
+        &self,
+        delivery: &Delivery,
+        error: &str,
+        config: &WebhookConfig,
+    ) -> Result<bool, Error> {
+        let attempts = delivery.attempts.max(0) as u32;
+        let dead = attempts >= config.max_attempts;
+        let mut tx = self.begin_system().await?;
+        if dead {
+            sqlx::query(
+                r#"WITH dead AS (DELETE FROM rsvp.webhook_deliveries WHERE id = $1 RETURNING *)
+                INSERT INTO rsvp.webhook_dead_letters (id, tenant_id, subscription_id, change_id, payload, attempts, last_error)
+                SELECT id, tenant_id, subscription_id, change_id, payload, attempts, $2 FROM dead"#,
+            )
+            .bind(delivery.id)
+            .bind(error)
+            .execute(&mut *tx)
+            .await?;
+        } else {
+            sqlx::query(
+                "UPDATE rsvp.webhook_deliveries SET next_attempt_at = now() + $2, last_error = $3 WHERE id = $1",
+            )
+            .bind(delivery.id)
+            .bind(convert_to_interval(config.backoff(attempts)))
+            .bind(error)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(dead)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{Reservation, ReservationUpdateType};
+
+    use super::*;
+    use crate::Rsvp;
+    use crate::test_util::get_tdb;
+
+    #[tokio::test]
+    async fn deliveries_should_be_claimed_in_order_per_subscription() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await).with_tenant("hotel-a");
+        let all = manager
+            .save_webhook(WebhookSubscription::new("http://127.0.0.1:1/all", "s3cret"))
+            .await
+            .unwrap();
+        let created = manager
+            .save_webhook(WebhookSubscription {
+                ops: vec![ReservationUpdateType::Create as i32],
+                ..WebhookSubscription::new("http://127.0.0.1:1/created", "s3cret")
+            })
+            .await
+            .unwrap();
+        // other tenants' changes are not delivered to the subscriptions
+        manager
+            .with_tenant("hotel-b")
+            .reserve(reservation("room-2"))
+            .await
+            .unwrap();
+        let rsvp = manager.reserve(reservation("room-1")).await.unwrap();
+        manager.change_status(rsvp.id).await.unwrap();
+
+        let claimed = manager.claim_webhook_deliveries(60).await.unwrap();
+        let heads = claimed
+            .iter()
+            .map(|d| (d.subscription_id, d.attempts))
+            .collect::<Vec<_>>();
+        assert_eq!(heads, [(all.id, 1), (created.id, 1)]);
+        assert!(claimed[0].payload.contains(r#""op": "create""#));
+        assert_eq!(claimed[0].secret, "s3cret");
+        // leased, and the later change of the first subscription waits for the head
+        assert!(
+            manager
+                .claim_webhook_deliveries(60)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        for delivery in &claimed {
+            manager
+                .complete_webhook_delivery(delivery.id)
+                .await
+                .unwrap();
+        }
+        let claimed = manager.claim_webhook_deliveries(60).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert!(claimed[0].payload.contains(r#""op": "update""#));
+    }
+
+    #[tokio::test]
+    async fn failed_deliveries_should_back_off_and_die() {
+        let tdb = get_tdb();
+        let manager = ReservationManager::new(tdb.get_pool().await);
+        let subscription = manager
+            .save_webhook(WebhookSubscription::new("http://127.0.0.1:1/", "s3cret"))
+            .await
+            .unwrap();
+        manager.reserve(reservation("room-1")).await.unwrap();
+        let config = WebhookConfig {
+            max_attempts: 2,
+            backoff_secs: 0,
+            ..Default::default()
+        };
+
+        let delivery = manager.claim_webhook_deliveries(0).await.unwrap().remove(0);
+        let dead = manager
+            .fail_webhook_delivery(&delivery, "connection refused", &config)
+            .await
+            .unwrap();
+        assert!(!dead);
+        let delivery = manager.claim_webhook_deliveries(0).await.unwrap().remove(0);
+        assert_eq!(delivery.attempts, 2);
+        let dead = manager
+            .fail_webhook_delivery(&delivery, "connection refused", &config)
+            .await
+            .unwrap();
+        assert!(dead);
+
+        assert!(
+            manager
+                .claim_webhook_deliveries(0)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let dead_letters = manager.list_dead_letters(subscription.id).await.unwrap();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].id, delivery.id);
+        assert_eq!(dead_letters[0].attempts, 2);
+        assert_eq!(dead_letters[0].last_error, "connection refused");
+        assert_eq!(manager.list_webhooks().await.unwrap()[0].secret, "");
+    }
+
+    fn reservation(rid: &str) -> Reservation {
+        Reservation::new_pending(
+            "silwingsId",
+            rid,
+            "2030-12-25T12:00:00+0800".parse().unwrap(),
+            "2030-12-26T12:00:00+0800".parse().unwrap(),
+            "",
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use abi::{Error, ExportChunk, ExportWriter, Reservation};
+use futures::stream;
+use tokio::sync::mpsc::Receiver;
+
+use crate::ExportStream;
+
+/// rendered lines are sent once a chunk holds this many bytes
+const EXPORT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// render the reservations as they arrive, so only a chunk at a time is held in memory
+pub(crate) fn export_chunks(
+    rows: Receiver<Result<Reservation, Error>>,
+    writer: ExportWriter,
+) -> ExportStream {
+    let buf = writer.header();
+    let chunks = stream::unfold(Some((rows, writer, buf)), |state| async move {
+        let (mut rows, writer, mut buf) = state?;
+        loop {
+            match rows.recv().await {
+                Some(Ok(rsvp)) => {
+                    writer.write(&rsvp, &mut buf);
+                    if buf.len() >= EXPORT_CHUNK_SIZE {
+                        let chunk = ExportChunk { data: buf.into() };
+                        return Some((Ok(chunk), Some((rows, writer, String::new()))));
+                    }
+                }
+                // a failed export ends with the error
+                Some(Err(e)) => return Some((Err(e.into()), None)),
+                None if buf.is_empty() => return None,
+                None => return Some((Ok(ExportChunk { data: buf.into() }), None)),
+            }
+        }
+    });
+    Box::pin(chunks)
+}
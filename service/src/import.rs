@@ -0,0 +1,118 @@
+use abi::{Error, ImportChunk, ImportReader, ImportResponse, ImportRow};
+use futures::{Stream, StreamExt};
+use reservation::{Imports, ReservationManager};
+use tokio::sync::mpsc::{self, Sender};
+use tonic::Status;
+
+/// rows an import parses ahead of the inserts
+const IMPORT_BUFFER: usize = 1024;
+
+/// parse the chunks as they arrive and import their rows. The format and mode are those of the
+/// first chunk
+pub(crate) async fn import_chunks<S>(
+    manager: &ReservationManager,
+    mut chunks: S,
+) -> Result<ImportResponse, Error>
+where
+    S: Stream<Item = Result<ImportChunk, Status>> + Send + Unpin + 'static,
+{
+    let first = match chunks.next().await {
+        Some(chunk) => chunk.map_err(|status| Error::InvalidImport(status.message().into()))?,
+        None => return Err(Error::InvalidImport("empty import".into())),
+    };
+    let mode = first.mode();
+    let (sender, receiver) = mpsc::channel(IMPORT_BUFFER);
+    tokio::spawn(async move {
+        if let Err(e) = read_rows(first, chunks, &sender).await {
+            let _ = sender.send(Err(e)).await;
+        }
+    });
+    manager.import(receiver, mode).await
+}
+
+async fn read_rows<S>(
+    first: ImportChunk,
+    mut chunks: S,
+    sender: &Sender<Result<ImportRow, Error>>,
+) -> Result<(), Error>
+where
+    S: Stream<Item = Result<ImportChunk, Status>> + Unpin,
+{
+    let mut reader = ImportReader::new(first.format());
+    let mut data = first.data;
+    loop {
+        for row in reader.push(&data)? {
+            // the import failed and went away
+            if sender.send(Ok(row)).await.is_err() {
+                return Ok(());
+            }
+        }
+        match chunks.next().await {
+            Some(chunk) => {
+                data = chunk
+                    .map_err(|status| Error::InvalidImport(status.message().into()))?
+                    .data
+            }
+            None => break,
+        }
+    }
+    if let Some(row) = reader.finish()? {
+        let _ = sender.send(Ok(row)).await;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{ExportFormat, ImportMode};
+    use futures::stream;
+
+    use super::*;
+    use crate::{RsvpService, service::tests::TestConfig};
+
+    fn chunk(data: &str) -> ImportChunk {
+        ImportChunk {
+            format: ExportFormat::JsonLines as i32,
+            mode: ImportMode::BestEffort as i32,
+            data: data.as_bytes().to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn import_chunks_should_import_rows_split_across_chunks() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let manager = service.manager.clone();
+
+        let chunks = stream::iter([
+            chunk(
+                r#"{"user_id": "silwings", "resource_id": "ixia-3230", "start": "2025-12-26T15:00:00+08:00", "#,
+            ),
+            chunk(r#""end": "2025-12-26T17:00:00+08:00"}"#),
+            chunk("\n{\"user_id\": \"silwings\"}\n"),
+            chunk(
+                r#"{"user_id": "silwings", "resource_id": "ixia-3231", "start": "2025-12-26T15:00:00+08:00", "end": "2025-12-26T17:00:00+08:00"}"#,
+            ),
+        ]
+        .map(Ok));
+        let response = import_chunks(&manager, chunks).await.unwrap();
+        assert!(response.committed);
+        assert_eq!(response.accepted, 2);
+        let invalid = response.invalid.iter().map(|e| e.row).collect::<Vec<_>>();
+        assert_eq!(invalid, [2]);
+
+        // a broken stream aborts the import
+        let chunks = stream::iter([
+            Ok(chunk(
+                r#"{"user_id": "silwings", "resource_id": "ixia-3232", "start": "2025-12-26T15:00:00+08:00", "end": "2025-12-26T17:00:00+08:00"}"#,
+            )),
+            Err(Status::cancelled("client went away")),
+        ]);
+        let err = import_chunks(&manager, chunks).await.unwrap_err();
+        assert_eq!(err, Error::InvalidImport("client went away".into()));
+        assert_eq!(
+            import_chunks(&manager, stream::empty()).await,
+            Err(Error::InvalidImport("empty import".into()))
+        );
+    }
+}
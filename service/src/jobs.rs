@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use abi::{NoShowConfig, NotificationConfig, WebhookConfig};
+use reservation::ReservationManager;
+
+use crate::{NotificationScheduler, WebhookWorker, notifier_from_config};
+
+/// periodically mark reservations nobody checked in as no_show
+pub fn spawn_no_show_job(manager: ReservationManager, config: NoShowConfig) {
+    if config.interval_secs == 0 {
+        return;
+    }
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_secs));
+        loop {
+            interval.tick().await;
+            match manager.mark_no_shows(&config).await {
+                Ok(rsvps) if !rsvps.is_empty() => {
+                    println!("Marked {} reservations as no_show", rsvps.len())
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to mark no_show reservations: {e}"),
+            }
+        }
+    });
+}
+
+/// periodically deliver queued reservation changes to webhook subscriptions, until none is due
+pub fn spawn_webhook_job(
+    manager: ReservationManager,
+    config: WebhookConfig,
+) -> Result<(), reqwest::Error> {
+    if config.interval_secs == 0 {
+        return Ok(());
+    }
+    let worker = WebhookWorker::new(manager, config.clone())?;
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_secs));
+        loop {
+            interval.tick().await;
+            loop {
+                match worker.run_once().await {
+                    Ok(0) => break,
+                    Ok(n) => println!("Delivered {n} reservation changes to webhooks"),
+                    Err(e) => {
+                        eprintln!("Failed to deliver webhooks: {e}");
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+/// periodically schedule reservation notifications and send the due ones
+pub fn spawn_notification_job(
+    manager: ReservationManager,
+    config: NotificationConfig,
+) -> Result<(), anyhow::Error> {
+    if config.interval_secs == 0 {
+        return Ok(());
+    }
+    let notifier = notifier_from_config(&config.notifier)?;
+    let scheduler = NotificationScheduler::new(manager, notifier, config.clone());
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_secs));
+        loop {
+            interval.tick().await;
+            match scheduler.run_once().await {
+                Ok(0) => {}
+                Ok(n) => println!("Sent {n} reservation notifications"),
+                Err(e) => eprintln!("Failed to send notifications: {e}"),
+            }
+        }
+    });
+    Ok(())
+}
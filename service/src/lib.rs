@@ -1,13 +1,26 @@
+mod export;
+mod import;
+mod jobs;
+mod metadata;
+mod notifier;
+mod scheduler;
 mod service;
+mod webhook;
 
 use std::pin::Pin;
 
-use abi::{Config, Reservation, reservation_service_server::ReservationServiceServer};
+use abi::{Config, ExportChunk, Reservation, reservation_service_server::ReservationServiceServer};
 use futures::Stream;
 use reservation::ReservationManager;
 use tonic::transport::Server;
 
+pub use metadata::{ADMIN_ROLE, PREEMPT_ROLE, ROLES_KEY, TENANT_ID_KEY, USER_ID_KEY};
+pub use notifier::{LogNotifier, Notifier, SmtpNotifier, WebhookNotifier, notifier_from_config};
+pub use scheduler::NotificationScheduler;
+pub use webhook::{DELIVERY_HEADER, SIGNATURE_HEADER, WebhookWorker, sign};
+
 type ReservationStream = Pin<Box<dyn Stream<Item = Result<Reservation, tonic::Status>> + Send>>;
+type ExportStream = Pin<Box<dyn Stream<Item = Result<ExportChunk, tonic::Status>> + Send>>;
 
 pub struct RsvpService {
     manager: ReservationManager,
@@ -17,6 +30,9 @@ pub async fn start_server(config: &Config) -> Result<(), anyhow::Error> {
     let addr = format!("{}:{}", config.server.host, config.server.port).parse()?;
 
     let svc = RsvpService::from_config(config).await?;
+    jobs::spawn_no_show_job(svc.manager.clone(), config.no_show.clone());
+    jobs::spawn_webhook_job(svc.manager.clone(), config.webhook.clone())?;
+    jobs::spawn_notification_job(svc.manager.clone(), config.notification.clone())?;
     let svc = ReservationServiceServer::new(svc);
 
     println!("Listening on {}", addr);
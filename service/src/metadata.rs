@@ -0,0 +1,79 @@
+use abi::{Error, TenantId, UserId};
+use tonic::Request;
+
+/// metadata key carrying the caller's tenant
+pub const TENANT_ID_KEY: &str = "x-tenant-id";
+/// metadata key carrying the calling user
+pub const USER_ID_KEY: &str = "x-user-id";
+/// metadata key carrying the caller's roles, separated by commas
+pub const ROLES_KEY: &str = "x-roles";
+
+/// role granted every permission
+pub const ADMIN_ROLE: &str = "admin";
+/// role allowed to preempt reservations of lower priority
+pub const PREEMPT_ROLE: &str = "preempt";
+
+/// get the tenant from request metadata, requests without it belong to the default tenant
+pub fn tenant_id<T>(request: &Request<T>) -> Result<TenantId, Error> {
+    let Some(value) = request.metadata().get(TENANT_ID_KEY) else {
+        return Ok(TenantId::default());
+    };
+    let tenant_id = value
+        .to_str()
+        .map_err(|_| Error::InvalidTenantId(format!("{value:?}")))?;
+    let valid = tenant_id.len() <= 64
+        && tenant_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(Error::InvalidTenantId(tenant_id.to_string()));
+    }
+    Ok(tenant_id.to_string())
+}
+
+/// get the calling user from request metadata, operations acting on behalf of a user require it
+pub fn user_id<T>(request: &Request<T>) -> Result<UserId, Error> {
+    let user_id = request
+        .metadata()
+        .get(USER_ID_KEY)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    if user_id.is_empty() {
+        return Err(Error::PermissionDenied(format!("{USER_ID_KEY} required")));
+    }
+    if user_id.len() > 64 {
+        return Err(Error::InvalidUserId(user_id.to_string()));
+    }
+    Ok(user_id.to_string())
+}
+
+/// roles of the caller from request metadata
+pub fn roles<T>(request: &Request<T>) -> Vec<String> {
+    request
+        .metadata()
+        .get(ROLES_KEY)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|role| !role.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// whether the caller has the role, admins have every role
+pub fn has_role<T>(request: &Request<T>, role: &str) -> bool {
+    roles(request).iter().any(|r| r == role || r == ADMIN_ROLE)
+}
+
+/// fail unless the caller has the role, admins have every role
+pub fn require_role<T>(request: &Request<T>, role: &str) -> Result<(), Error> {
+    if has_role(request, role) {
+        Ok(())
+    } else {
+        Err(Error::PermissionDenied(format!("{role} role required")))
+    }
+}
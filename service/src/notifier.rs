@@ -0,0 +1,277 @@
+use std::{sync::Arc, time::Duration};
+
+use abi::NotifierConfig;
+use anyhow::{Context, bail};
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, header::ContentType},
+    transport::smtp::authentication::Credentials,
+};
+use reqwest::header::CONTENT_TYPE;
+use reservation::Notification;
+use serde_json::json;
+use tonic::async_trait;
+
+use crate::{SIGNATURE_HEADER, sign};
+
+/// how long the webhook notifier waits for a response
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+/// delivers a notification to the user of its reservation
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// an error has the notification retried later
+    async fn notify(&self, notification: &Notification) -> Result<(), anyhow::Error>;
+}
+
+/// build the notifier the config asks for
+pub fn notifier_from_config(config: &NotifierConfig) -> Result<Arc<dyn Notifier>, anyhow::Error> {
+    let notifier: Arc<dyn Notifier> = match config {
+        NotifierConfig::Log => Arc::new(LogNotifier),
+        NotifierConfig::Webhook { url, secret } => {
+            Arc::new(WebhookNotifier::new(url.clone(), secret.clone())?)
+        }
+        NotifierConfig::Smtp {
+            host,
+            port,
+            from,
+            domain,
+            username,
+            password,
+            starttls,
+        } => {
+            let mut builder = if *starttls {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?
+            } else {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+            };
+            builder = builder.port(*port);
+            if !username.is_empty() {
+                builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+            }
+            Arc::new(SmtpNotifier::new(builder.build(), from, domain.clone())?)
+        }
+    };
+    Ok(notifier)
+}
+
+/// prints notifications, for development
+pub struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<(), anyhow::Error> {
+        println!(
+            "Notify {} of tenant {:?}: {}",
+            notification.user_id,
+            notification.tenant_id,
+            notification.message()
+        );
+        Ok(())
+    }
+}
+
+/// POSTs notifications as JSON, signed like webhook deliveries if it has a secret
+pub struct WebhookNotifier {
+    url: String,
+    secret: String,
+    client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: impl Into<String>, secret: impl Into<String>) -> Result<Self, reqwest::Error> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+            .build()?;
+        Ok(Self {
+            url: url.into(),
+            secret: secret.into(),
+            client,
+        })
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    /// any 2xx response acknowledges the notification
+    async fn notify(&self, notification: &Notification) -> Result<(), anyhow::Error> {
+        let body = json!({
+            "id": notification.id,
+            "tenant_id": notification.tenant_id,
+            "reservation_id": notification.reservation_id,
+            "user_id": notification.user_id,
+            "resource_id": notification.resource_id,
+            "kind": notification.kind.name(),
+            "starts_at": notification.starts_at.to_rfc3339(),
+            "subject": notification.subject(),
+            "message": notification.message(),
+        })
+        .to_string();
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header(CONTENT_TYPE, "application/json");
+        if !self.secret.is_empty() {
+            request = request.header(SIGNATURE_HEADER, sign(&self.secret, &body));
+        }
+        let response = request.body(body).send().await?;
+        if !response.status().is_success() {
+            bail!("responded {}", response.status());
+        }
+        Ok(())
+    }
+}
+
+/// mails notifications, user ids without "@" are local parts of addresses in the domain
+pub struct SmtpNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    domain: String,
+}
+
+impl SmtpNotifier {
+    pub fn new(
+        transport: AsyncSmtpTransport<Tokio1Executor>,
+        from: &str,
+        domain: impl Into<String>,
+    ) -> Result<Self, anyhow::Error> {
+        let from = from
+            .parse()
+            .with_context(|| format!("invalid sender {from}"))?;
+        Ok(Self {
+            transport,
+            from,
+            domain: domain.into(),
+        })
+    }
+
+    fn recipient(&self, user_id: &str) -> Result<Mailbox, anyhow::Error> {
+        let address = if user_id.contains('@') {
+            user_id.to_string()
+        } else {
+            format!("{user_id}@{}", self.domain)
+        };
+        address
+            .parse()
+            .with_context(|| format!("invalid recipient {address}"))
+    }
+}
+
+#[async_trait]
+impl Notifier for SmtpNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<(), anyhow::Error> {
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(self.recipient(&notification.user_id)?)
+            .subject(notification.subject())
+            .header(ContentType::TEXT_PLAIN)
+            .body(notification.message())?;
+        self.transport.send(email).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::{TimeZone, Utc};
+    use reservation::NotificationKind;
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+    };
+
+    use super::*;
+    use crate::webhook::tests::stand_in;
+
+    fn notification(user_id: &str) -> Notification {
+        Notification {
+            id: 1,
+            tenant_id: "hotel-a".to_string(),
+            reservation_id: 42,
+            user_id: user_id.to_string(),
+            resource_id: "ocean-view-room-713".to_string(),
+            kind: NotificationKind::HoldExpiring,
+            starts_at: Utc.with_ymd_and_hms(2030, 12, 25, 12, 0, 0).unwrap(),
+            attempts: 1,
+        }
+    }
+
+    /// local SMTP server accepting every mail, records the commands and the data of a session
+    async fn mock_smtp() -> (u16, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let log = received.clone();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            writer.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+            let mut data = None::<String>;
+            while let Ok(Some(line)) = lines.next_line().await {
+                if let Some(body) = data.as_mut() {
+                    if line == "." {
+                        log.lock().unwrap().push(data.take().unwrap());
+                        writer.write_all(b"250 queued\r\n").await.unwrap();
+                    } else {
+                        body.push_str(&line);
+                        body.push('\n');
+                    }
+                    continue;
+                }
+                let reply: &[u8] = match line.split(' ').next().unwrap().to_uppercase().as_str() {
+                    "EHLO" | "HELO" => b"250 localhost\r\n",
+                    "DATA" => {
+                        data = Some(String::new());
+                        b"354 go ahead\r\n"
+                    }
+                    "QUIT" => b"221 bye\r\n",
+                    _ => b"250 ok\r\n",
+                };
+                log.lock().unwrap().push(line);
+                writer.write_all(reply).await.unwrap();
+            }
+        });
+        (port, received)
+    }
+
+    #[tokio::test]
+    async fn smtp_notifier_should_mail_the_user() {
+        let (port, received) = mock_smtp().await;
+        let notifier = notifier_from_config(&NotifierConfig::Smtp {
+            host: "127.0.0.1".to_string(),
+            port,
+            from: "Reservations <rsvp@example.com>".to_string(),
+            domain: "example.com".to_string(),
+            username: String::new(),
+            password: String::new(),
+            starttls: false,
+        })
+        .unwrap();
+        notifier.notify(&notification("silwings")).await.unwrap();
+
+        let received = received.lock().unwrap().clone();
+        assert!(received.contains(&"MAIL FROM:<rsvp@example.com>".to_string()));
+        assert!(received.contains(&"RCPT TO:<silwings@example.com>".to_string()));
+        let data = received.iter().find(|s| s.contains("Subject:")).unwrap();
+        assert!(data.contains("Subject: Please confirm your reservation of ocean-view-room-713"));
+        assert!(data.contains("Reservation 42 of ocean-view-room-713"));
+    }
+
+    #[tokio::test]
+    async fn webhook_notifier_should_post_signed_json() {
+        let (url, received) = stand_in(vec![]).await;
+        let notifier = WebhookNotifier::new(url, "s3cret").unwrap();
+        notifier.notify(&notification("silwings")).await.unwrap();
+
+        let received = received.lock().unwrap().clone();
+        let (signature, body) = &received[0];
+        assert_eq!(*signature, sign("s3cret", body));
+        let body: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["kind"], "hold_expiring");
+        assert_eq!(body["user_id"], "silwings");
+        assert_eq!(body["starts_at"], "2030-12-25T12:00:00+00:00");
+    }
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use abi::{Error, NotificationConfig};
+use reservation::ReservationManager;
+use tokio::{sync::Semaphore, task::JoinSet};
+
+use crate::Notifier;
+
+/// how long a claimed notification is not due again, covering a slow notifier
+const NOTIFICATION_LEASE_SECS: u64 = 300;
+
+/// computes the due notifications of every tenant and dispatches them through the notifier.
+/// Notifications are persisted before they are sent, so a restart does not lose them. Delivery is
+/// at least once: one sent right before a crash, or outliving its lease, is sent again
+#[derive(Clone)]
+pub struct NotificationScheduler {
+    manager: ReservationManager,
+    notifier: Arc<dyn Notifier>,
+    config: NotificationConfig,
+}
+
+impl NotificationScheduler {
+    pub fn new(
+        manager: ReservationManager,
+        notifier: Arc<dyn Notifier>,
+        config: NotificationConfig,
+    ) -> Self {
+        Self {
+            manager,
+            notifier,
+            config,
+        }
+    }
+
+    /// schedule upcoming notifications, then send a batch of the due ones, a bounded number at
+    /// a time. Returns how many were sent
+    pub async fn run_once(&self) -> Result<usize, Error> {
+        self.manager.schedule_notifications(&self.config).await?;
+        let notifications = self
+            .manager
+            .claim_notifications(NOTIFICATION_LEASE_SECS, self.config.batch_size)
+            .await?;
+
+        let permits = Arc::new(Semaphore::new(self.config.concurrency.max(1)));
+        let mut sends = JoinSet::new();
+        for notification in notifications {
+            let notifier = self.notifier.clone();
+            let permits = permits.clone();
+            sends.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                let result = notifier.notify(&notification).await;
+                (notification, result)
+            });
+        }
+        let mut sent = 0;
+        while let Some(joined) = sends.join_next().await {
+            // a panicked send is retried once its lease is over
+            let Ok((notification, result)) = joined else {
+                continue;
+            };
+            match result {
+                Ok(()) => {
+                    self.manager.complete_notification(notification.id).await?;
+                    sent += 1;
+                }
+                Err(e) => {
+                    self.manager
+                        .fail_notification(&notification, &format!("{e:#}"), &self.config)
+                        .await?;
+                }
+            }
+        }
+        Ok(sent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use abi::Reservation;
+    use anyhow::bail;
+    use chrono::{Duration, Utc};
+    use reservation::{Notification, NotificationKind, Rsvp};
+    use tonic::async_trait;
+
+    use super::*;
+    use crate::{RsvpService, service::tests::TestConfig};
+
+    /// records what it is asked to send, failing the first time
+    #[derive(Default)]
+    struct RecordingNotifier {
+        sent: Mutex<Vec<(i64, NotificationKind)>>,
+        failed: Mutex<bool>,
+    }
+
+    #[async_trait]
+    impl Notifier for RecordingNotifier {
+        async fn notify(&self, notification: &Notification) -> Result<(), anyhow::Error> {
+            let mut failed = self.failed.lock().unwrap();
+            if !*failed {
+                *failed = true;
+                bail!("mail server down");
+            }
+            self.sent
+                .lock()
+                .unwrap()
+                .push((notification.reservation_id, notification.kind));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn scheduler_should_send_due_notifications_and_retry_failures() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let manager = service.manager.clone();
+        let start = Utc::now() + Duration::minutes(30);
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwings",
+                "ixia-3230",
+                start.fixed_offset(),
+                (start + Duration::hours(1)).fixed_offset(),
+                "",
+            ))
+            .await
+            .unwrap();
+
+        let notifier = Arc::new(RecordingNotifier::default());
+        let scheduler = NotificationScheduler::new(
+            manager,
+            notifier.clone(),
+            NotificationConfig {
+                retry_secs: 0,
+                ..Default::default()
+            },
+        );
+        // the reminder and the hold warning are both due, one of them fails once
+        assert_eq!(scheduler.run_once().await.unwrap(), 1);
+        assert_eq!(scheduler.run_once().await.unwrap(), 1);
+        assert_eq!(scheduler.run_once().await.unwrap(), 0);
+
+        let mut sent = notifier.sent.lock().unwrap().clone();
+        sent.sort_by_key(|(_, kind)| *kind as i32);
+        assert_eq!(
+            sent,
+            [
+                (rsvp.id, NotificationKind::Reminder),
+                (rsvp.id, NotificationKind::HoldExpiring)
+            ]
+        );
+    }
+}
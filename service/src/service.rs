@@ -1,19 +1,63 @@
 use abi::{
-    CancelRequest, CancelResponse, Config, ConfirmRequest, ConfirmResponse, FilterRequest,
-    FilterResponse, GetRequest, GetResponse, ListenRequest, QueryRequest, ReserveRequest,
-    ReserveResponse, UpdateRequest, UpdateResponse, reservation_service_server::ReservationService,
+    AcceptTransferRequest, AcceptTransferResponse, AddAttendeeRequest, AddAttendeeResponse,
+    ApprovalInboxRequest, ApprovalInboxResponse, ApproveRequest, ApproveResponse,
+    AvailabilityRequest, AvailabilityResponse, CancelRequest, CancelResponse, CheckInRequest,
+    CheckInResponse, CheckOutRequest, CheckOutResponse, Config, ConfirmRequest, ConfirmResponse,
+    DeclineTransferRequest, DeclineTransferResponse, DeleteWebhookRequest, DeleteWebhookResponse,
+    EndEarlyRequest, EndEarlyResponse, ExportRequest, ExportWriter, ExtendRequest, ExtendResponse,
+    FilterRequest, FilterResponse, GetPoolRequest, GetPoolResponse, GetRatePlanRequest,
+    GetRatePlanResponse, GetRequest, GetResourceRequest, GetResourceResponse, GetResponse,
+    GetTenantSettingsRequest, GetTenantSettingsResponse, ImportChunk, ImportResponse,
+    JoinWaitlistRequest, JoinWaitlistResponse, LeaveWaitlistRequest, LeaveWaitlistResponse,
+    ListAttendeesRequest, ListAttendeesResponse, ListDeadLettersRequest, ListDeadLettersResponse,
+    ListPoliciesRequest, ListPoliciesResponse, ListWaitlistRequest, ListWaitlistResponse,
+    ListWebhooksRequest, ListWebhooksResponse, ListenRequest, MergeRequest, MergeResponse,
+    OccupancyRequest, OccupancyResponse, OverbookedWindowsRequest, OverbookedWindowsResponse,
+    QueryRequest, QuoteRequest, QuoteResponse, RejectRequest, RejectResponse,
+    RemoveAttendeeRequest, RemoveAttendeeResponse, RescheduleRequest, RescheduleResponse,
+    ReserveFromPoolRequest, ReserveFromPoolResponse, ReserveRequest, ReserveResponse,
+    RespondRequest, RespondResponse, SavePoolRequest, SavePoolResponse, SaveRatePlanRequest,
+    SaveRatePlanResponse, SaveResourceRequest, SaveResourceResponse, SaveTenantSettingsRequest,
+    SaveTenantSettingsResponse, SaveWebhookRequest, SaveWebhookResponse, SetPolicyRequest,
+    SetPolicyResponse, SplitRequest, SplitResponse, TransferRequest, TransferResponse,
+    UpdateRequest, UpdateResponse, UtilizationRequest, UtilizationResponse, convert_to_utc_time,
+    reservation_service_server::ReservationService,
 };
-use reservation::{ReservationManager, Rsvp};
-use tonic::{Response, Status, async_trait};
+use futures::stream;
+use reservation::{
+    Approvals, Attendees, Overbooking, Policies, Pools, Pricing, Reporting, ReservationManager,
+    ResourceRegistry, Rsvp, Waitlist, Webhooks,
+};
+use tonic::{Request, Response, Status, Streaming, async_trait};
 
-use crate::{ReservationStream, RsvpService};
+use crate::{
+    ADMIN_ROLE, ExportStream, PREEMPT_ROLE, ReservationStream, RsvpService,
+    export::export_chunks,
+    import::import_chunks,
+    metadata::{has_role, require_role, tenant_id, user_id},
+};
 
 impl RsvpService {
     pub async fn from_config(config: &Config) -> Result<Self, anyhow::Error> {
         Ok(Self {
-            manager: ReservationManager::from_config(&config.db).await?,
+            manager: ReservationManager::from_config(&config.db)
+                .await?
+                .with_policies(config.policies.clone()),
         })
     }
+
+    /// the manager scoped to the tenant of the request
+    fn manager<T>(&self, request: &Request<T>) -> Result<ReservationManager, abi::Error> {
+        Ok(self.manager.with_tenant(tenant_id(request)?))
+    }
+}
+
+/// only callers who may preempt rank their reservations above the default priority
+fn with_allowed_priority(mut reservation: abi::Reservation, may_preempt: bool) -> abi::Reservation {
+    if !may_preempt {
+        reservation.priority = 0;
+    }
+    reservation
 }
 
 #[async_trait]
@@ -23,72 +67,669 @@ impl ReservationService for RsvpService {
         &self,
         request: tonic::Request<ReserveRequest>,
     ) -> std::result::Result<tonic::Response<ReserveResponse>, tonic::Status> {
+        if request.get_ref().preempt {
+            require_role(&request, PREEMPT_ROLE)?;
+        }
+        let manager = self.manager(&request)?;
+        let may_preempt = has_role(&request, PREEMPT_ROLE);
         let request = request.into_inner();
-        if request.reservation.is_none() {
+        let Some(reservation) = request.reservation else {
             return Err(Status::invalid_argument("missing reservation"));
-        }
-        let reservation = self.manager.reserve(request.reservation.unwrap()).await?;
+        };
+        let reservation = with_allowed_priority(reservation, may_preempt);
+        let (reservation, preempted) = if request.preempt {
+            manager.preempt(reservation).await?
+        } else {
+            (manager.reserve(reservation).await?, vec![])
+        };
         Ok(Response::new(ReserveResponse {
             reservation: Some(reservation),
+            preempted,
         }))
     }
     /// confirm a pending reservation, if reservation is not pending, do nothing
     async fn confirm(
         &self,
-        _request: tonic::Request<ConfirmRequest>,
+        request: tonic::Request<ConfirmRequest>,
     ) -> std::result::Result<tonic::Response<ConfirmResponse>, tonic::Status> {
-        todo!()
+        let manager = self.manager(&request)?;
+        let id = request.into_inner().id;
+        let reservation = match manager.change_status(id).await {
+            Ok(reservation) => reservation,
+            // not pending, so it is returned as it is
+            Err(abi::Error::NotFound) => manager.get(id).await?,
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Response::new(ConfirmResponse {
+            reservation: Some(reservation),
+        }))
     }
     /// update the reservation note
     async fn update(
         &self,
-        _request: tonic::Request<UpdateRequest>,
+        request: tonic::Request<UpdateRequest>,
     ) -> std::result::Result<tonic::Response<UpdateResponse>, tonic::Status> {
-        todo!()
+        let manager = self.manager(&request)?;
+        let request = request.into_inner();
+        let reservation = manager.update_note(request.id, request.note).await?;
+        Ok(Response::new(UpdateResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// move the reservation to another window
+    async fn reschedule(
+        &self,
+        request: tonic::Request<RescheduleRequest>,
+    ) -> std::result::Result<tonic::Response<RescheduleResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let request = request.into_inner();
+        let Some(window) = request.window else {
+            return Err(Status::invalid_argument("missing window"));
+        };
+        let reservation = manager.reschedule(request.id, window).await?;
+        Ok(Response::new(RescheduleResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// end the reservation now or at the given time, freeing the rest of its window
+    async fn end_early(
+        &self,
+        request: tonic::Request<EndEarlyRequest>,
+    ) -> std::result::Result<tonic::Response<EndEarlyResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let request = request.into_inner();
+        let end = request.end.as_ref().map(convert_to_utc_time);
+        let reservation = manager.end_early(request.id, end).await?;
+        Ok(Response::new(EndEarlyResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// make the reservation end later if the following time is free
+    async fn extend(
+        &self,
+        request: tonic::Request<ExtendRequest>,
+    ) -> std::result::Result<tonic::Response<ExtendResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let request = request.into_inner();
+        let Some(end) = request.end.as_ref() else {
+            return Err(Status::invalid_argument("missing end"));
+        };
+        let reservation = manager
+            .extend(request.id, convert_to_utc_time(end), request.best_effort)
+            .await?;
+        Ok(Response::new(ExtendResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// split a reservation in two at a point in time
+    async fn split(
+        &self,
+        request: tonic::Request<SplitRequest>,
+    ) -> std::result::Result<tonic::Response<SplitResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let request = request.into_inner();
+        let Some(at) = request.at.as_ref() else {
+            return Err(Status::invalid_argument("missing split time"));
+        };
+        let (first, second) = manager.split(request.id, convert_to_utc_time(at)).await?;
+        Ok(Response::new(SplitResponse {
+            reservations: vec![first, second],
+        }))
+    }
+    /// combine contiguous reservations of the same user and resource
+    async fn merge(
+        &self,
+        request: tonic::Request<MergeRequest>,
+    ) -> std::result::Result<tonic::Response<MergeResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let reservation = manager.merge(request.into_inner().ids).await?;
+        Ok(Response::new(MergeResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// hand the reservation over to another user, the caller must be its owner or an admin
+    async fn transfer(
+        &self,
+        request: tonic::Request<TransferRequest>,
+    ) -> std::result::Result<tonic::Response<TransferResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let by = if has_role(&request, ADMIN_ROLE) {
+            None
+        } else {
+            Some(user_id(&request)?)
+        };
+        let request = request.into_inner();
+        let reservation = manager
+            .transfer(
+                request.id,
+                by,
+                request.to_user_id,
+                request.require_acceptance,
+            )
+            .await?;
+        Ok(Response::new(TransferResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// accept a transfer, the caller must be its recipient
+    async fn accept_transfer(
+        &self,
+        request: tonic::Request<AcceptTransferRequest>,
+    ) -> std::result::Result<tonic::Response<AcceptTransferResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let uid = user_id(&request)?;
+        let reservation = manager
+            .accept_transfer(request.into_inner().id, uid)
+            .await?;
+        Ok(Response::new(AcceptTransferResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// decline or withdraw a transfer, the caller must be its recipient or the owner
+    async fn decline_transfer(
+        &self,
+        request: tonic::Request<DeclineTransferRequest>,
+    ) -> std::result::Result<tonic::Response<DeclineTransferResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let uid = user_id(&request)?;
+        let reservation = manager
+            .decline_transfer(request.into_inner().id, uid)
+            .await?;
+        Ok(Response::new(DeclineTransferResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// confirm a reservation awaiting approval, the caller must be an approver of the resource
+    async fn approve(
+        &self,
+        request: tonic::Request<ApproveRequest>,
+    ) -> std::result::Result<tonic::Response<ApproveResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let uid = user_id(&request)?;
+        let reservation = manager.approve(request.into_inner().id, uid).await?;
+        Ok(Response::new(ApproveResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// reject a reservation awaiting approval, the caller must be an approver of the resource
+    async fn reject(
+        &self,
+        request: tonic::Request<RejectRequest>,
+    ) -> std::result::Result<tonic::Response<RejectResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let uid = user_id(&request)?;
+        let reservation = manager.reject(request.into_inner().id, uid).await?;
+        Ok(Response::new(RejectResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// reservations awaiting the caller's approval
+    async fn approval_inbox(
+        &self,
+        request: tonic::Request<ApprovalInboxRequest>,
+    ) -> std::result::Result<tonic::Response<ApprovalInboxResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let uid = user_id(&request)?;
+        let reservations = manager.approval_inbox(uid).await?;
+        Ok(Response::new(ApprovalInboxResponse { reservations }))
+    }
+    /// record the guest has arrived
+    async fn check_in(
+        &self,
+        request: tonic::Request<CheckInRequest>,
+    ) -> std::result::Result<tonic::Response<CheckInResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let reservation = manager.check_in(request.into_inner().id).await?;
+        Ok(Response::new(CheckInResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// record the guest has left
+    async fn check_out(
+        &self,
+        request: tonic::Request<CheckOutRequest>,
+    ) -> std::result::Result<tonic::Response<CheckOutResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let reservation = manager.check_out(request.into_inner().id).await?;
+        Ok(Response::new(CheckOutResponse {
+            reservation: Some(reservation),
+        }))
     }
     /// cancel a reservation
     async fn cancel(
         &self,
-        _request: tonic::Request<CancelRequest>,
+        request: tonic::Request<CancelRequest>,
     ) -> std::result::Result<tonic::Response<CancelResponse>, tonic::Status> {
-        todo!()
+        if request.get_ref().override_policy {
+            require_role(&request, ADMIN_ROLE)?;
+        }
+        let manager = self.manager(&request)?;
+        let request = request.into_inner();
+        let (reservation, fee) = manager.cancel(request.id, request.override_policy).await?;
+        Ok(Response::new(CancelResponse {
+            reservation: Some(reservation),
+            fee,
+        }))
     }
     /// get a reservation by id
     async fn get(
         &self,
-        _request: tonic::Request<GetRequest>,
+        request: tonic::Request<GetRequest>,
     ) -> std::result::Result<tonic::Response<GetResponse>, tonic::Status> {
-        todo!()
+        let manager = self.manager(&request)?;
+        let reservation = manager.get(request.into_inner().id).await?;
+        Ok(Response::new(GetResponse {
+            reservation: Some(reservation),
+        }))
     }
     /// Server streaming response type for the query method.
     type queryStream = ReservationStream;
     /// query reservations by resource id, user id, status, start time, end time
     async fn query(
         &self,
-        _request: tonic::Request<QueryRequest>,
+        request: tonic::Request<QueryRequest>,
     ) -> std::result::Result<tonic::Response<Self::queryStream>, tonic::Status> {
-        todo!()
+        let manager = self.manager(&request)?;
+        let Some(query) = request.into_inner().query else {
+            return Err(Status::invalid_argument("missing query"));
+        };
+        let reservations = manager.query(query).await?;
+        Ok(Response::new(Box::pin(stream::iter(
+            reservations.into_iter().map(Ok),
+        ))))
     }
     /// filter reservations, order by reservation id
     async fn filter(
         &self,
-        _request: tonic::Request<FilterRequest>,
+        request: tonic::Request<FilterRequest>,
     ) -> std::result::Result<tonic::Response<FilterResponse>, tonic::Status> {
-        todo!()
+        let manager = self.manager(&request)?;
+        let Some(filter) = request.into_inner().filter else {
+            return Err(Status::invalid_argument("missing filter"));
+        };
+        let (pager, reservations) = manager.filter(filter).await?;
+        Ok(Response::new(FilterResponse {
+            reservations,
+            pager: Some(pager),
+        }))
     }
     /// Server streaming response type for the listen method.
     type listenStream = ReservationStream;
     /// another system could monitor newly added/confirmed/cancelled reservations
     async fn listen(
         &self,
-        _request: tonic::Request<ListenRequest>,
+        request: tonic::Request<ListenRequest>,
     ) -> std::result::Result<tonic::Response<Self::listenStream>, tonic::Status> {
-        todo!()
+        let manager = self.manager(&request)?;
+        let changes = manager.listen().await?;
+        let stream = stream::unfold(changes, |mut changes| async move {
+            let change = changes.recv().await?;
+            Some((change.map_err(Status::from), changes))
+        });
+        Ok(Response::new(Box::pin(stream)))
+    }
+    /// register or update a resource, admins only
+    async fn save_resource(
+        &self,
+        request: tonic::Request<SaveResourceRequest>,
+    ) -> std::result::Result<tonic::Response<SaveResourceResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let Some(resource) = request.into_inner().resource else {
+            return Err(Status::invalid_argument("missing resource"));
+        };
+        let resource = manager.save_resource(resource).await?;
+        Ok(Response::new(SaveResourceResponse {
+            resource: Some(resource),
+        }))
+    }
+    /// get a resource by id
+    async fn get_resource(
+        &self,
+        request: tonic::Request<GetResourceRequest>,
+    ) -> std::result::Result<tonic::Response<GetResourceResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let resource = manager.get_resource(&request.into_inner().id).await?;
+        Ok(Response::new(GetResourceResponse {
+            resource: Some(resource),
+        }))
+    }
+    /// register or replace the rate plan of a resource, admins only
+    async fn save_rate_plan(
+        &self,
+        request: tonic::Request<SaveRatePlanRequest>,
+    ) -> std::result::Result<tonic::Response<SaveRatePlanResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let Some(plan) = request.into_inner().rate_plan else {
+            return Err(Status::invalid_argument("missing rate plan"));
+        };
+        let plan = manager.save_rate_plan(plan).await?;
+        Ok(Response::new(SaveRatePlanResponse {
+            rate_plan: Some(plan),
+        }))
+    }
+    /// get the rate plan of a resource
+    async fn get_rate_plan(
+        &self,
+        request: tonic::Request<GetRatePlanRequest>,
+    ) -> std::result::Result<tonic::Response<GetRatePlanResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let plan = manager
+            .get_rate_plan(&request.into_inner().resource_id)
+            .await?;
+        Ok(Response::new(GetRatePlanResponse {
+            rate_plan: Some(plan),
+        }))
+    }
+    /// price a prospective reservation
+    async fn quote(
+        &self,
+        request: tonic::Request<QuoteRequest>,
+    ) -> std::result::Result<tonic::Response<QuoteResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let Some(reservation) = request.into_inner().reservation else {
+            return Err(Status::invalid_argument("missing reservation"));
+        };
+        let quote = manager.quote(reservation).await?;
+        Ok(Response::new(QuoteResponse { quote: Some(quote) }))
+    }
+    /// change the settings of the tenant, e.g. its currency, admins only
+    async fn save_tenant_settings(
+        &self,
+        request: tonic::Request<SaveTenantSettingsRequest>,
+    ) -> std::result::Result<tonic::Response<SaveTenantSettingsResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let Some(settings) = request.into_inner().settings else {
+            return Err(Status::invalid_argument("missing settings"));
+        };
+        let settings = manager.save_tenant_settings(settings).await?;
+        Ok(Response::new(SaveTenantSettingsResponse {
+            settings: Some(settings),
+        }))
+    }
+    /// get the settings of the tenant
+    async fn get_tenant_settings(
+        &self,
+        request: tonic::Request<GetTenantSettingsRequest>,
+    ) -> std::result::Result<tonic::Response<GetTenantSettingsResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let settings = manager.get_tenant_settings().await?;
+        Ok(Response::new(GetTenantSettingsResponse {
+            settings: Some(settings),
+        }))
+    }
+    /// register or update a webhook subscription, requires the admin role
+    async fn save_webhook(
+        &self,
+        request: tonic::Request<SaveWebhookRequest>,
+    ) -> std::result::Result<tonic::Response<SaveWebhookResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let Some(subscription) = request.into_inner().subscription else {
+            return Err(Status::invalid_argument("missing subscription"));
+        };
+        let subscription = manager.save_webhook(subscription).await?;
+        Ok(Response::new(SaveWebhookResponse {
+            subscription: Some(subscription),
+        }))
+    }
+    /// delete a webhook subscription, requires the admin role
+    async fn delete_webhook(
+        &self,
+        request: tonic::Request<DeleteWebhookRequest>,
+    ) -> std::result::Result<tonic::Response<DeleteWebhookResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let subscription = manager.delete_webhook(request.into_inner().id).await?;
+        Ok(Response::new(DeleteWebhookResponse {
+            subscription: Some(subscription),
+        }))
+    }
+    /// list the webhook subscriptions of the tenant, requires the admin role
+    async fn list_webhooks(
+        &self,
+        request: tonic::Request<ListWebhooksRequest>,
+    ) -> std::result::Result<tonic::Response<ListWebhooksResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let subscriptions = manager.list_webhooks().await?;
+        Ok(Response::new(ListWebhooksResponse { subscriptions }))
+    }
+    /// list changes which could not be delivered, requires the admin role
+    async fn list_dead_letters(
+        &self,
+        request: tonic::Request<ListDeadLettersRequest>,
+    ) -> std::result::Result<tonic::Response<ListDeadLettersResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let dead_letters = manager
+            .list_dead_letters(request.into_inner().subscription_id)
+            .await?;
+        Ok(Response::new(ListDeadLettersResponse { dead_letters }))
+    }
+    /// register or replace a pool of interchangeable resources, admins only
+    async fn save_pool(
+        &self,
+        request: tonic::Request<SavePoolRequest>,
+    ) -> std::result::Result<tonic::Response<SavePoolResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let Some(pool) = request.into_inner().pool else {
+            return Err(Status::invalid_argument("missing pool"));
+        };
+        let pool = manager.save_pool(pool).await?;
+        Ok(Response::new(SavePoolResponse { pool: Some(pool) }))
+    }
+    /// get a pool by id
+    async fn get_pool(
+        &self,
+        request: tonic::Request<GetPoolRequest>,
+    ) -> std::result::Result<tonic::Response<GetPoolResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let pool = manager.get_pool(&request.into_inner().id).await?;
+        Ok(Response::new(GetPoolResponse { pool: Some(pool) }))
+    }
+    /// reserve whichever member of the pool its strategy picks
+    async fn reserve_from_pool(
+        &self,
+        request: tonic::Request<ReserveFromPoolRequest>,
+    ) -> std::result::Result<tonic::Response<ReserveFromPoolResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let may_preempt = has_role(&request, PREEMPT_ROLE);
+        let request = request.into_inner();
+        let Some(reservation) = request.reservation else {
+            return Err(Status::invalid_argument("missing reservation"));
+        };
+        let reservation = with_allowed_priority(reservation, may_preempt);
+        let reservation = manager
+            .reserve_from_pool(&request.pool_id, reservation)
+            .await?;
+        Ok(Response::new(ReserveFromPoolResponse {
+            reservation: Some(reservation),
+        }))
+    }
+    /// add or replace the tenant's booking policy of a resource type
+    async fn set_policy(
+        &self,
+        request: tonic::Request<SetPolicyRequest>,
+    ) -> std::result::Result<tonic::Response<SetPolicyResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let Some(policy) = request.into_inner().policy else {
+            return Err(Status::invalid_argument("missing policy"));
+        };
+        let policy = manager.set_policy(policy).await?;
+        Ok(Response::new(SetPolicyResponse {
+            policy: Some(policy),
+        }))
+    }
+    /// list the booking policies in effect
+    async fn list_policies(
+        &self,
+        request: tonic::Request<ListPoliciesRequest>,
+    ) -> std::result::Result<tonic::Response<ListPoliciesResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        Ok(Response::new(ListPoliciesResponse {
+            policies: manager.list_policies().await?,
+        }))
+    }
+    /// report the overbooked windows which have not ended yet
+    async fn overbooked_windows(
+        &self,
+        request: tonic::Request<OverbookedWindowsRequest>,
+    ) -> std::result::Result<tonic::Response<OverbookedWindowsResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let windows = manager
+            .overbooked_windows(&request.into_inner().resource_type)
+            .await?;
+        Ok(Response::new(OverbookedWindowsResponse { windows }))
+    }
+    /// find the free windows of a resource
+    async fn availability(
+        &self,
+        request: tonic::Request<AvailabilityRequest>,
+    ) -> std::result::Result<tonic::Response<AvailabilityResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let request = request.into_inner();
+        let Some(window) = request.window else {
+            return Err(Status::invalid_argument("missing window"));
+        };
+        let windows = manager.availability(request.resource_id, window).await?;
+        Ok(Response::new(AvailabilityResponse { windows }))
+    }
+    /// report how much resources were used in a period
+    async fn utilization(
+        &self,
+        request: tonic::Request<UtilizationRequest>,
+    ) -> std::result::Result<tonic::Response<UtilizationResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let resources = manager.utilization(request.into_inner()).await?;
+        Ok(Response::new(UtilizationResponse { resources }))
+    }
+    /// occupancy timelines of resources
+    async fn occupancy(
+        &self,
+        request: tonic::Request<OccupancyRequest>,
+    ) -> std::result::Result<tonic::Response<OccupancyResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let timelines = manager.occupancy(request.into_inner()).await?;
+        Ok(Response::new(OccupancyResponse { timelines }))
+    }
+    /// Server streaming response type for the export method.
+    type exportStream = ExportStream;
+    /// dump reservations as CSV or JSON Lines
+    async fn export(
+        &self,
+        request: tonic::Request<ExportRequest>,
+    ) -> std::result::Result<tonic::Response<Self::exportStream>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let request = request.into_inner();
+        let writer = ExportWriter::new(&request)?;
+        let Some(selection) = request.selection else {
+            return Err(Status::invalid_argument("missing query or filter"));
+        };
+        let rows = manager.export(selection).await?;
+        Ok(Response::new(export_chunks(rows, writer)))
+    }
+    /// load reservations from CSV or JSON Lines, admins only
+    async fn import(
+        &self,
+        request: tonic::Request<Streaming<ImportChunk>>,
+    ) -> std::result::Result<tonic::Response<ImportResponse>, tonic::Status> {
+        require_role(&request, ADMIN_ROLE)?;
+        let manager = self.manager(&request)?;
+        let response = import_chunks(&manager, request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+    /// wait for a reserved window
+    async fn join_waitlist(
+        &self,
+        request: tonic::Request<JoinWaitlistRequest>,
+    ) -> std::result::Result<tonic::Response<JoinWaitlistResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let Some(entry) = request.into_inner().entry else {
+            return Err(Status::invalid_argument("missing waitlist entry"));
+        };
+        let entry = manager.join_waitlist(entry).await?;
+        Ok(Response::new(JoinWaitlistResponse { entry: Some(entry) }))
+    }
+    /// leave the waitlist
+    async fn leave_waitlist(
+        &self,
+        request: tonic::Request<LeaveWaitlistRequest>,
+    ) -> std::result::Result<tonic::Response<LeaveWaitlistResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let entry = manager.leave_waitlist(request.into_inner().id).await?;
+        Ok(Response::new(LeaveWaitlistResponse { entry: Some(entry) }))
+    }
+    /// list waitlist entries by resource id and user id
+    async fn list_waitlist(
+        &self,
+        request: tonic::Request<ListWaitlistRequest>,
+    ) -> std::result::Result<tonic::Response<ListWaitlistResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let request = request.into_inner();
+        let entries = manager
+            .list_waitlist(request.resource_id, request.user_id)
+            .await?;
+        Ok(Response::new(ListWaitlistResponse { entries }))
+    }
+    /// invite a user or external email to a reservation
+    async fn add_attendee(
+        &self,
+        request: tonic::Request<AddAttendeeRequest>,
+    ) -> std::result::Result<tonic::Response<AddAttendeeResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let Some(attendee) = request.into_inner().attendee else {
+            return Err(Status::invalid_argument("missing attendee"));
+        };
+        let attendee = manager.add_attendee(attendee).await?;
+        Ok(Response::new(AddAttendeeResponse {
+            attendee: Some(attendee),
+        }))
+    }
+    /// remove an attendee from a reservation
+    async fn remove_attendee(
+        &self,
+        request: tonic::Request<RemoveAttendeeRequest>,
+    ) -> std::result::Result<tonic::Response<RemoveAttendeeResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let attendee = manager.remove_attendee(request.into_inner().id).await?;
+        Ok(Response::new(RemoveAttendeeResponse {
+            attendee: Some(attendee),
+        }))
+    }
+    /// answer an invitation
+    async fn respond(
+        &self,
+        request: tonic::Request<RespondRequest>,
+    ) -> std::result::Result<tonic::Response<RespondResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let uid = user_id(&request)?;
+        let request = request.into_inner();
+        let attendee = manager.respond(request.id, uid, request.response()).await?;
+        Ok(Response::new(RespondResponse {
+            attendee: Some(attendee),
+        }))
+    }
+    /// list the attendees of a reservation
+    async fn list_attendees(
+        &self,
+        request: tonic::Request<ListAttendeesRequest>,
+    ) -> std::result::Result<tonic::Response<ListAttendeesResponse>, tonic::Status> {
+        let manager = self.manager(&request)?;
+        let attendees = manager
+            .list_attendees(request.into_inner().reservation_id)
+            .await?;
+        Ok(Response::new(ListAttendeesResponse { attendees }))
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
 
     use std::{
         ops::Deref,
@@ -96,7 +737,8 @@ mod tests {
         thread::{self},
     };
 
-    use abi::Reservation;
+    use abi::{ExportFormat, Reservation, ReservationFilter, export_request::Selection};
+    use futures::StreamExt;
     use lazy_static::lazy_static;
     use sqlx::{Connection, Executor, types::Uuid};
     use tokio::runtime::Runtime;
@@ -107,7 +749,7 @@ mod tests {
         static ref RT: Runtime = Runtime::new().unwrap();
     }
 
-    struct TestConfig {
+    pub(crate) struct TestConfig {
         config: Arc<Config>,
     }
 
@@ -127,7 +769,7 @@ mod tests {
             let url = config.db.url();
             let server_url = config.db.server_url();
 
-            let _ = thread::spawn(move || {
+            thread::spawn(move || {
                 // create database dbname
                 RT.block_on(async {
                     let mut conn = sqlx::PgConnection::connect(&server_url).await.unwrap();
@@ -155,7 +797,7 @@ mod tests {
         fn drop(&mut self) {
             let server_url = self.config.db.server_url();
             let db_name = self.config.db.dbname.clone();
-            let _ = thread::spawn(move || {
+            thread::spawn(move || {
                 RT.block_on(async move {
                     let mut conn = sqlx::PgConnection::connect(&server_url).await.unwrap();
                     sqlx::query(&format!(r#"SELECT pg_terminate_backend(pid) FROM pg_stat_activity WHERE pid <> pg_backend_pid() AND datname = '{}'"#,db_name))
@@ -185,6 +827,7 @@ mod tests {
         );
         let request = tonic::Request::new(ReserveRequest {
             reservation: Some(reservation.clone()),
+            preempt: false,
         });
         let response = service.reserve(request).await.unwrap();
         let reservation_res = response.into_inner().reservation;
@@ -197,4 +840,538 @@ mod tests {
         assert_eq!(reservation_res.note, reservation.note);
         assert_eq!(reservation_res.status, reservation.status);
     }
+
+    #[tokio::test]
+    async fn rpc_reserve_should_use_tenant_from_metadata() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let reservation = Reservation::new_pending(
+            "silwings",
+            "ixia-3230",
+            "2025-12-26T15:00:00+0800".parse().unwrap(),
+            "2025-12-30T12:00:00+0800".parse().unwrap(),
+            "test device reservation",
+        );
+        let mut request = tonic::Request::new(ReserveRequest {
+            reservation: Some(Reservation {
+                tenant_id: "spoofed".to_string(),
+                ..reservation.clone()
+            }),
+            preempt: false,
+        });
+        request
+            .metadata_mut()
+            .insert(crate::TENANT_ID_KEY, "lab-a".parse().unwrap());
+        let response = service.reserve(request).await.unwrap();
+        let reservation_res = response.into_inner().reservation.unwrap();
+        assert_eq!(reservation_res.tenant_id, "lab-a");
+
+        // the same window in another tenant should not conflict
+        let mut request = tonic::Request::new(ReserveRequest {
+            reservation: Some(reservation),
+            preempt: false,
+        });
+        request
+            .metadata_mut()
+            .insert(crate::TENANT_ID_KEY, "lab-b".parse().unwrap());
+        let response = service.reserve(request).await.unwrap();
+        assert_eq!(
+            response.into_inner().reservation.unwrap().tenant_id,
+            "lab-b"
+        );
+    }
+
+    #[tokio::test]
+    async fn rpc_reserve_with_invalid_tenant_should_reject() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let mut request = tonic::Request::new(ReserveRequest {
+            reservation: None,
+            preempt: false,
+        });
+        request
+            .metadata_mut()
+            .insert(crate::TENANT_ID_KEY, "lab a; drop".parse().unwrap());
+        let status = service.reserve(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    fn with_tenant<T>(
+        tenant: &str,
+        roles: &str,
+        mut request: tonic::Request<T>,
+    ) -> tonic::Request<T> {
+        let metadata = request.metadata_mut();
+        metadata.insert(crate::TENANT_ID_KEY, tenant.parse().unwrap());
+        metadata.insert(crate::ROLES_KEY, roles.parse().unwrap());
+        request
+    }
+
+    #[tokio::test]
+    async fn rpc_set_policy_should_apply_to_reserve_of_the_tenant() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        for tenant in ["lab-a", "lab-b"] {
+            let request = tonic::Request::new(SaveResourceRequest {
+                resource: Some(abi::Resource::new("ixia-3230", "lab-device")),
+            });
+            service
+                .save_resource(with_tenant(tenant, "admin", request))
+                .await
+                .unwrap();
+        }
+        let policy = SetPolicyRequest {
+            policy: Some(abi::BookingPolicy {
+                resource_type: "lab-device".to_string(),
+                max_duration_secs: Some(3600),
+                ..Default::default()
+            }),
+        };
+        let request = with_tenant("lab-a", "nurse", tonic::Request::new(policy.clone()));
+        let status = service.set_policy(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        let request = with_tenant("lab-a", "admin", tonic::Request::new(policy));
+        service.set_policy(request).await.unwrap();
+
+        let list = |tenant: &str| {
+            let request = with_tenant(tenant, "admin", tonic::Request::new(ListPoliciesRequest {}));
+            service.list_policies(request)
+        };
+        let policies = list("lab-a").await.unwrap().into_inner().policies;
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies[1].resource_type, "lab-device");
+        let policies = list("lab-b").await.unwrap().into_inner().policies;
+        assert_eq!(policies.len(), 1);
+        // the policy is stored, so it survives a restart
+        let service = RsvpService::from_config(&config).await.unwrap();
+
+        let reserve = |tenant: &str| {
+            let reservation = Reservation::new_pending(
+                "silwings",
+                "ixia-3230",
+                "2035-12-26T15:00:00+0800".parse().unwrap(),
+                "2035-12-26T17:00:00+0800".parse().unwrap(),
+                "test device reservation",
+            );
+            let request = tonic::Request::new(ReserveRequest {
+                reservation: Some(reservation),
+                preempt: false,
+            });
+            service.reserve(with_tenant(tenant, "", request))
+        };
+        let status = reserve("lab-a").await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        reserve("lab-b").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rpc_confirm_overbooked_should_fail_while_window_is_taken() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let request = tonic::Request::new(SetPolicyRequest {
+            policy: Some(abi::BookingPolicy {
+                overbooking_percent: Some(100),
+                ..Default::default()
+            }),
+        });
+        service
+            .set_policy(with_tenant("lab-a", "admin", request))
+            .await
+            .unwrap();
+        let mut ids = vec![];
+        for uid in ["silwings", "alice"] {
+            let request = tonic::Request::new(ReserveRequest {
+                reservation: Some(Reservation::new_pending(
+                    uid,
+                    "ixia-3230",
+                    "2035-12-26T15:00:00+0800".parse().unwrap(),
+                    "2035-12-26T17:00:00+0800".parse().unwrap(),
+                    "",
+                )),
+                preempt: false,
+            });
+            let response = service
+                .reserve(with_tenant("lab-a", "", request))
+                .await
+                .unwrap();
+            ids.push(response.into_inner().reservation.unwrap().id);
+        }
+
+        let request = tonic::Request::new(ConfirmRequest { id: ids[1] });
+        let status = service
+            .confirm(with_tenant("lab-a", "", request))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        assert!(status.message().contains("overbooked"), "{status:?}");
+    }
+
+    #[tokio::test]
+    async fn rpc_reserve_with_preempt_should_require_role() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let reservation = Reservation::new_pending(
+            "silwings",
+            "theatre-1",
+            "2025-12-26T15:00:00+0800".parse().unwrap(),
+            "2025-12-26T17:00:00+0800".parse().unwrap(),
+            "routine",
+        );
+        let request = tonic::Request::new(ReserveRequest {
+            reservation: Some(reservation.clone()),
+            preempt: false,
+        });
+        let routine = service.reserve(request).await.unwrap().into_inner();
+
+        let emergency = Reservation {
+            priority: 1,
+            ..reservation
+        };
+        let request = tonic::Request::new(ReserveRequest {
+            reservation: Some(emergency.clone()),
+            preempt: true,
+        });
+        let status = service.reserve(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        let mut request = tonic::Request::new(ReserveRequest {
+            reservation: Some(emergency),
+            preempt: true,
+        });
+        request
+            .metadata_mut()
+            .insert(crate::ROLES_KEY, "nurse, preempt".parse().unwrap());
+        let response = service.reserve(request).await.unwrap().into_inner();
+        assert_eq!(response.preempted.len(), 1);
+        assert_eq!(response.preempted[0].id, routine.reservation.unwrap().id);
+    }
+
+    #[tokio::test]
+    async fn rpc_reserve_should_keep_default_priority_without_preempt_role() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let reserve = |rid: &str, roles: &str| {
+            let reservation = Reservation {
+                priority: 7,
+                ..Reservation::new_pending(
+                    "silwings",
+                    rid,
+                    "2035-12-26T15:00:00+0800".parse().unwrap(),
+                    "2035-12-26T17:00:00+0800".parse().unwrap(),
+                    "",
+                )
+            };
+            let request = tonic::Request::new(ReserveRequest {
+                reservation: Some(reservation),
+                preempt: false,
+            });
+            service.reserve(with_tenant("lab-a", roles, request))
+        };
+        let response = reserve("theatre-1", "nurse").await.unwrap().into_inner();
+        assert_eq!(response.reservation.unwrap().priority, 0);
+        let response = reserve("theatre-2", "preempt").await.unwrap().into_inner();
+        assert_eq!(response.reservation.unwrap().priority, 7);
+    }
+
+    #[tokio::test]
+    async fn rpc_cancel_override_should_require_admin() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let resource = SaveResourceRequest {
+            resource: Some(abi::Resource {
+                cancellation_tiers: vec![abi::CancellationTier::new(86400, 0)],
+                ..abi::Resource::new("ixia-3230", "lab-device")
+            }),
+        };
+        let request = tonic::Request::new(resource.clone());
+        let status = service.save_resource(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        let mut request = tonic::Request::new(resource);
+        request
+            .metadata_mut()
+            .insert(crate::ROLES_KEY, "admin".parse().unwrap());
+        service.save_resource(request).await.unwrap();
+        // already started, so it can no longer be cancelled by the policy
+        let reservation = Reservation::new_pending(
+            "silwings",
+            "ixia-3230",
+            "2025-12-26T15:00:00+0800".parse().unwrap(),
+            "2025-12-26T17:00:00+0800".parse().unwrap(),
+            "",
+        );
+        let request = tonic::Request::new(ReserveRequest {
+            reservation: Some(reservation),
+            preempt: false,
+        });
+        let id = service
+            .reserve(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap()
+            .id;
+
+        let cancel = |override_policy: bool| {
+            tonic::Request::new(CancelRequest {
+                id,
+                override_policy,
+            })
+        };
+        let status = service.cancel(cancel(false)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        let status = service.cancel(cancel(true)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        let mut request = cancel(true);
+        request
+            .metadata_mut()
+            .insert(crate::ROLES_KEY, "admin".parse().unwrap());
+        let response = service.cancel(request).await.unwrap().into_inner();
+        assert_eq!(response.reservation.unwrap().id, id);
+        assert_eq!(response.fee, None);
+    }
+
+    #[tokio::test]
+    async fn rpc_transfer_should_be_made_by_owner_and_accepted_by_recipient() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let reservation = Reservation::new_pending(
+            "silwings",
+            "ixia-3230",
+            "2025-12-26T15:00:00+0800".parse().unwrap(),
+            "2025-12-30T12:00:00+0800".parse().unwrap(),
+            "test device reservation",
+        );
+        let request = tonic::Request::new(ReserveRequest {
+            reservation: Some(reservation),
+            preempt: false,
+        });
+        let id = service
+            .reserve(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap()
+            .id;
+        let mut changes = service
+            .listen(tonic::Request::new(ListenRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        let transfer = |uid: Option<&str>| {
+            let mut request = tonic::Request::new(TransferRequest {
+                id,
+                to_user_id: "alice".to_string(),
+                require_acceptance: true,
+            });
+            if let Some(uid) = uid {
+                request
+                    .metadata_mut()
+                    .insert(crate::USER_ID_KEY, uid.parse().unwrap());
+            }
+            service.transfer(request)
+        };
+        for uid in [None, Some("bob")] {
+            let status = transfer(uid).await.unwrap_err();
+            assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        }
+        transfer(Some("silwings")).await.unwrap();
+        let change = tokio::time::timeout(std::time::Duration::from_secs(5), changes.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!((change.id, change.transfer_to.as_str()), (id, "alice"));
+
+        let request = tonic::Request::new(AcceptTransferRequest { id });
+        let status = service.accept_transfer(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        let mut request = tonic::Request::new(AcceptTransferRequest { id });
+        request
+            .metadata_mut()
+            .insert(crate::USER_ID_KEY, "alice".parse().unwrap());
+        let reservation = service
+            .accept_transfer(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+        assert_eq!(reservation.user_id, "alice");
+    }
+
+    #[tokio::test]
+    async fn rpc_export_should_stream_matching_reservations() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let mut ids = vec![];
+        for rid in ["ixia-3230", "ixia-3231", "ixia-3232"] {
+            let request = tonic::Request::new(ReserveRequest {
+                reservation: Some(Reservation::new_pending(
+                    "silwings",
+                    rid,
+                    "2025-12-26T15:00:00+0800".parse().unwrap(),
+                    "2025-12-26T17:00:00+0800".parse().unwrap(),
+                    "",
+                )),
+                preempt: false,
+            });
+            let rsvp = service.reserve(request).await.unwrap().into_inner();
+            ids.push(rsvp.reservation.unwrap().id);
+        }
+
+        let export = ExportRequest {
+            // the export resumes after the cursor, the page size does not limit it
+            selection: Some(Selection::Filter(ReservationFilter {
+                cursor: Some(ids[0]),
+                page_size: 1,
+                ..Default::default()
+            })),
+            format: ExportFormat::Csv as i32,
+            columns: vec!["id".into(), "resource_id".into(), "start".into()],
+            time_zone: "Asia/Shanghai".into(),
+        };
+        let mut chunks = service
+            .export(tonic::Request::new(export.clone()))
+            .await
+            .unwrap()
+            .into_inner();
+        let mut data = vec![];
+        while let Some(chunk) = chunks.next().await {
+            data.extend(chunk.unwrap().data);
+        }
+        assert_eq!(
+            String::from_utf8(data).unwrap(),
+            format!(
+                "id,resource_id,start\r\n{},ixia-3231,2025-12-26T15:00:00+08:00\r\n{},ixia-3232,2025-12-26T15:00:00+08:00\r\n",
+                ids[1], ids[2]
+            )
+        );
+
+        let request = tonic::Request::new(ExportRequest {
+            columns: vec!["password".into()],
+            ..export
+        });
+        let status = service.export(request).await.err().unwrap();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn rpc_get_update_confirm_query_and_filter_should_work() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let request = tonic::Request::new(ReserveRequest {
+            reservation: Some(Reservation::new_pending(
+                "silwings",
+                "ixia-3230",
+                "2025-12-26T15:00:00+0800".parse().unwrap(),
+                "2025-12-26T17:00:00+0800".parse().unwrap(),
+                "",
+            )),
+            preempt: false,
+        });
+        let id = service
+            .reserve(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap()
+            .id;
+
+        let request = tonic::Request::new(UpdateRequest {
+            id,
+            note: "two screens".into(),
+        });
+        service.update(request).await.unwrap();
+        // confirming twice leaves it confirmed
+        for _ in 0..2 {
+            let request = tonic::Request::new(ConfirmRequest { id });
+            let reservation = service.confirm(request).await.unwrap().into_inner();
+            assert_eq!(
+                reservation.reservation.unwrap().status,
+                abi::ReservationStatus::Confirmed as i32
+            );
+        }
+        let reservation = service
+            .get(tonic::Request::new(GetRequest { id }))
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+        assert_eq!(reservation.note, "two screens");
+
+        let request = tonic::Request::new(QueryRequest {
+            query: Some(abi::ReservationQuery {
+                user_id: "silwings".into(),
+                ..Default::default()
+            }),
+        });
+        let queried = service
+            .query(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(queried.len(), 1);
+        assert_eq!(queried[0].as_ref().unwrap().id, id);
+
+        let request = tonic::Request::new(FilterRequest {
+            filter: Some(ReservationFilter {
+                resource_id: "ixia-3230".into(),
+                page_size: 10,
+                ..Default::default()
+            }),
+        });
+        let filtered = service.filter(request).await.unwrap().into_inner();
+        assert_eq!(filtered.reservations, [reservation]);
+
+        let status = service
+            .get(tonic::Request::new(GetRequest { id: id + 1 }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn rpc_listen_should_stream_changes_of_the_tenant() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let mut request = tonic::Request::new(ListenRequest {});
+        request
+            .metadata_mut()
+            .insert(crate::TENANT_ID_KEY, "lab-a".parse().unwrap());
+        let mut changes = service.listen(request).await.unwrap().into_inner();
+
+        for tenant in ["lab-b", "lab-a"] {
+            let mut request = tonic::Request::new(ReserveRequest {
+                reservation: Some(Reservation::new_pending(
+                    "silwings",
+                    "ixia-3230",
+                    "2025-12-26T15:00:00+0800".parse().unwrap(),
+                    "2025-12-26T17:00:00+0800".parse().unwrap(),
+                    tenant,
+                )),
+                preempt: false,
+            });
+            request
+                .metadata_mut()
+                .insert(crate::TENANT_ID_KEY, tenant.parse().unwrap());
+            service.reserve(request).await.unwrap();
+        }
+        let change = tokio::time::timeout(std::time::Duration::from_secs(5), changes.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            (change.tenant_id.as_str(), change.note.as_str()),
+            ("lab-a", "lab-a")
+        );
+    }
 }
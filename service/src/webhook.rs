@@ -0,0 +1,239 @@
+use std::time::Duration;
+
+use abi::{Error, WebhookConfig};
+use hmac::{Hmac, Mac};
+use reqwest::header::CONTENT_TYPE;
+use reservation::{Delivery, ReservationManager};
+use sha2::Sha256;
+use tokio::task::JoinSet;
+
+/// header carrying the signature of the body, see [sign]
+pub const SIGNATURE_HEADER: &str = "x-rsvp-signature";
+/// header carrying the id of the delivery, the same for every retry
+pub const DELIVERY_HEADER: &str = "x-rsvp-delivery";
+
+/// how much longer than the callback timeout a claimed delivery stays leased
+const LEASE_MARGIN_SECS: u64 = 30;
+
+/// "sha256=" and the hex HMAC-SHA256 of the body keyed with the secret of the subscription
+pub fn sign(secret: &str, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC takes keys of any size");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// POSTs queued reservation changes to the webhook subscriptions of every tenant
+#[derive(Clone)]
+pub struct WebhookWorker {
+    manager: ReservationManager,
+    client: reqwest::Client,
+    config: WebhookConfig,
+}
+
+impl WebhookWorker {
+    pub fn new(manager: ReservationManager, config: WebhookConfig) -> Result<Self, reqwest::Error> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()?;
+        Ok(Self {
+            manager,
+            client,
+            config,
+        })
+    }
+
+    /// deliver the next due change of every subscription, subscriptions are called concurrently.
+    /// Returns how many changes were delivered
+    pub async fn run_once(&self) -> Result<usize, Error> {
+        let lease = self.config.timeout_secs + LEASE_MARGIN_SECS;
+        let deliveries = self.manager.claim_webhook_deliveries(lease).await?;
+
+        let mut posts = JoinSet::new();
+        for delivery in deliveries {
+            let worker = self.clone();
+            posts.spawn(async move {
+                let result = worker.post(&delivery).await;
+                (delivery, result)
+            });
+        }
+        let mut delivered = 0;
+        while let Some(joined) = posts.join_next().await {
+            // a panicked post is retried once its lease is over
+            let Ok((delivery, result)) = joined else {
+                continue;
+            };
+            match result {
+                Ok(()) => {
+                    self.manager.complete_webhook_delivery(delivery.id).await?;
+                    delivered += 1;
+                }
+                Err(e) => {
+                    self.manager
+                        .fail_webhook_delivery(&delivery, &e, &self.config)
+                        .await?;
+                }
+            }
+        }
+        Ok(delivered)
+    }
+
+    /// any 2xx response acknowledges the delivery
+    async fn post(&self, delivery: &Delivery) -> Result<(), String> {
+        let response = self
+            .client
+            .post(&delivery.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, sign(&delivery.secret, &delivery.payload))
+            .header(DELIVERY_HEADER, delivery.id)
+            .body(delivery.payload.clone())
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("responded {}", response.status()))
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use abi::{Reservation, WebhookSubscription};
+    use reservation::{Rsvp, Webhooks};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    use super::*;
+    use crate::{RsvpService, service::tests::TestConfig};
+
+    /// a request received by the stand-in: signature header and body
+    pub(crate) type Received = Arc<Mutex<Vec<(String, String)>>>;
+
+    /// local HTTP stand-in for a subscriber, answering with the given statuses in turn and
+    /// with 200 once they are used up
+    pub(crate) async fn stand_in(statuses: Vec<u16>) -> (String, Received) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+        let received = Received::default();
+        let statuses = Arc::new(Mutex::new(statuses.into_iter()));
+        let log = received.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(stream, log.clone(), statuses.clone()));
+            }
+        });
+        (url, received)
+    }
+
+    async fn serve(
+        mut stream: TcpStream,
+        received: Received,
+        statuses: Arc<Mutex<std::vec::IntoIter<u16>>>,
+    ) {
+        let mut buf = Vec::new();
+        loop {
+            let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") else {
+                let mut chunk = [0; 4096];
+                match stream.read(&mut chunk).await {
+                    Ok(0) | Err(_) => return,
+                    Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                }
+                continue;
+            };
+            let head = String::from_utf8_lossy(&buf[..end]).to_lowercase();
+            let header = |name: &str| {
+                head.lines()
+                    .find_map(|line| line.strip_prefix(&format!("{name}: ")))
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let length: usize = header("content-length").parse().unwrap_or(0);
+            while buf.len() < end + 4 + length {
+                let mut chunk = [0; 4096];
+                match stream.read(&mut chunk).await {
+                    Ok(0) | Err(_) => return,
+                    Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                }
+            }
+            let body = String::from_utf8_lossy(&buf[end + 4..end + 4 + length]).to_string();
+            received
+                .lock()
+                .unwrap()
+                .push((header(SIGNATURE_HEADER), body));
+            buf.drain(..end + 4 + length);
+
+            let status = statuses.lock().unwrap().next().unwrap_or(200);
+            let response = format!("HTTP/1.1 {status} X\r\ncontent-length: 0\r\n\r\n");
+            if stream.write_all(response.as_bytes()).await.is_err() {
+                return;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn worker_should_post_signed_changes_in_order_and_retry() {
+        let config = TestConfig::new();
+        let service = RsvpService::from_config(&config).await.unwrap();
+        let manager = service.manager.clone();
+        let (url, received) = stand_in(vec![500]).await;
+        manager
+            .save_webhook(WebhookSubscription::new(url, "s3cret"))
+            .await
+            .unwrap();
+        let rsvp = manager
+            .reserve(Reservation::new_pending(
+                "silwings",
+                "ixia-3230",
+                "2030-12-26T15:00:00+0800".parse().unwrap(),
+                "2030-12-26T17:00:00+0800".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+        manager.change_status(rsvp.id).await.unwrap();
+
+        let worker = WebhookWorker::new(
+            manager.clone(),
+            WebhookConfig {
+                backoff_secs: 0,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        // the create is rejected once, the update waits behind it
+        assert_eq!(worker.run_once().await.unwrap(), 0);
+        assert_eq!(worker.run_once().await.unwrap(), 1);
+        assert_eq!(worker.run_once().await.unwrap(), 1);
+        assert_eq!(worker.run_once().await.unwrap(), 0);
+
+        let received = received.lock().unwrap().clone();
+        let ops = received
+            .iter()
+            .map(|(_, body)| {
+                ["create", "update"]
+                    .into_iter()
+                    .find(|op| body.contains(&format!(r#""op": "{op}""#)))
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ops, ["create", "create", "update"]);
+        for (signature, body) in &received {
+            assert_eq!(*signature, sign("s3cret", body));
+        }
+    }
+
+    #[test]
+    fn sign_should_match_known_hmac() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign("Jefe", "what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}